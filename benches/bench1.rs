@@ -740,6 +740,8 @@ macro_rules! mat_mul {
 }
 
 mat_mul! {mat_mul_f32, f32,
+    (m002, 2, 2, 2)
+    (m003, 3, 3, 3)
     (m004, 4, 4, 4)
     (m007, 7, 7, 7)
     (m008, 8, 8, 8)
@@ -754,6 +756,8 @@ mat_mul! {mat_mul_f32, f32,
 }
 
 mat_mul! {mat_mul_f64, f64,
+    (m002, 2, 2, 2)
+    (m003, 3, 3, 3)
     (m004, 4, 4, 4)
     (m007, 7, 7, 7)
     (m008, 8, 8, 8)
@@ -1,13 +1,20 @@
 extern crate approx;
 extern crate defmac;
 extern crate ndarray;
+extern crate num_complex;
 extern crate num_traits;
 
 use ndarray::linalg::general_mat_mul;
 use ndarray::linalg::general_mat_vec_mul;
+use ndarray::linalg::Givens;
+use ndarray::linalg::{banded_mat_vec_mul, BandedMatrix};
+use ndarray::linalg::{hermitian_mat_mul, hermitian_rank_k_update, Hermitian};
+use ndarray::linalg::{solve_triangular_mat, solve_triangular_vec, Triangle};
+use ndarray::linalg::{symmetric_mat_mul, symmetric_rank_k_update, Symmetric};
 use ndarray::prelude::*;
 use ndarray::{Data, LinalgScalar};
 use ndarray::{Ix, Ixs, SliceInfo, SliceOrIndex};
+use num_complex::{Complex32, Complex64};
 use std::iter::FromIterator;
 
 use approx::{assert_abs_diff_eq, assert_relative_eq};
@@ -26,6 +33,17 @@ where
         .fold(A::zero(), |acc, (&x, &y)| acc + x * y)
 }
 
+fn reference_dot_generic<A, S, S2>(a: &ArrayBase<S, Ix1>, b: &ArrayBase<S2, Ix1>) -> A
+where
+    A: LinalgScalar,
+    S: Data<Elem = A>,
+    S2: Data<Elem = A>,
+{
+    a.iter()
+        .zip(b.iter())
+        .fold(A::zero(), |acc, (&x, &y)| acc + x * y)
+}
+
 #[test]
 fn dot_product() {
     let a = Array::range(0., 69., 1.);
@@ -133,6 +151,15 @@ fn range_i32(m: Ix, n: Ix) -> Array2<i32> {
         .unwrap()
 }
 
+// complex matrix with small integer parts, so that products are exact
+fn range_c64(m: Ix, n: Ix) -> Array2<Complex64> {
+    Array::from_iter(
+        (0..(m * n) as i32).map(|i| Complex64::new((i % 7) as f64, (i % 5 - 2) as f64)),
+    )
+    .into_shape((m, n))
+    .unwrap()
+}
+
 // simple, slow, correct (hopefully) mat mul
 fn reference_mat_mul<A, S, S2>(lhs: &ArrayBase<S, Ix2>, rhs: &ArrayBase<S2, Ix2>) -> Array2<A>
 where
@@ -270,6 +297,32 @@ fn mat_mul_order() {
     assert_eq!(ff.strides()[0], 1);
 }
 
+// Check all combinations of c- and f-order operands and outputs,
+// including non-square ones
+#[test]
+fn gen_mat_mul_mixed_order() {
+    for &(m, k, n) in &[(9, 12, 10), (16, 3, 8), (1, 17, 9), (11, 8, 1)] {
+        let a = range_mat64(m, k);
+        let b = range_mat64(k, n) / 4.;
+        let answer = reference_mat_mul(&a, &b);
+
+        let mut af = Array::zeros(a.dim().f());
+        let mut bf = Array::zeros(b.dim().f());
+        af.assign(&a);
+        bf.assign(&b);
+
+        for lhs in &[a.view(), af.view()] {
+            for rhs in &[b.view(), bf.view()] {
+                for &f_order in &[false, true] {
+                    let mut c = Array::zeros((m, n).set_f(f_order));
+                    general_mat_mul(1., lhs, rhs, 0., &mut c);
+                    assert_relative_eq!(c, answer, epsilon = 1e-12, max_relative = 1e-7);
+                }
+            }
+        }
+    }
+}
+
 // test matrix multiplication shape mismatch
 #[test]
 #[should_panic]
@@ -433,7 +486,7 @@ fn scaled_add_3() {
 
                 {
                     let mut av = a.slice_mut(s![..;s1, ..;s2]);
-                    let c = c.slice(SliceInfo::<_, IxDyn>::new(cslice).unwrap().as_ref());
+                    let c = c.slice(SliceInfo::<_, IxDyn, IxDyn>::new(cslice).unwrap().as_ref());
 
                     let mut answerv = answer.slice_mut(s![..;s1, ..;s2]);
                     answerv += &(beta * &c);
@@ -612,3 +665,527 @@ fn vec_mat_mul() {
         }
     }
 }
+
+#[test]
+fn gen_mat_mul_complex() {
+    let alpha = Complex64::new(-1., 2.);
+    let beta = Complex64::new(3., -1.);
+    let sizes = vec![
+        (4, 4, 4),
+        (8, 8, 8),
+        (17, 15, 16),
+        (4, 17, 3),
+        (19, 18, 2),
+        (15, 16, 17),
+    ];
+    for &(m, k, n) in &sizes {
+        let a = range_c64(m, k);
+        let b = range_c64(k, n);
+        let c = range_c64(m, n);
+        let answer = reference_mat_mul(&a, &b) * alpha + &c * beta;
+
+        let mut bf = Array::zeros(b.dim().f());
+        bf.assign(&b);
+        for b in &[
+            b.view(),
+            bf.view(),
+            b.slice(s![.., ..;-1]).to_owned().slice(s![.., ..;-1]),
+        ] {
+            let mut c = c.clone();
+            general_mat_mul(alpha, &a, b, beta, &mut c);
+            assert_eq!(c, answer);
+        }
+
+        // single precision
+        let a32 = a.mapv(|x| Complex32::new(x.re as f32, x.im as f32));
+        let b32 = b.mapv(|x| Complex32::new(x.re as f32, x.im as f32));
+        let answer32 = reference_mat_mul(&a32, &b32);
+        assert_eq!(a32.dot(&b32), answer32);
+    }
+}
+
+#[test]
+fn gen_mat_vec_mul_complex() {
+    let alpha = Complex64::new(2., -1.);
+    let beta = Complex64::new(0., 1.);
+    for &(m, k) in &[(4, 4), (17, 15), (4, 17), (67, 63)] {
+        for &rev in &[false, true] {
+            let mut a = range_c64(m, k);
+            if rev {
+                a = a.reversed_axes();
+            }
+            let (m, k) = a.dim();
+            let x = range_c64(k, 1).into_shape(k).unwrap();
+            let mut y = range_c64(m, 1).into_shape(m).unwrap();
+            let answer = reference_mat_vec_mul(&a, &x) * alpha + &y * beta;
+            general_mat_vec_mul(alpha, &a, &x, beta, &mut y);
+            assert_eq!(y, answer);
+            assert_eq!(a.dot(&x), reference_mat_vec_mul(&a, &x));
+        }
+    }
+}
+
+#[test]
+fn dot_product_complex() {
+    let a = range_c64(1, 69).into_shape(69).unwrap();
+    let b = range_c64(69, 1).into_shape(69).unwrap().mapv(|x| x.conj());
+    assert_eq!(a.dot(&b), reference_dot_generic(&a, &b));
+    let a = a.slice(s![..;-2]);
+    let b = b.slice(s![..;2]);
+    assert_eq!(a.dot(&b), reference_dot_generic(&a, &b));
+}
+
+#[test]
+fn solve_triangular_blas() {
+    for &n in &[1, 4, 17, 33] {
+        for &uplo in &[Triangle::Upper, Triangle::Lower] {
+            // diagonally dominant so the solution is well conditioned
+            let a = Array::from_shape_fn((n, n), |(i, j)| {
+                let inside = match uplo {
+                    Triangle::Upper => j >= i,
+                    Triangle::Lower => j <= i,
+                };
+                if i == j {
+                    n as f64 + 1.
+                } else if inside {
+                    (i + 2 * j) as f64 / n as f64
+                } else {
+                    99.
+                }
+            });
+            let mut af = Array::zeros(a.dim().f());
+            af.assign(&a);
+            let tri = Array::from_shape_fn((n, n), |(i, j)| match uplo {
+                Triangle::Upper if j >= i => a[[i, j]],
+                Triangle::Lower if j <= i => a[[i, j]],
+                _ => 0.,
+            });
+            let b = range_mat64(n, 3);
+            for a in &[a.view(), af.view()] {
+                let mut x = b.clone();
+                solve_triangular_mat(uplo, a, &mut x);
+                assert_relative_eq!(tri.dot(&x), b, epsilon = 1e-10);
+
+                let mut xf = Array::zeros(b.dim().f());
+                xf.assign(&b);
+                solve_triangular_mat(uplo, a, &mut xf);
+                assert_relative_eq!(xf, x, epsilon = 1e-10);
+
+                let mut v = b.column(0).to_owned();
+                solve_triangular_vec(uplo, a, &mut v.slice_mut(s![..;-1]).slice_mut(s![..;-1]));
+                assert_relative_eq!(v, x.column(0), epsilon = 1e-10);
+                let mut v = Array::zeros(2 * n);
+                v.slice_mut(s![..;-2]).assign(&b.column(1));
+                solve_triangular_vec(uplo, a, &mut v.slice_mut(s![..;-2]));
+                assert_relative_eq!(v.slice(s![..;-2]), x.column(1), epsilon = 1e-10);
+            }
+        }
+    }
+}
+
+#[test]
+fn symmetric_blas() {
+    for &(m, n) in &[(1, 1), (4, 4), (5, 2), (3, 17), (17, 15)] {
+        for &uplo in &[Triangle::Upper, Triangle::Lower] {
+            let in_triangle = |i: usize, j: usize| match uplo {
+                Triangle::Upper => i <= j,
+                Triangle::Lower => i >= j,
+            };
+            let a = range_c64(m, n);
+            let mut af = Array::zeros(a.dim().f());
+            af.assign(&a);
+            let c = range_c64(m, m);
+            let alpha = Complex64::new(2., -1.);
+            let beta = Complex64::new(0., 1.);
+            let full = reference_mat_mul(&a, &a.t()) * alpha + &c * beta;
+            for a in &[a.view(), af.view()] {
+                let mut c1 = c.clone();
+                symmetric_rank_k_update(alpha, a, beta, &mut c1, uplo);
+                let mut c2 = Array::zeros(c.dim().f());
+                c2.assign(&c);
+                symmetric_rank_k_update(alpha, a, beta, &mut c2, uplo);
+                for ((i, j), &x) in c1.indexed_iter() {
+                    let expected = if in_triangle(i, j) {
+                        full[[i, j]]
+                    } else {
+                        c[[i, j]]
+                    };
+                    assert_eq!(x, expected);
+                    assert_eq!(c2[[i, j]], expected);
+                }
+            }
+
+            let sym = Array::from_shape_fn((m, m), |(i, j)| {
+                if in_triangle(i, j) {
+                    (i * j + i + j) as f64
+                } else {
+                    -99.
+                }
+            });
+            let mut symf = Array::zeros(sym.dim().f());
+            symf.assign(&sym);
+            let b = range_mat64(m, n);
+            let mut bf = Array::zeros(b.dim().f());
+            bf.assign(&b);
+            let answer = reference_mat_mul(&Symmetric::new(sym.view(), uplo).to_full(), &b);
+            for a in &[sym.view(), symf.view()] {
+                for b in &[b.view(), bf.view()] {
+                    let mut c = Array::zeros((m, n));
+                    symmetric_mat_mul(1., Symmetric::new(a.view(), uplo), b, 0., &mut c);
+                    assert_eq!(c, answer);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn rank1_update_blas() {
+    for &(m, n) in &[(1, 1), (4, 4), (5, 2), (3, 17), (17, 15)] {
+        let a = range_c64(m, n);
+        let x = range_c64(m, 1).into_shape(m).unwrap();
+        let y = range_c64(1, n).into_shape(n).unwrap().mapv(|e| e.conj());
+        let alpha = Complex64::new(1., -2.);
+        let xm = x.view().into_shape((m, 1)).unwrap();
+        let ym = y.view().into_shape((1, n)).unwrap();
+        let answer = reference_mat_mul(&xm, &ym) * alpha + &a;
+
+        let mut af = Array::zeros(a.dim().f());
+        af.assign(&a);
+        for a in &mut [a.clone(), af] {
+            a.general_rank1_update(alpha, &x, &y);
+            assert_eq!(*a, answer);
+        }
+
+        let a = range_mat64(m, n);
+        let x = range1_mat64(m);
+        let y = range1_mat64(n);
+        let xm = x.view().into_shape((m, 1)).unwrap();
+        let ym = y.view().into_shape((1, n)).unwrap();
+        let answer = reference_mat_mul(&xm, &ym) * -2. + &a;
+        let rev_answer = answer.slice(s![..;-1, ..]).to_owned();
+        let mut a_rev = a.slice(s![..;-1, ..]).to_owned();
+        a_rev.general_rank1_update(-2., &x.slice(s![..;-1]), &y);
+        assert_relative_eq!(a_rev, rev_answer, epsilon = 1e-12);
+    }
+}
+
+#[test]
+fn rank1_update_blas_broadcast() {
+    for &(m, n) in &[(4, 4), (5, 2), (17, 15)] {
+        let a = range_mat64(m, n);
+        let x = range1_mat64(m);
+        let y0 = arr0(2.);
+        let y = y0.broadcast(n).unwrap();
+        let answer = x.view().into_shape((m, 1)).unwrap().to_owned() * 2. * 3. + &a;
+        let mut a_blas = a.clone();
+        a_blas.general_rank1_update(3., &x, &y);
+        assert_relative_eq!(a_blas, answer, epsilon = 1e-12);
+    }
+}
+
+#[test]
+fn outer_blas_broadcast() {
+    use ndarray::linalg::outer;
+
+    for &(m, n) in &[(4, 4), (5, 2), (17, 15)] {
+        let x = range1_mat64(m);
+        let two = arr0(2.);
+        let answer = Array::from_shape_fn((m, n), |(i, _)| 2. * x[i]);
+        assert_eq!(outer(&x, &two.broadcast(n).unwrap()), answer);
+        assert_eq!(outer(&two.broadcast(n).unwrap(), &x), answer.t());
+    }
+}
+
+#[test]
+fn vector_norms_blas() {
+    for &n in &[1, 31, 32, 67] {
+        let v = range1_mat64(n).mapv(|x| (x * 7. % 11.) - 5.);
+        let l2 = v.iter().map(|x| x * x).sum::<f64>().sqrt();
+        let l1 = v.iter().map(|x| x.abs()).sum::<f64>();
+        for v in &[v.view(), v.slice(s![..;-1])] {
+            assert_relative_eq!(v.norm_l2(), l2, max_relative = 1e-12);
+            assert_relative_eq!(v.norm_l1(), l1, max_relative = 1e-12);
+            let i = v.argmax_abs().unwrap();
+            let max = v.fold(0., |m: f64, x| m.max(x.abs()));
+            assert_eq!(v[i].abs(), max);
+            assert!(v.slice(s![..i]).iter().all(|x| x.abs() < max));
+        }
+        let v32 = v.mapv(|x| x as f32);
+        assert_relative_eq!(
+            v32.slice(s![..;2]).norm_l1(),
+            v.slice(s![..;2]).norm_l1() as f32
+        );
+        assert_eq!(v32.argmax_abs(), v.argmax_abs());
+    }
+}
+
+#[test]
+fn vector_norms_blas_broadcast() {
+    for &n in &[32, 67] {
+        let x = arr0(3.);
+        let v = x.broadcast(n).unwrap();
+        assert_relative_eq!(v.norm_l2(), 3. * (n as f64).sqrt(), max_relative = 1e-12);
+        assert_eq!(v.norm_l1(), 3. * n as f64);
+        let x32 = arr0(3f32);
+        let v32 = x32.broadcast(n).unwrap();
+        assert_eq!(v32.norm_l1(), 3. * n as f32);
+    }
+}
+
+#[test]
+fn vector_norms_blas_infinite() {
+    for &n in &[32, 67] {
+        let mut v = range1_mat64(n);
+        v[n / 2] = f64::INFINITY;
+        assert_eq!(v.norm_l2(), f64::INFINITY);
+        assert_eq!(v.norm_l1(), f64::INFINITY);
+        v[n / 2] = f64::NEG_INFINITY;
+        assert_eq!(v.slice(s![..;-1]).norm_l2(), f64::INFINITY);
+        let v32 = v.mapv(|x| x as f32);
+        assert_eq!(v32.norm_l2(), f32::INFINITY);
+    }
+}
+
+#[test]
+fn argmax_abs_blas_nan() {
+    for &n in &[32, 67] {
+        let mut v = range1_mat64(n);
+        v[n / 3] = f64::NAN;
+        v[n / 2] = f64::INFINITY;
+        v[2 * n / 3] = f64::NAN;
+        assert_eq!(v.argmax_abs(), Some(n / 3));
+        let rev = v.slice(s![..;-1]);
+        assert_eq!(rev.argmax_abs(), Some(n - 1 - 2 * n / 3));
+        let v32 = v.mapv(|x| x as f32);
+        assert_eq!(v32.argmax_abs(), Some(n / 3));
+        v[0] = f64::NAN;
+        assert_eq!(v.argmax_abs(), Some(0));
+    }
+}
+
+#[test]
+fn scaled_add_and_scale_1d() {
+    let alpha = Complex64::new(0.5, -1.);
+    for &n in &[1, 32, 67] {
+        let x = range_c64(1, 2 * n).into_shape(2 * n).unwrap();
+        let y = range_c64(2 * n, 1)
+            .into_shape(2 * n)
+            .unwrap()
+            .mapv(|e| e.conj());
+        for &(sx, sy) in &[(1, 1), (2, -2), (-1, 2), (-2, -1)] {
+            let xv = x.slice(s![..;sx]).slice(s![..n]).to_owned();
+            let mut y1 = y.clone();
+            let answer = &y.slice(s![..;sy]).slice(s![..n]) + &(&xv * alpha);
+            y1.slice_mut(s![..;sy])
+                .slice_mut(s![..n])
+                .scaled_add(alpha, &x.slice(s![..;sx]).slice(s![..n]));
+            assert_eq!(y1.slice(s![..;sy]).slice(s![..n]), answer);
+
+            let mut y2 = y.mapv(|e| e.re);
+            let answer = y2.slice(s![..;sy]).mapv(|e| e * -3.);
+            y2.slice_mut(s![..;sy]).scale(-3.);
+            assert_eq!(y2.slice(s![..;sy]), answer);
+        }
+        let mut c = range_c64(n, 3).reversed_axes();
+        let answer = &c * alpha;
+        c.scale(alpha);
+        assert_eq!(c, answer);
+    }
+}
+
+#[test]
+fn gen_mat_vec_mul_reversed_vectors() {
+    let alpha = 1.5;
+    let beta = -0.5;
+    for &(m, k) in &[(4, 4), (17, 15), (67, 63)] {
+        for &rev in &[false, true] {
+            let mut a = range_mat64(m, k);
+            if rev {
+                a = a.reversed_axes();
+            }
+            let (m, k) = a.dim();
+            let x = range1_mat64(2 * k);
+            let y = range1_mat64(2 * m);
+            for &(sa0, sa1, sx, sy) in
+                &[(1, 1, -1, 1), (1, 1, 1, -2), (-1, 1, 2, -1), (1, -1, -2, 1)]
+            {
+                let a = a.slice(s![..;sa0, ..;sa1]);
+                let x = x.slice(s![..;sx]);
+                let x = x.slice(s![..k]);
+                let mut y = y.clone();
+                let mut yv = y.slice_mut(s![..;sy]);
+                let mut yv = yv.slice_mut(s![..m]);
+                let answer = alpha * reference_mat_vec_mul(&a, &x) + beta * &yv;
+                general_mat_vec_mul(alpha, &a, &x, beta, &mut yv);
+                assert_relative_eq!(yv, answer, epsilon = 1e-12, max_relative = 1e-12);
+            }
+        }
+    }
+}
+
+#[test]
+fn banded_mat_vec_blas() {
+    for &(m, n) in &[(0, 0), (1, 1), (4, 4), (7, 3), (3, 7), (17, 15)] {
+        for &(kl, ku) in &[(0, 0), (1, 1), (2, 0), (0, 3), (5, 9)] {
+            let a = Array::from_shape_fn((m, n), |(i, j)| {
+                if i <= j + kl && j <= i + ku {
+                    (i * n + j) as f64 - 10.
+                } else {
+                    0.
+                }
+            });
+            // elements outside the band are ignored
+            let a_dirty =
+                &a + &Array::from_shape_fn((m, n), |(i, j)| {
+                    if i <= j + kl && j <= i + ku {
+                        0.
+                    } else {
+                        99.
+                    }
+                });
+            let banded = BandedMatrix::from_dense(&a_dirty, kl, ku);
+            assert_eq!(banded.dim(), (m, n));
+            assert_eq!(banded.to_dense(), a);
+
+            let x = Array::from_iter((0..2 * n).map(|i| i as f64));
+            let x = x.slice(s![..;-2]);
+            assert_eq!(banded.dot(&x), a.dot(&x));
+
+            let mut y = Array::from_iter((0..m).map(|i| i as f64));
+            let answer = a.dot(&x) * 2. + &y * -1.;
+            banded_mat_vec_mul(2., &banded, &x, -1., &mut y);
+            assert_eq!(y, answer);
+        }
+    }
+}
+
+#[test]
+fn hermitian_blas() {
+    let in_triangle = |uplo, i, j| match uplo {
+        Triangle::Upper => i <= j,
+        Triangle::Lower => i >= j,
+    };
+    let range_complex = |m: usize, n: usize| {
+        Array::from_shape_fn((m, n), |(i, j)| {
+            Complex64::new((i * n + j) as f64 - 3., (i + 2 * j) as f64 % 5. - 2.)
+        })
+    };
+    for &(m, n) in &[(0, 2), (1, 1), (4, 4), (5, 2), (3, 17), (17, 15)] {
+        for &uplo in &[Triangle::Upper, Triangle::Lower] {
+            // rank-k update
+            let a = range_complex(m, n);
+            let mut af = Array::zeros((m, n).f());
+            af.assign(&a);
+            let c = range_complex(m, m);
+            let a_h = a.t().mapv(|x| x.conj());
+            let full = a.dot(&a_h) * 2. + &c * -1.;
+            for a in &[a.view(), af.view()] {
+                let mut c1 = c.clone();
+                hermitian_rank_k_update(2., a, -1., &mut c1, uplo);
+                for ((i, j), &x) in c1.indexed_iter() {
+                    if i == j {
+                        assert_eq!(x, Complex64::new(full[[i, j]].re, 0.));
+                    } else if in_triangle(uplo, i, j) {
+                        assert_eq!(x, full[[i, j]]);
+                    } else {
+                        assert_eq!(x, c[[i, j]]);
+                    }
+                }
+            }
+
+            // multiplication; the other triangle and the imaginary part of
+            // the diagonal are never read
+            let h = a.dot(&a_h);
+            let h_dirty = Array::from_shape_fn((m, m), |(i, j)| {
+                if i == j {
+                    h[[i, j]] + Complex64::new(0., 7.)
+                } else if in_triangle(uplo, i, j) {
+                    h[[i, j]]
+                } else {
+                    Complex64::new(99., 99.)
+                }
+            });
+            let mut h_dirty_f = Array::zeros((m, m).f());
+            h_dirty_f.assign(&h_dirty);
+            assert_eq!(Hermitian::new(h_dirty.view(), uplo).to_full(), h);
+            let b = range_complex(m, n);
+            let mut bf = Array::zeros((m, n).f());
+            bf.assign(&b);
+            let alpha = Complex64::new(1., 2.);
+            let beta = Complex64::new(0., -1.);
+            let answer = h.dot(&b) * alpha + &b * beta;
+            for h in &[h_dirty.view(), h_dirty_f.view()] {
+                for b in &[b.view(), bf.view()] {
+                    let mut c = b.to_owned();
+                    hermitian_mat_mul(alpha, Hermitian::new(h.view(), uplo), b, beta, &mut c);
+                    assert_eq!(c, answer);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn givens_rotation_blas() {
+    let (g, r) = Givens::zeroing(-3., 4.);
+    assert_eq!(r, -5.);
+    assert_eq!((g.cos(), g.sin()), (0.6, -0.8));
+    assert_eq!(Givens::zeroing(0., -2.), (Givens::new(0., 1.), -2.));
+    assert_eq!(Givens::zeroing(-2., 0.), (Givens::new(1., 0.), -2.));
+
+    // reduce a matrix to upper triangular form, as in a QR decomposition
+    for &(m, n) in &[(2, 2), (5, 3), (40, 40), (3, 6)] {
+        let a = Array::from_shape_fn((m, n), |(i, j)| {
+            ((i * 7 + j * 3) % 11) as f64 - 5. + (i == j) as u8 as f64
+        });
+        let mut r = a.clone();
+        let mut q = Array::eye(m);
+        for j in 0..n {
+            for i in (j + 1..m).rev() {
+                let (g, rij) = Givens::zeroing(r[[i - 1, j]], r[[i, j]]);
+                g.rotate_rows(&mut r, i - 1, i);
+                g.rotate_columns(&mut q, i - 1, i);
+                assert_abs_diff_eq!(r[[i - 1, j]], rij, epsilon = 1e-12);
+                assert_abs_diff_eq!(r[[i, j]], 0., epsilon = 1e-12);
+            }
+        }
+        assert_abs_diff_eq!(q.dot(&r), a, epsilon = 1e-10);
+        assert_abs_diff_eq!(q.t().dot(&q), Array::eye(m), epsilon = 1e-12);
+    }
+
+    // reversed and strided vectors
+    let x0 = Array::linspace(0., 9., 10);
+    let y0 = Array::linspace(-5., 13., 10);
+    let g = Givens::new(0.6, 0.8);
+    let mut x = x0.clone();
+    let mut y = Array::zeros(20);
+    y.slice_mut(s![..;-2]).assign(&y0);
+    g.rotate(&mut x, &mut y.slice_mut(s![..;-2]));
+    assert_abs_diff_eq!(x, &x0 * 0.6 + &y0 * 0.8, epsilon = 1e-12);
+    assert_abs_diff_eq!(y.slice(s![..;-2]), &y0 * 0.6 - &x0 * 0.8, epsilon = 1e-12);
+}
+
+#[test]
+fn lu_solve() {
+    for &n in &[1, 2, 5, 40] {
+        let a = Array::from_shape_fn((n, n), |(i, j)| {
+            ((i * 7 + j * 3) % 11) as f64 - 5. + (i == j) as u8 as f64 * 20.
+        });
+        let lu = a.factorize_lu().unwrap();
+        assert_abs_diff_eq!(lu.solve(&a).unwrap(), Array::eye(n), epsilon = 1e-10);
+
+        let x = Array::linspace(-1., 2., n);
+        assert_abs_diff_eq!(lu.solve(&a.dot(&x)).unwrap(), x, epsilon = 1e-10);
+        let xs = Array::from_shape_fn((n, 3), |(i, j)| (i * 3 + j) as f64);
+        let mut b = Array::zeros((n, 3).f());
+        b.assign(&a.dot(&xs));
+        assert_abs_diff_eq!(a.t().t().solve_into(b).unwrap(), xs, epsilon = 1e-10);
+        let b = a.dot(&xs);
+        assert_abs_diff_eq!(
+            lu.solve(&b.slice(s![.., ..;-1])).unwrap(),
+            xs.slice(s![.., ..;-1]),
+            epsilon = 1e-10
+        );
+    }
+}
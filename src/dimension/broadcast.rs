@@ -0,0 +1,133 @@
+// Copyright 2014-2016 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::error::{from_kind, ErrorKind, ShapeError};
+use crate::{Dimension, Ix0, Ix1, Ix2, Ix3, Ix4, Ix5, Ix6, IxDyn};
+
+use super::size_of_shape_checked;
+
+/// Calculate the common shape of two shapes, following the broadcasting
+/// rules of NumPy: the shapes are aligned at their last axes, and each
+/// pair of axis lengths must either be equal or include a 1.
+///
+/// **Errors** with `IncompatibleShape` if the shapes can not be broadcast
+/// together, and with `Overflow` if the number of elements in the common
+/// shape would overflow `isize`.
+pub(crate) fn co_broadcast<D1, D2, Output>(shape1: &D1, shape2: &D2) -> Result<Output, ShapeError>
+where
+    D1: Dimension,
+    D2: Dimension,
+    Output: Dimension,
+{
+    if shape1.ndim() < shape2.ndim() {
+        return co_broadcast(shape2, shape1);
+    }
+    // The result has as many axes as `shape1`; `shape2` fills the last ones.
+    let k = shape1.ndim() - shape2.ndim();
+    let mut out = Output::zeros(shape1.ndim());
+    out.slice_mut().copy_from_slice(shape1.slice());
+    for (out, &len) in out.slice_mut()[k..].iter_mut().zip(shape2.slice()) {
+        if *out != len {
+            if *out == 1 {
+                *out = len;
+            } else if len != 1 {
+                return Err(from_kind(ErrorKind::IncompatibleShape));
+            }
+        }
+    }
+    size_of_shape_checked(&out)?;
+    Ok(out)
+}
+
+/// The dimension type of the result of broadcasting two arrays together.
+///
+/// For fixed dimensions it is the one with more axes, and if either is
+/// `IxDyn`, it is `IxDyn`.
+pub trait DimMax<Other: Dimension> {
+    /// The dimension type of the broadcast result.
+    type Output: Dimension;
+}
+
+impl<D: Dimension> DimMax<D> for D {
+    type Output = D;
+}
+
+macro_rules! impl_dim_max {
+    ($smaller:ty, $larger:ty) => {
+        impl DimMax<$larger> for $smaller {
+            type Output = $larger;
+        }
+
+        impl DimMax<$smaller> for $larger {
+            type Output = $larger;
+        }
+    };
+}
+
+impl_dim_max!(Ix0, Ix1);
+impl_dim_max!(Ix0, Ix2);
+impl_dim_max!(Ix0, Ix3);
+impl_dim_max!(Ix0, Ix4);
+impl_dim_max!(Ix0, Ix5);
+impl_dim_max!(Ix0, Ix6);
+impl_dim_max!(Ix1, Ix2);
+impl_dim_max!(Ix1, Ix3);
+impl_dim_max!(Ix1, Ix4);
+impl_dim_max!(Ix1, Ix5);
+impl_dim_max!(Ix1, Ix6);
+impl_dim_max!(Ix2, Ix3);
+impl_dim_max!(Ix2, Ix4);
+impl_dim_max!(Ix2, Ix5);
+impl_dim_max!(Ix2, Ix6);
+impl_dim_max!(Ix3, Ix4);
+impl_dim_max!(Ix3, Ix5);
+impl_dim_max!(Ix3, Ix6);
+impl_dim_max!(Ix4, Ix5);
+impl_dim_max!(Ix4, Ix6);
+impl_dim_max!(Ix5, Ix6);
+impl_dim_max!(Ix0, IxDyn);
+impl_dim_max!(Ix1, IxDyn);
+impl_dim_max!(Ix2, IxDyn);
+impl_dim_max!(Ix3, IxDyn);
+impl_dim_max!(Ix4, IxDyn);
+impl_dim_max!(Ix5, IxDyn);
+impl_dim_max!(Ix6, IxDyn);
+
+#[cfg(test)]
+mod tests {
+    use super::co_broadcast;
+    use crate::{Dim, DimMax, Dimension, ErrorKind, IxDyn, ShapeError};
+
+    fn test_co<D1, D2>(d1: &D1, d2: &D2, expected: Result<<D1 as DimMax<D2>>::Output, ShapeError>)
+    where
+        D1: Dimension + DimMax<D2>,
+        D2: Dimension,
+    {
+        let d = co_broadcast::<D1, D2, <D1 as DimMax<D2>>::Output>(d1, d2);
+        assert_eq!(d, expected);
+    }
+
+    #[test]
+    fn test_co_broadcast() {
+        test_co(&Dim([2, 3]), &Dim([4, 1, 3]), Ok(Dim([4, 2, 3])));
+        test_co(
+            &Dim([1, 2, 2]),
+            &Dim([1, 3, 4]),
+            Err(ShapeError::from_kind(ErrorKind::IncompatibleShape)),
+        );
+        test_co(&Dim([3, 4, 5]), &Dim([]), Ok(Dim([3, 4, 5])));
+        test_co(&Dim([1, 1]), &Dim([0, 1, 3]), Ok(Dim([0, 1, 3])));
+        test_co(&Dim([2, 0]), &Dim([1]), Ok(Dim([2, 0])));
+        test_co(&Dim([2, 1]), &IxDyn(&[5, 1, 4]), Ok(IxDyn(&[5, 2, 4])));
+        test_co(
+            &Dim([usize::max_value(), 1]),
+            &Dim([1, 2]),
+            Err(ShapeError::from_kind(ErrorKind::Overflow)),
+        );
+    }
+}
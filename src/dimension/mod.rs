@@ -7,11 +7,14 @@
 // except according to those terms.
 
 use crate::error::{from_kind, ErrorKind, ShapeError};
+use crate::slice::expand_ellipsis;
 use crate::{Ix, Ixs, Slice, SliceOrIndex};
 use num_integer::div_floor;
 
 pub use self::axes::{axes_of, Axes, AxisDescription};
 pub use self::axis::Axis;
+pub(crate) use self::broadcast::co_broadcast;
+pub use self::broadcast::DimMax;
 pub use self::conversion::IntoDimension;
 pub use self::dim::*;
 pub use self::dimension_trait::Dimension;
@@ -26,6 +29,7 @@ use std::mem;
 mod macros;
 mod axes;
 mod axis;
+mod broadcast;
 mod conversion;
 pub mod dim;
 mod dimension_trait;
@@ -560,13 +564,15 @@ fn slice_min_max(axis_len: usize, slice: Slice) -> Option<(usize, usize)> {
 }
 
 /// Returns `true` iff the slices intersect.
-pub fn slices_intersect<D: Dimension>(
-    dim: &D,
-    indices1: &D::SliceArg,
-    indices2: &D::SliceArg,
-) -> bool {
-    debug_assert_eq!(indices1.as_ref().len(), indices2.as_ref().len());
-    for (&axis_len, &si1, &si2) in izip!(dim.slice(), indices1.as_ref(), indices2.as_ref()) {
+pub fn slices_intersect<D, I1, I2>(dim: &D, indices1: &I1, indices2: &I2) -> bool
+where
+    D: Dimension,
+    I1: AsRef<[SliceOrIndex]> + ?Sized,
+    I2: AsRef<[SliceOrIndex]> + ?Sized,
+{
+    let indices1 = expand_ellipsis(indices1.as_ref(), dim.ndim()).filter(|s| !s.is_new_axis());
+    let indices2 = expand_ellipsis(indices2.as_ref(), dim.ndim()).filter(|s| !s.is_new_axis());
+    for (&axis_len, si1, si2) in izip!(dim.slice(), indices1, indices2) {
         // The slices do not intersect iff any pair of `SliceOrIndex` does not intersect.
         match (si1, si2) {
             (
@@ -614,6 +620,7 @@ pub fn slices_intersect<D: Dimension>(
                     return false;
                 }
             }
+            _ => unreachable!(),
         }
     }
     true
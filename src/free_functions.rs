@@ -9,8 +9,9 @@
 use std::mem::{forget, size_of};
 use std::slice;
 
+use crate::dimension::co_broadcast;
 use crate::imp_prelude::*;
-use crate::{dimension, ArcArray1, ArcArray2};
+use crate::{dimension, ArcArray1, ArcArray2, DimMax, Zip};
 
 /// Create an [**`Array`**](type.Array.html) with one, two or
 /// three dimensions.
@@ -318,3 +319,66 @@ where
 {
     arr3(xs).into_shared()
 }
+
+/// Choose elementwise between `then` and `otherwise` by `condition`.
+///
+/// Each element of the result is the corresponding element of `then` where
+/// `condition` is `true`, and of `otherwise` where it is `false`, like
+/// `numpy.where`. The three arrays are broadcast together (see
+/// [`DimMax`](trait.DimMax.html)), so any of them can be smaller than the
+/// result, for example a zero-dimensional array of a single value.
+///
+/// **Panics** if broadcasting isn’t possible.
+///
+/// ```
+/// use ndarray::{arr0, arr1, arr2, where_};
+///
+/// let a = arr2(&[[1, -2],
+///                [-3, 4]]);
+/// let positive = a.mapv(|x| x > 0);
+/// assert_eq!(where_(&positive, &a, &arr0(0)), arr2(&[[1, 0],
+///                                                    [0, 4]]));
+/// assert_eq!(where_(&positive, &arr1(&[10, 20]), &a), arr2(&[[10, -2],
+///                                                            [-3, 20]]));
+/// // pick whole rows
+/// assert_eq!(where_(&arr2(&[[true], [false]]), &a, &arr0(0)), arr2(&[[1, -2],
+///                                                                    [0, 0]]));
+/// ```
+pub fn where_<A, S1, S2, S3, D, E1, E2>(
+    condition: &ArrayBase<S1, D>,
+    then: &ArrayBase<S2, E1>,
+    otherwise: &ArrayBase<S3, E2>,
+) -> Array<A, <<D as DimMax<E1>>::Output as DimMax<E2>>::Output>
+where
+    A: Clone,
+    S1: Data<Elem = bool>,
+    S2: Data<Elem = A>,
+    S3: Data<Elem = A>,
+    D: Dimension + DimMax<E1>,
+    E1: Dimension,
+    E2: Dimension,
+    <D as DimMax<E1>>::Output: DimMax<E2>,
+{
+    let broadcast_panic = || -> ! {
+        panic!(
+            "ndarray: could not broadcast arrays of shape {:?}, {:?} and {:?} together",
+            condition.shape(),
+            then.shape(),
+            otherwise.shape()
+        )
+    };
+    let shape = co_broadcast::<D, E1, <D as DimMax<E1>>::Output>(&condition.dim, &then.dim)
+        .unwrap_or_else(|_| broadcast_panic());
+    let shape = co_broadcast::<_, E2, <<D as DimMax<E1>>::Output as DimMax<E2>>::Output>(
+        &shape,
+        &otherwise.dim,
+    )
+    .unwrap_or_else(|_| broadcast_panic());
+    let condition = condition.broadcast_unwrap(shape.clone());
+    let then = then.broadcast_unwrap(shape.clone());
+    let otherwise = otherwise.broadcast_unwrap(shape);
+    Zip::from(&condition)
+        .and(&then)
+        .and(&otherwise)
+        .apply_collect(|&c, x, y| if c { x.clone() } else { y.clone() })
+}
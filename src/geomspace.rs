@@ -16,6 +16,27 @@ pub struct Geomspace<F> {
     step: F,
     index: usize,
     len: usize,
+    /// The exact first and last elements and the index of the last.
+    ends: (F, F),
+    last: usize,
+}
+
+impl<F> Geomspace<F>
+where
+    F: Float,
+{
+    #[inline]
+    fn value(&self, i: usize) -> F {
+        if i == 0 {
+            self.ends.0
+        } else if i == self.last {
+            self.ends.1
+        } else {
+            // Calculate the value just like numpy.geomspace does
+            let exponent = self.start + self.step * F::from(i).unwrap();
+            self.sign * exponent.exp()
+        }
+    }
 }
 
 impl<F> Iterator for Geomspace<F>
@@ -29,11 +50,9 @@ where
         if self.index >= self.len {
             None
         } else {
-            // Calculate the value just like numpy.linspace does
             let i = self.index;
             self.index += 1;
-            let exponent = self.start + self.step * F::from(i).unwrap();
-            Some(self.sign * exponent.exp())
+            Some(self.value(i))
         }
     }
 
@@ -53,11 +72,9 @@ where
         if self.index >= self.len {
             None
         } else {
-            // Calculate the value just like numpy.linspace does
             self.len -= 1;
             let i = self.len;
-            let exponent = self.start + self.step * F::from(i).unwrap();
-            Some(self.sign * exponent.exp())
+            Some(self.value(i))
         }
     }
 }
@@ -99,6 +116,8 @@ where
         step,
         index: 0,
         len: n,
+        ends: (a, b),
+        last: n.saturating_sub(1),
     })
 }
 
@@ -169,4 +188,17 @@ mod tests {
     fn zero_included() {
         assert!(geomspace(-1.0, 1.0, 4).is_none());
     }
+
+    #[test]
+    fn exact_endpoints() {
+        for &(a, b) in &[(0.3f64, 7.1), (7.1, 0.3), (-2.9, -1e5)] {
+            for &n in &[1, 2, 3, 17] {
+                let v: Vec<_> = geomspace(a, b, n).unwrap().collect();
+                assert_eq!(v[0], a);
+                if n > 1 {
+                    assert_eq!(v[n - 1], b);
+                }
+            }
+        }
+    }
 }
@@ -7,6 +7,9 @@
 // except according to those terms.
 
 //! Methods for one-dimensional arrays.
+use num_traits::{PrimInt, Zero};
+use std::ops::Add;
+
 use crate::imp_prelude::*;
 
 /// # Methods For 1-D Arrays
@@ -26,4 +29,84 @@ where
             crate::iterators::to_vec(self.iter().cloned())
         }
     }
+
+    /// Count the occurrences of each value in an array of non-negative
+    /// integers.
+    ///
+    /// Element `i` of the result is the number of elements equal to `i`.
+    /// The result has length `max + 1`, where `max` is the largest element,
+    /// or `minlength` if that is larger.
+    ///
+    /// **Panics** if an element is negative or does not fit in `usize`.
+    ///
+    /// ```
+    /// use ndarray::arr1;
+    ///
+    /// let a = arr1(&[1, 3, 1, 0, 3, 3]);
+    /// assert_eq!(a.bincount(0), arr1(&[1, 2, 0, 3]));
+    /// assert_eq!(a.bincount(6), arr1(&[1, 2, 0, 3, 0, 0]));
+    /// ```
+    pub fn bincount(&self, minlength: usize) -> Array1<usize>
+    where
+        A: PrimInt,
+        S: Data,
+    {
+        let mut counts = vec![0; minlength];
+        for x in self.iter() {
+            let i = bin_index(x);
+            if i >= counts.len() {
+                counts.resize(i + 1, 0);
+            }
+            counts[i] += 1;
+        }
+        Array::from(counts)
+    }
+
+    /// Sum the `weights` of each value in an array of non-negative integers.
+    ///
+    /// Element `i` of the result is the sum of `weights[k]` over the indices
+    /// `k` where `self[k]` is equal to `i`. The length of the result is as
+    /// for [`.bincount()`](#method.bincount).
+    ///
+    /// **Panics** if `weights` does not have the same length as `self`, or
+    /// if an element is negative or does not fit in `usize`.
+    ///
+    /// ```
+    /// use ndarray::arr1;
+    ///
+    /// let a = arr1(&[1, 3, 1, 0]);
+    /// let w = arr1(&[0.5, 1., 0.25, 2.]);
+    /// assert_eq!(a.bincount_weighted(&w, 0), arr1(&[2., 0.75, 0., 1.]));
+    /// ```
+    pub fn bincount_weighted<W, S2>(
+        &self,
+        weights: &ArrayBase<S2, Ix1>,
+        minlength: usize,
+    ) -> Array1<W>
+    where
+        A: PrimInt,
+        W: Clone + Zero + Add<Output = W>,
+        S: Data,
+        S2: Data<Elem = W>,
+    {
+        assert_eq!(
+            self.len(),
+            weights.len(),
+            "ndarray: bincount_weighted requires one weight for each element"
+        );
+        let mut sums = vec![W::zero(); minlength];
+        for (x, w) in self.iter().zip(weights) {
+            let i = bin_index(x);
+            if i >= sums.len() {
+                sums.resize(i + 1, W::zero());
+            }
+            sums[i] = sums[i].clone() + w.clone();
+        }
+        Array::from(sums)
+    }
+}
+
+fn bin_index<A: PrimInt>(x: &A) -> usize {
+    x.to_usize()
+        .expect("ndarray: bincount requires non-negative integers that fit in usize")
 }
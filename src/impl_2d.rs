@@ -8,6 +8,8 @@
 
 //! Methods for two-dimensional arrays.
 use crate::imp_prelude::*;
+use crate::Slice;
+use num_traits::Zero;
 
 /// # Methods For 2-D Arrays
 impl<A, S> ArrayBase<S, Ix2>
@@ -114,6 +116,161 @@ where
         self.ncols()
     }
 
+    /// Return a view of the array with the order of the rows reversed
+    /// (flipped upside down), without copying.
+    ///
+    /// This is the same as `.slice(s![..;-1, ..])`; see also
+    /// [`.invert_axis()`](#method.invert_axis).
+    ///
+    /// ```
+    /// use ndarray::array;
+    /// let array = array![[1., 2.], [3., 4.]];
+    /// assert_eq!(array.flipud(), array![[3., 4.], [1., 2.]]);
+    /// ```
+    pub fn flipud(&self) -> ArrayView2<'_, A>
+    where
+        S: Data,
+    {
+        let mut view = self.view();
+        view.invert_axis(Axis(0));
+        view
+    }
+
+    /// Return a view of the array with the order of the columns reversed
+    /// (flipped left to right), without copying.
+    ///
+    /// This is the same as `.slice(s![.., ..;-1])`; see also
+    /// [`.invert_axis()`](#method.invert_axis).
+    ///
+    /// ```
+    /// use ndarray::array;
+    /// let array = array![[1., 2.], [3., 4.]];
+    /// assert_eq!(array.fliplr(), array![[2., 1.], [4., 3.]]);
+    /// ```
+    pub fn fliplr(&self) -> ArrayView2<'_, A>
+    where
+        S: Data,
+    {
+        let mut view = self.view();
+        view.invert_axis(Axis(1));
+        view
+    }
+
+    /// Return a view of the `k`-th diagonal of the array.
+    ///
+    /// The `k`-th diagonal is the main diagonal for `k` = 0, above it for
+    /// `k` > 0 and below it for `k` < 0: the elements (*i*, *j*) with
+    /// *j* − *i* = `k`. It is empty if it lies outside the array.
+    ///
+    /// ```
+    /// use ndarray::array;
+    /// let array = array![[1, 2, 3], [4, 5, 6]];
+    /// assert_eq!(array.diag_offset(0), array![1, 5]);
+    /// assert_eq!(array.diag_offset(1), array![2, 6]);
+    /// assert_eq!(array.diag_offset(-1), array![4]);
+    /// assert!(array.diag_offset(3).is_empty());
+    /// ```
+    pub fn diag_offset(&self, k: isize) -> ArrayView1<'_, A>
+    where
+        S: Data,
+    {
+        let (axis, start) = diagonal_start(k, self.dim());
+        self.slice_axis(axis, Slice::from(start..)).into_diag()
+    }
+
+    /// Return a mutable view of the `k`-th diagonal of the array.
+    ///
+    /// See [`.diag_offset()`](#method.diag_offset).
+    ///
+    /// ```
+    /// use ndarray::array;
+    /// let mut array = array![[1, 2, 3], [4, 5, 6]];
+    /// array.diag_offset_mut(1).fill(0);
+    /// assert_eq!(array, array![[1, 0, 3], [4, 5, 0]]);
+    /// ```
+    pub fn diag_offset_mut(&mut self, k: isize) -> ArrayViewMut1<'_, A>
+    where
+        S: DataMut,
+    {
+        let (axis, start) = diagonal_start(k, self.dim());
+        self.slice_axis_mut(axis, Slice::from(start..)).into_diag()
+    }
+
+    /// Return a copy of the upper triangle of the array, with the elements
+    /// below the `k`-th diagonal set to zero.
+    ///
+    /// The `k`-th diagonal is the main diagonal for `k` = 0, above it for
+    /// `k` > 0 and below it for `k` < 0: element (*i*, *j*) is kept if
+    /// *j* − *i* ≥ `k`.
+    ///
+    /// ```
+    /// use ndarray::array;
+    /// let array = array![[1, 2, 3], [4, 5, 6], [7, 8, 9]];
+    /// assert_eq!(array.triu(0), array![[1, 2, 3], [0, 5, 6], [0, 0, 9]]);
+    /// assert_eq!(array.triu(-1), array![[1, 2, 3], [4, 5, 6], [0, 8, 9]]);
+    /// ```
+    pub fn triu(&self, k: isize) -> Array2<A>
+    where
+        A: Clone + Zero,
+        S: Data,
+    {
+        let mut triu = self.to_owned();
+        triu.triu_inplace(k);
+        triu
+    }
+
+    /// Return a copy of the lower triangle of the array, with the elements
+    /// above the `k`-th diagonal set to zero.
+    ///
+    /// Element (*i*, *j*) is kept if *j* − *i* ≤ `k`; see
+    /// [`.triu()`](#method.triu).
+    ///
+    /// ```
+    /// use ndarray::array;
+    /// let array = array![[1, 2, 3], [4, 5, 6], [7, 8, 9]];
+    /// assert_eq!(array.tril(0), array![[1, 0, 0], [4, 5, 0], [7, 8, 9]]);
+    /// assert_eq!(array.tril(1), array![[1, 2, 0], [4, 5, 6], [7, 8, 9]]);
+    /// ```
+    pub fn tril(&self, k: isize) -> Array2<A>
+    where
+        A: Clone + Zero,
+        S: Data,
+    {
+        let mut tril = self.to_owned();
+        tril.tril_inplace(k);
+        tril
+    }
+
+    /// Set the elements below the `k`-th diagonal to zero, in place.
+    ///
+    /// See [`.triu()`](#method.triu).
+    pub fn triu_inplace(&mut self, k: isize)
+    where
+        A: Clone + Zero,
+        S: DataMut,
+    {
+        let ncols = self.ncols();
+        for (i, mut row) in self.genrows_mut().into_iter().enumerate() {
+            let end = diagonal_column(i, k, ncols);
+            row.slice_mut(s![..end]).fill(A::zero());
+        }
+    }
+
+    /// Set the elements above the `k`-th diagonal to zero, in place.
+    ///
+    /// See [`.tril()`](#method.tril).
+    pub fn tril_inplace(&mut self, k: isize)
+    where
+        A: Clone + Zero,
+        S: DataMut,
+    {
+        let ncols = self.ncols();
+        for (i, mut row) in self.genrows_mut().into_iter().enumerate() {
+            let start = diagonal_column(i, k.saturating_add(1), ncols);
+            row.slice_mut(s![start..]).fill(A::zero());
+        }
+    }
+
     /// Return true if the array is square, false otherwise.
     ///
     /// # Examples
@@ -133,3 +290,23 @@ where
         self.nrows() == self.ncols()
     }
 }
+
+/// Return the column of row `i` on the `k`-th diagonal, clamped to
+/// `0..=ncols`.
+fn diagonal_column(i: usize, k: isize, ncols: usize) -> usize {
+    if k < 0 {
+        i.saturating_sub(k.wrapping_neg() as usize).min(ncols)
+    } else {
+        i.saturating_add(k as usize).min(ncols)
+    }
+}
+
+/// Return the axis and the index along it where the `k`-th diagonal of an
+/// array of shape `dim` starts, clamped to the length of that axis.
+fn diagonal_start(k: isize, (nrows, ncols): (usize, usize)) -> (Axis, usize) {
+    if k < 0 {
+        (Axis(0), (k.wrapping_neg() as usize).min(nrows))
+    } else {
+        (Axis(1), (k as usize).min(ncols))
+    }
+}
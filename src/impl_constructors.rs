@@ -22,6 +22,7 @@ use crate::imp_prelude::*;
 use crate::indexes;
 use crate::indices;
 use crate::iterators::{to_vec, to_vec_mapped};
+use crate::RawDataSubst;
 use crate::StrideShape;
 use crate::{geomspace, linspace, logspace};
 
@@ -51,6 +52,7 @@ where
 
     /// Create a one-dimensional array with `n` evenly spaced elements from
     /// `start` to `end` (inclusive). `A` must be a floating point type.
+    /// The first and last elements are exactly `start` and `end`.
     ///
     /// Note that if `start > end`, the first element will still be `start`,
     /// and the following elements will be decreasing. This is different from
@@ -121,6 +123,7 @@ where
 
     /// Create a one-dimensional array with `n` geometrically spaced elements
     /// from `start` to `end` (inclusive). `A` must be a floating point type.
+    /// The first and last elements are exactly `start` and `end`.
     ///
     /// Returns `None` if `start` and `end` have different signs or if either
     /// one is zero. Conceptually, this means that in order to obtain a `Some`
@@ -174,6 +177,46 @@ where
         eye
     }
 
+    /// Create an identity matrix of size `n`; the same as
+    /// [`eye`](#method.eye).
+    ///
+    /// **Panics** if `n * n` would overflow `isize`.
+    pub fn identity(n: Ix) -> Self
+    where
+        S: DataMut,
+        A: Clone + Zero + One,
+    {
+        Self::eye(n)
+    }
+
+    /// Create a matrix of shape `shape` with ones on the `k`-th diagonal
+    /// and zeros elsewhere.
+    ///
+    /// `k` selects the diagonal like in
+    /// [`.diag_offset()`](#method.diag_offset): zero is the main diagonal,
+    /// positive `k` is above it and negative `k` below it. If the diagonal
+    /// is outside of the matrix, the result is all zeros.
+    ///
+    /// **Panics** if the number of elements would overflow `isize`.
+    ///
+    /// ```rust
+    /// use ndarray::{Array2, arr2};
+    ///
+    /// let a = Array2::<i32>::eye_offset((2, 4), 1);
+    /// assert_eq!(a, arr2(&[[0, 1, 0, 0],
+    ///                      [0, 0, 1, 0]]));
+    /// ```
+    pub fn eye_offset<Sh>(shape: Sh, k: isize) -> Self
+    where
+        S: DataMut,
+        A: Clone + Zero + One,
+        Sh: ShapeBuilder<Dim = Ix2>,
+    {
+        let mut eye = Self::zeros(shape);
+        eye.diag_offset_mut(k).fill(A::one());
+        eye
+    }
+
     /// Create a 2D matrix from its diagonal
     ///
     /// **Panics** if `diag.len() * diag.len()` would overflow `isize`.
@@ -499,6 +542,39 @@ where
         v.set_len(size);
         Self::from_shape_vec_unchecked(shape, v)
     }
+
+    /// Create an array with uninitialized elements, shape `shape`, of the
+    /// same storage kind as `Self` but with element type `MaybeUninit<A>`.
+    ///
+    /// This is the same as [`maybe_uninit`], but the element type is given
+    /// as `A`, so that it can be written `Array::<f64, _>::uninit(shape)`.
+    /// Once all elements are written, the array is converted to an array of
+    /// `A` using [`.assume_init()`].
+    ///
+    /// **Panics** if the number of elements in `shape` would overflow isize.
+    ///
+    /// [`maybe_uninit`]: ArrayBase::maybe_uninit
+    /// [`.assume_init()`]: ArrayBase::assume_init
+    ///
+    /// ```
+    /// use ndarray::{Array, Array2};
+    /// use std::mem::MaybeUninit;
+    ///
+    /// let mut a = Array2::<i32>::uninit((2, 3));
+    /// for ((i, j), elt) in a.indexed_iter_mut() {
+    ///     *elt = MaybeUninit::new((i * 3 + j) as i32);
+    /// }
+    /// let a = unsafe { a.assume_init() };
+    /// assert_eq!(a, Array::from_shape_fn((2, 3), |(i, j)| (i * 3 + j) as i32));
+    /// ```
+    pub fn uninit<Sh>(shape: Sh) -> ArrayBase<<S as RawDataSubst<MaybeUninit<A>>>::Output, D>
+    where
+        S: RawDataSubst<MaybeUninit<A>>,
+        <S as RawDataSubst<MaybeUninit<A>>>::Output: DataOwned,
+        Sh: ShapeBuilder<Dim = D>,
+    {
+        ArrayBase::maybe_uninit(shape)
+    }
 }
 
 impl<S, A, D> ArrayBase<S, D>
@@ -17,7 +17,8 @@ use crate::arraytraits;
 use crate::dimension;
 use crate::dimension::IntoDimension;
 use crate::dimension::{
-    abs_index, axes_of, do_slice, merge_axes, size_of_shape_checked, stride_offset, Axes,
+    abs_index, axes_of, co_broadcast, do_slice, merge_axes, size_of_shape_checked, stride_offset,
+    Axes, DimMax,
 };
 use crate::error::{self, ErrorKind, ShapeError};
 use crate::itertools::zip;
@@ -27,9 +28,9 @@ use crate::iter::{
     AxisChunksIter, AxisChunksIterMut, AxisIter, AxisIterMut, ExactChunks, ExactChunksMut,
     IndexedIter, IndexedIterMut, Iter, IterMut, Lanes, LanesMut, Windows,
 };
-use crate::slice::MultiSlice;
+use crate::slice::{expand_ellipsis, MultiSlice};
 use crate::stacking::concatenate;
-use crate::{NdIndex, Slice, SliceInfo, SliceOrIndex};
+use crate::{NdIndex, Shape, ShapeBuilder, Slice, SliceInDim, SliceInfo, SliceOrIndex};
 
 /// # Methods For All Array Types
 impl<A, S, D> ArrayBase<S, D>
@@ -323,9 +324,11 @@ where
     /// [`D::SliceArg`]: trait.Dimension.html#associatedtype.SliceArg
     ///
     /// **Panics** if an index is out of bounds or step size is zero.<br>
-    /// (**Panics** if `D` is `IxDyn` and `info` does not match the number of array axes.)
-    pub fn slice<Do>(&self, info: &SliceInfo<D::SliceArg, Do>) -> ArrayView<'_, A, Do>
+    /// (**Panics** if `D` or `Din` is `IxDyn` and `info` does not match the number of array axes.)
+    pub fn slice<T, Din, Do>(&self, info: &SliceInfo<T, Din, Do>) -> ArrayView<'_, A, Do>
     where
+        T: AsRef<[SliceOrIndex]> + ?Sized,
+        Din: SliceInDim<D>,
         Do: Dimension,
         S: Data,
     {
@@ -341,15 +344,58 @@ where
     /// [`D::SliceArg`]: trait.Dimension.html#associatedtype.SliceArg
     ///
     /// **Panics** if an index is out of bounds or step size is zero.<br>
-    /// (**Panics** if `D` is `IxDyn` and `info` does not match the number of array axes.)
-    pub fn slice_mut<Do>(&mut self, info: &SliceInfo<D::SliceArg, Do>) -> ArrayViewMut<'_, A, Do>
+    /// (**Panics** if `D` or `Din` is `IxDyn` and `info` does not match the number of array axes.)
+    pub fn slice_mut<T, Din, Do>(&mut self, info: &SliceInfo<T, Din, Do>) -> ArrayViewMut<'_, A, Do>
     where
+        T: AsRef<[SliceOrIndex]> + ?Sized,
+        Din: SliceInDim<D>,
         Do: Dimension,
         S: DataMut,
     {
         self.view_mut().slice_move(info)
     }
 
+    /// Assign `values` to the slice of the array given by `info`.
+    ///
+    /// This is a shorthand for `.slice_mut(info).assign(values)`; `values`
+    /// is broadcast to the shape of the slice.
+    ///
+    /// See [*Slicing*](#slicing) for full documentation.
+    ///
+    /// **Panics** if an index is out of bounds or step size is zero, or if
+    /// `values` can not be broadcast to the shape of the slice.<br>
+    /// (**Panics** if `D` or `Din` is `IxDyn` and `info` does not match the number of array axes.)
+    ///
+    /// ```
+    /// use ndarray::{arr1, arr2, s, Array2};
+    ///
+    /// let mut a = Array2::zeros((3, 4));
+    /// a.slice_assign(s![1.., ..;2], &arr2(&[[1, 2], [3, 4]]));
+    /// a.slice_assign(s![0, 1..], &arr1(&[5]));
+    /// assert_eq!(a, arr2(&[[0, 5, 5, 5],
+    ///                      [1, 0, 2, 0],
+    ///                      [3, 0, 4, 0]]));
+    /// ```
+    pub fn slice_assign<T, Din, Do, S2, E>(
+        &mut self,
+        info: &SliceInfo<T, Din, Do>,
+        values: &ArrayBase<S2, E>,
+    ) where
+        T: AsRef<[SliceOrIndex]> + ?Sized,
+        Din: SliceInDim<D>,
+        Do: Dimension,
+        S: DataMut,
+        A: Clone,
+        S2: Data<Elem = A>,
+        E: Dimension,
+    {
+        let mut view = self.slice_mut(info);
+        match values.broadcast(view.raw_dim()) {
+            Some(values) => view.assign(&values),
+            None => slice_assign_error(values.shape(), (**info).as_ref(), view.shape()),
+        }
+    }
+
     /// Return multiple disjoint, sliced, mutable views of the array.
     ///
     /// See [*Slicing*](#slicing) for full documentation.
@@ -383,6 +429,43 @@ where
         info.multi_slice_move(self.view_mut())
     }
 
+    /// Split the array along `axis` into `n` views of nearly equal length,
+    /// in order.
+    ///
+    /// This is [`ArrayView::split`](#method.split) on a view of the array,
+    /// so it also works for owned and shared arrays; see there for how the
+    /// lengths of the parts are chosen.
+    ///
+    /// **Panics** if `axis` is out of bounds or `n` is zero.
+    ///
+    /// ```
+    /// use ndarray::{arr2, Axis};
+    ///
+    /// let a = arr2(&[[0, 1],
+    ///                [2, 3],
+    ///                [4, 5]]);
+    /// let parts = a.split_view(Axis(0), 2);
+    /// assert_eq!(parts[0], arr2(&[[0, 1], [2, 3]]));
+    /// assert_eq!(parts[1], arr2(&[[4, 5]]));
+    /// ```
+    pub fn split_view(&self, axis: Axis, n: usize) -> Vec<ArrayView<'_, A, D>>
+    where
+        S: Data,
+    {
+        self.view().split(axis, n)
+    }
+
+    /// Split the array along `axis` into `n` mutable views of nearly equal
+    /// length, in order; see [`.split_view()`](#method.split_view).
+    ///
+    /// **Panics** if `axis` is out of bounds or `n` is zero.
+    pub fn split_view_mut(&mut self, axis: Axis, n: usize) -> Vec<ArrayViewMut<'_, A, D>>
+    where
+        S: DataMut,
+    {
+        self.view_mut().split(axis, n)
+    }
+
     /// Slice the array, possibly changing the number of dimensions.
     ///
     /// See [*Slicing*](#slicing) for full documentation.
@@ -392,30 +475,46 @@ where
     /// [`D::SliceArg`]: trait.Dimension.html#associatedtype.SliceArg
     ///
     /// **Panics** if an index is out of bounds or step size is zero.<br>
-    /// (**Panics** if `D` is `IxDyn` and `info` does not match the number of array axes.)
-    pub fn slice_move<Do>(mut self, info: &SliceInfo<D::SliceArg, Do>) -> ArrayBase<S, Do>
+    /// (**Panics** if `D` or `Din` is `IxDyn` and `info` does not match the number of array axes.)
+    pub fn slice_move<T, Din, Do>(mut self, info: &SliceInfo<T, Din, Do>) -> ArrayBase<S, Do>
     where
+        T: AsRef<[SliceOrIndex]> + ?Sized,
+        Din: SliceInDim<D>,
         Do: Dimension,
     {
-        // Slice and collapse in-place without changing the number of dimensions.
-        self.slice_collapse(&*info);
-
         let indices: &[SliceOrIndex] = (**info).as_ref();
-
-        // Copy the dim and strides that remain after removing the subview axes.
-        let out_ndim = info.out_ndim();
+        let out_ndim = expand_ellipsis(indices, self.ndim())
+            .filter(|s| s.is_slice() || s.is_new_axis())
+            .count();
         let mut new_dim = Do::zeros(out_ndim);
         let mut new_strides = Do::zeros(out_ndim);
-        izip!(self.dim.slice(), self.strides.slice(), indices)
-            .filter_map(|(d, s, slice_or_index)| match slice_or_index {
-                SliceOrIndex::Slice { .. } => Some((d, s)),
-                SliceOrIndex::Index(_) => None,
-            })
-            .zip(izip!(new_dim.slice_mut(), new_strides.slice_mut()))
-            .for_each(|((d, s), (new_d, new_s))| {
-                *new_d = *d;
-                *new_s = *s;
-            });
+
+        // Slice and collapse the input axes in order, copying the dim and
+        // strides of the sliced axes and inserting the new axes.
+        let mut axis = 0;
+        let mut new_axis = 0;
+        for slice_or_index in expand_ellipsis(indices, self.ndim()) {
+            match slice_or_index {
+                SliceOrIndex::Slice { start, end, step } => {
+                    self.slice_axis_inplace(Axis(axis), Slice { start, end, step });
+                    new_dim[new_axis] = self.dim[axis];
+                    new_strides[new_axis] = self.strides[axis];
+                    axis += 1;
+                    new_axis += 1;
+                }
+                SliceOrIndex::Index(index) => {
+                    let i_usize = abs_index(self.len_of(Axis(axis)), index);
+                    self.collapse_axis(Axis(axis), i_usize);
+                    axis += 1;
+                }
+                SliceOrIndex::NewAxis => {
+                    new_dim[new_axis] = 1;
+                    new_strides[new_axis] = 0;
+                    new_axis += 1;
+                }
+                SliceOrIndex::Ellipsis => unreachable!(),
+            }
+        }
 
         ArrayBase {
             ptr: self.ptr,
@@ -437,15 +536,15 @@ where
     ///
     /// [`D::SliceArg`]: trait.Dimension.html#associatedtype.SliceArg
     ///
-    /// **Panics** if an index is out of bounds or step size is zero.<br>
+    /// **Panics** if an index is out of bounds or step size is zero, or if
+    /// `indices` contains a `NewAxis`.<br>
     /// (**Panics** if `D` is `IxDyn` and `indices` does not match the number of array axes.)
     pub fn slice_collapse(&mut self, indices: &D::SliceArg) {
         let indices: &[SliceOrIndex] = indices.as_ref();
-        assert_eq!(indices.len(), self.ndim());
-        indices
-            .iter()
+        let ndim = self.ndim();
+        expand_ellipsis(indices, ndim)
             .enumerate()
-            .for_each(|(axis, &slice_or_index)| match slice_or_index {
+            .for_each(|(axis, slice_or_index)| match slice_or_index {
                 SliceOrIndex::Slice { start, end, step } => {
                     self.slice_axis_inplace(Axis(axis), Slice { start, end, step })
                 }
@@ -453,6 +552,8 @@ where
                     let i_usize = abs_index(self.len_of(Axis(axis)), index);
                     self.collapse_axis(Axis(axis), i_usize)
                 }
+                SliceOrIndex::NewAxis => panic!("ndarray: slice_collapse can not insert a new axis"),
+                SliceOrIndex::Ellipsis => unreachable!(),
             });
     }
 
@@ -659,6 +760,48 @@ where
         );
     }
 
+    /// Swap the subviews at indices `index1` and `index2` along `axis`,
+    /// like two rows or two columns of a matrix, element by element.
+    ///
+    /// Indices may be equal.
+    ///
+    /// ***Panics*** if `axis` or an index is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{arr2, Axis};
+    ///
+    /// let mut a = arr2(&[[1, 2, 3],
+    ///                    [4, 5, 6]]);
+    /// a.swap_axes_content(Axis(1), 0, 2);
+    /// assert_eq!(a, arr2(&[[3, 2, 1],
+    ///                      [6, 5, 4]]));
+    /// a.swap_axes_content(Axis(0), 0, 1);
+    /// assert_eq!(a, arr2(&[[6, 5, 4],
+    ///                      [3, 2, 1]]));
+    /// ```
+    pub fn swap_axes_content(&mut self, axis: Axis, index1: usize, index2: usize)
+    where
+        S: DataMut,
+    {
+        let len = self.len_of(axis);
+        assert!(
+            index1 < len && index2 < len,
+            "ndarray: index out of bounds in swap_axes_content"
+        );
+        if index1 == index2 {
+            return;
+        }
+        let (low, high) = if index1 < index2 {
+            (index1, index2)
+        } else {
+            (index2, index1)
+        };
+        let (mut first, mut second) = self.view_mut().split_at(axis, high);
+        Zip::from(first.slice_axis_mut(axis, Slice::from(low..low + 1)))
+            .and(second.slice_axis_mut(axis, Slice::from(0..1)))
+            .apply(std::mem::swap);
+    }
+
     // `get` for zero-dimensional arrays
     // panics if dimension is not zero. otherwise an element is always present.
     fn get_0d(&self) -> &A
@@ -844,6 +987,330 @@ where
         }
     }
 
+    /// Along `axis`, gather the subviews at `indices` into a new array.
+    ///
+    /// Subview `k` along `axis` of the result is subview `indices[k]` of
+    /// `self`; indices may repeat and be in any order. Unlike
+    /// [`.select()`](#method.select), the elements only need to be `Clone`.
+    ///
+    /// **Panics** if `axis` or an element of `indices` is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{arr2, Axis};
+    ///
+    /// let x = arr2(&[[0, 1, 2],
+    ///                [3, 4, 5]]);
+    /// assert_eq!(x.take(Axis(1), &[2, 0, 2]), arr2(&[[2, 0, 2],
+    ///                                                [5, 3, 5]]));
+    /// ```
+    pub fn take(&self, axis: Axis, indices: &[Ix]) -> Array<A, D>
+    where
+        A: Clone,
+        S: Data,
+    {
+        self.check_take_indices(axis, indices);
+        let mut dim = self.raw_dim();
+        dim.set_axis(axis, indices.len());
+        Array::from_shape_fn(dim, |index| {
+            let mut index = index.into_dimension();
+            index[axis.index()] = indices[index[axis.index()]];
+            self[index].clone()
+        })
+    }
+
+    /// Gather the elements at the coordinates in `indices` into a new array,
+    /// with one array of indices for each axis of `self`.
+    ///
+    /// The index arrays are broadcast together, and element `i` of the
+    /// result is `self[[indices[0][i], indices[1][i], ...]]`. This is the
+    /// NumPy expression `a[i0, i1, ...]` with integer arrays `i0`, `i1`, ….
+    ///
+    /// **Panics** if `indices` does not have one array for each axis of
+    /// `self`, if the index arrays can not be broadcast together, or if an
+    /// index is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{arr1, arr2};
+    ///
+    /// let x = arr2(&[[0, 1, 2],
+    ///                [3, 4, 5]]);
+    ///
+    /// // The elements at (1, 0), (0, 2) and (1, 1)
+    /// let rows = arr1(&[1, 0, 1]);
+    /// let cols = arr1(&[0, 2, 1]);
+    /// assert_eq!(x.gather(&[rows.view(), cols.view()]), arr1(&[3, 2, 4]));
+    ///
+    /// // A column of row indices broadcasts with a row of column indices.
+    /// let rows = arr2(&[[1], [0]]);
+    /// let cols = arr2(&[[2, 0]]);
+    /// assert_eq!(x.gather(&[rows.view(), cols.view()]), arr2(&[[5, 3],
+    ///                                                          [2, 0]]));
+    /// ```
+    pub fn gather<S2, E>(&self, indices: &[ArrayBase<S2, E>]) -> Array<A, E>
+    where
+        A: Clone,
+        S: Data,
+        S2: Data<Elem = Ix>,
+        E: Dimension,
+    {
+        assert_eq!(
+            indices.len(),
+            self.ndim(),
+            "ndarray: gather requires one index array for each axis"
+        );
+        // Broadcasting with all ones (or no axes) leaves a shape unchanged.
+        let mut dim = E::zeros(E::NDIM.unwrap_or(0));
+        for len in dim.slice_mut() {
+            *len = 1;
+        }
+        for idx in indices {
+            dim = match co_broadcast::<E, E, E>(&dim, &idx.dim) {
+                Ok(dim) => dim,
+                Err(_) => gather_broadcast_error(dim.slice(), idx.shape()),
+            };
+        }
+        let indices: Vec<_> = indices
+            .iter()
+            .map(|idx| idx.broadcast_unwrap(dim.clone()))
+            .collect();
+        for (axis, idx) in indices.iter().enumerate() {
+            self.check_take_indices(Axis(axis), idx);
+        }
+
+        let mut source = self.raw_dim();
+        Array::from_shape_fn(dim, |index| {
+            let index = index.into_dimension();
+            for (axis, idx) in indices.iter().enumerate() {
+                source[axis] = idx[index.clone()];
+            }
+            self[source.clone()].clone()
+        })
+    }
+
+    /// Along `axis`, gather the subviews at `indices` into `out`, like
+    /// [`.take()`](#method.take) but without allocating.
+    ///
+    /// **Panics** if `axis` or an element of `indices` is out of bounds, or
+    /// if `out` does not have the shape of `self` with the length of `axis`
+    /// replaced by `indices.len()`.
+    ///
+    /// ```
+    /// use ndarray::{arr2, Array2, Axis};
+    ///
+    /// let x = arr2(&[[0, 1, 2],
+    ///                [3, 4, 5]]);
+    /// let mut batch = Array2::zeros((3, 3));
+    /// x.take_into(Axis(0), &[1, 1, 0], &mut batch);
+    /// assert_eq!(batch, arr2(&[[3, 4, 5],
+    ///                          [3, 4, 5],
+    ///                          [0, 1, 2]]));
+    /// ```
+    pub fn take_into<S2>(&self, axis: Axis, indices: &[Ix], out: &mut ArrayBase<S2, D>)
+    where
+        A: Clone,
+        S: Data,
+        S2: DataMut<Elem = A>,
+    {
+        self.check_take_indices(axis, indices);
+        let mut dim = self.raw_dim();
+        dim.set_axis(axis, indices.len());
+        if out.raw_dim() != dim {
+            take_shape_error(out.shape(), dim.slice());
+        }
+        for (k, &i) in indices.iter().enumerate() {
+            out.slice_axis_mut(axis, Slice::from(k..k + 1))
+                .assign(&self.slice_axis(axis, Slice::from(i..i + 1)));
+        }
+    }
+
+    /// Along `axis`, assign subview `k` of `values` to the subview at
+    /// `indices[k]` of `self`, for each `k`; the inverse of
+    /// [`.take()`](#method.take).
+    ///
+    /// If an index occurs more than once, the last of its subviews is
+    /// assigned. The other axes of `values` are broadcast to the shape of
+    /// `self`.
+    ///
+    /// **Panics** if `axis` or an element of `indices` is out of bounds, if
+    /// the length of `axis` in `values` is not `indices.len()`, or if
+    /// broadcasting isn’t possible.
+    ///
+    /// ```
+    /// use ndarray::{arr2, Array2, Axis};
+    ///
+    /// let mut a = Array2::zeros((3, 2));
+    /// a.put(Axis(0), &[2, 0], &arr2(&[[1, 2],
+    ///                                 [3, 4]]));
+    /// assert_eq!(a, arr2(&[[3, 4],
+    ///                      [0, 0],
+    ///                      [1, 2]]));
+    /// ```
+    pub fn put<S2>(&mut self, axis: Axis, indices: &[Ix], values: &ArrayBase<S2, D>)
+    where
+        S: DataMut,
+        A: Clone,
+        S2: Data<Elem = A>,
+    {
+        self.check_take_indices(axis, indices);
+        assert_eq!(
+            values.len_of(axis),
+            indices.len(),
+            "ndarray: put requires one subview of values for each index"
+        );
+        for (k, &i) in indices.iter().enumerate() {
+            self.slice_axis_mut(axis, Slice::from(i..i + 1))
+                .assign(&values.slice_axis(axis, Slice::from(k..k + 1)));
+        }
+    }
+
+    /// Assign `values` to the subview at `index` along `axis`.
+    ///
+    /// This is a shorthand for `.index_axis_mut(axis, index).assign(values)`;
+    /// `values` is broadcast to the shape of the subview.
+    ///
+    /// **Panics** if `axis` or `index` is out of bounds, or if broadcasting
+    /// isn’t possible.
+    ///
+    /// ```
+    /// use ndarray::{arr1, arr2, Array2, Axis};
+    ///
+    /// let mut a = Array2::zeros((2, 3));
+    /// a.index_axis_assign(Axis(1), 2, &arr1(&[5, 6]));
+    /// a.index_axis_assign(Axis(0), 0, &arr1(&[1]));
+    /// assert_eq!(a, arr2(&[[1, 1, 1],
+    ///                      [0, 0, 6]]));
+    /// ```
+    pub fn index_axis_assign<E, S2>(&mut self, axis: Axis, index: Ix, values: &ArrayBase<S2, E>)
+    where
+        S: DataMut,
+        A: Clone,
+        S2: Data<Elem = A>,
+        D: RemoveAxis,
+        E: Dimension,
+    {
+        self.index_axis_mut(axis, index).assign(values);
+    }
+
+    /// Return the elements where `mask` is `true`, in logical order, as a
+    /// one-dimensional array.
+    ///
+    /// **Panics** if `mask` does not have the same shape as `self`.
+    ///
+    /// ```
+    /// use ndarray::{arr1, arr2};
+    ///
+    /// let a = arr2(&[[1, -2],
+    ///                [-3, 4]]);
+    /// assert_eq!(a.select_where(&a.mapv(|x| x > 0)), arr1(&[1, 4]));
+    /// ```
+    pub fn select_where<S2>(&self, mask: &ArrayBase<S2, D>) -> Array<A, Ix1>
+    where
+        A: Clone,
+        S: Data,
+        S2: Data<Elem = bool>,
+    {
+        self.check_mask_shape(mask);
+        let selected: Vec<_> = self
+            .iter()
+            .zip(mask.iter())
+            .filter(|&(_, &m)| m)
+            .map(|(x, _)| x.clone())
+            .collect();
+        Array::from(selected)
+    }
+
+    /// Assign the elements of `values` to the elements of `self` where
+    /// `mask` is `true`.
+    ///
+    /// `values` is broadcast to the shape of `self`, so it can also be a
+    /// zero-dimensional array of a single value; see also
+    /// [`.fill_where()`](#method.fill_where).
+    ///
+    /// **Panics** if `mask` does not have the same shape as `self`, or if
+    /// broadcasting isn’t possible.
+    ///
+    /// ```
+    /// use ndarray::{arr1, arr2};
+    ///
+    /// let mut a = arr2(&[[1, -2],
+    ///                    [-3, 4]]);
+    /// let mask = a.mapv(|x| x < 0);
+    /// a.assign_where(&mask, &arr1(&[10, 20]));
+    /// assert_eq!(a, arr2(&[[1, 20],
+    ///                      [10, 4]]));
+    /// ```
+    pub fn assign_where<S2, S3, E>(&mut self, mask: &ArrayBase<S2, D>, values: &ArrayBase<S3, E>)
+    where
+        S: DataMut,
+        A: Clone,
+        S2: Data<Elem = bool>,
+        S3: Data<Elem = A>,
+        E: Dimension,
+    {
+        self.check_mask_shape(mask);
+        let values = values.broadcast_unwrap(self.raw_dim());
+        Zip::from(self.view_mut())
+            .and(mask)
+            .and(&values)
+            .apply(|x, &m, y| {
+                if m {
+                    *x = y.clone();
+                }
+            });
+    }
+
+    /// Assign `value` to the elements of `self` where `mask` is `true`.
+    ///
+    /// **Panics** if `mask` does not have the same shape as `self`.
+    ///
+    /// ```
+    /// use ndarray::arr2;
+    ///
+    /// let mut a = arr2(&[[1., -2.],
+    ///                    [-3., 4.]]);
+    /// let mask = a.mapv(|x| x < 0.);
+    /// a.fill_where(&mask, 0.);
+    /// assert_eq!(a, arr2(&[[1., 0.],
+    ///                      [0., 4.]]));
+    /// ```
+    pub fn fill_where<S2>(&mut self, mask: &ArrayBase<S2, D>, value: A)
+    where
+        S: DataMut,
+        A: Clone,
+        S2: Data<Elem = bool>,
+    {
+        self.check_mask_shape(mask);
+        Zip::from(self.view_mut()).and(mask).apply(|x, &m| {
+            if m {
+                *x = value.clone();
+            }
+        });
+    }
+
+    fn check_mask_shape<S2>(&self, mask: &ArrayBase<S2, D>)
+    where
+        S2: RawData<Elem = bool>,
+    {
+        if self.shape() != mask.shape() {
+            mask_shape_error(mask.shape(), self.shape());
+        }
+    }
+
+    fn check_take_indices<'a, I>(&self, axis: Axis, indices: I)
+    where
+        I: IntoIterator<Item = &'a Ix>,
+    {
+        let len = self.len_of(axis);
+        if let Some(&i) = indices.into_iter().find(|&&i| i >= len) {
+            panic!(
+                "ndarray: index {} is out of bounds for axis {} of length {}",
+                i,
+                axis.index(),
+                len
+            );
+        }
+    }
+
     /// Return a producer and iterable that traverses over the *generalized*
     /// rows of the array. For a 2D array these are the regular rows.
     ///
@@ -1204,6 +1671,42 @@ where
         Windows::new(self.view(), window_size)
     }
 
+    /// Return a window producer and iterable, with the windows `stride`
+    /// elements apart along each axis.
+    ///
+    /// This is like [`.windows()`](#method.windows), which has a stride of 1
+    /// along every axis. With a stride equal to the window size, the windows
+    /// don’t overlap, like [`.exact_chunks()`](#method.exact_chunks); with a
+    /// larger stride, elements between the windows are skipped.
+    ///
+    /// **Panics** if any dimension of `window_size` or `stride` is zero.<br>
+    /// (**Panics** if `D` is `IxDyn` and `window_size` or `stride` does not
+    /// match the number of array axes.)
+    ///
+    /// ```
+    /// use ndarray::{arr2, Array};
+    ///
+    /// let a = Array::from_shape_fn((4, 5), |(i, j)| 10 * i + j);
+    ///
+    /// // Maximum of each 2×2 window, with steps of 2 rows and 3 columns
+    /// let pooled: Vec<_> = a
+    ///     .windows_with_stride((2, 2), (2, 3))
+    ///     .into_iter()
+    ///     .map(|w| *w.iter().max().unwrap())
+    ///     .collect();
+    /// assert_eq!(pooled, vec![11, 14, 31, 34]);
+    ///
+    /// let second = a.windows_with_stride((2, 2), (2, 3)).into_iter().nth(1).unwrap();
+    /// assert_eq!(second, arr2(&[[3, 4], [13, 14]]));
+    /// ```
+    pub fn windows_with_stride<E>(&self, window_size: E, stride: E) -> Windows<'_, A, D>
+    where
+        E: IntoDimension<Dim = D>,
+        S: Data,
+    {
+        Windows::new_with_stride(self.view(), window_size, stride)
+    }
+
     // Return (length, stride) for diagonal
     fn diag_params(&self) -> (Ix, Ixs) {
         /* empty shape has len 1 */
@@ -1538,6 +2041,107 @@ where
         }
     }
 
+    /// Return the elements of the array in `shape`, as a view if the memory
+    /// layout allows it and as a new array otherwise.
+    ///
+    /// Any shape with the same number of elements is accepted. The elements
+    /// are read and placed in row-major order, or in column-major order if
+    /// the shape is given with [`.f()`](trait.ShapeBuilder.html#tymethod.f),
+    /// regardless of the memory layout of `self`. The result is a view if
+    /// `self` is contiguous in that order; otherwise the elements are copied
+    /// into a new array with that memory order.
+    ///
+    /// **Errors** if the shapes don't have the same number of elements.
+    ///
+    /// ```
+    /// use ndarray::{arr2, aview1, ShapeBuilder};
+    ///
+    /// let a = arr2(&[[1, 2, 3],
+    ///                [4, 5, 6]]);
+    /// let b = a.to_shape(6).unwrap();
+    /// assert!(b.is_view());
+    /// assert_eq!(b, aview1(&[1, 2, 3, 4, 5, 6]));
+    ///
+    /// // the transpose is not contiguous in row-major order
+    /// let t = a.t();
+    /// let c = t.to_shape(6).unwrap();
+    /// assert!(c.is_owned());
+    /// assert_eq!(c, aview1(&[1, 4, 2, 5, 3, 6]));
+    ///
+    /// // but it is in column-major order
+    /// let d = t.to_shape((6, 1).f()).unwrap();
+    /// assert!(d.is_view());
+    /// assert_eq!(d.column(0), aview1(&[1, 2, 3, 4, 5, 6]));
+    /// ```
+    pub fn to_shape<E>(&self, shape: E) -> Result<CowArray<'_, A, E::Dim>, ShapeError>
+    where
+        S: Data,
+        A: Clone,
+        E: ShapeBuilder,
+    {
+        let Shape { dim, is_c } = shape.into_shape();
+        if size_of_shape_checked(&dim) != Ok(self.dim.size()) {
+            return Err(error::incompatible_shapes(&self.dim, &dim));
+        }
+        if is_c && self.is_standard_layout() {
+            let strides = dim.default_strides();
+            unsafe { Ok(CowArray::from(ArrayView::new(self.ptr, dim, strides))) }
+        } else if !is_c && self.raw_view().reversed_axes().is_standard_layout() {
+            let strides = dim.fortran_strides();
+            unsafe { Ok(CowArray::from(ArrayView::new(self.ptr, dim, strides))) }
+        } else {
+            let v = if is_c {
+                self.iter().cloned().collect::<Vec<A>>()
+            } else {
+                // The reversed axes visit the elements in column-major order.
+                self.view().reversed_axes().iter().cloned().collect()
+            };
+            let array = unsafe { Array::from_shape_vec_unchecked(dim.set_f(!is_c), v) };
+            Ok(CowArray::from(array))
+        }
+    }
+
+    /// Return a one-dimensional array with the elements of `self` in
+    /// logical (row-major) order.
+    ///
+    /// ```
+    /// use ndarray::{arr1, arr2};
+    ///
+    /// let a = arr2(&[[1, 2],
+    ///                [3, 4]]);
+    /// assert_eq!(a.flatten(), arr1(&[1, 2, 3, 4]));
+    /// assert_eq!(a.t().flatten(), arr1(&[1, 3, 2, 4]));
+    /// ```
+    pub fn flatten(&self) -> Array1<A>
+    where
+        S: Data,
+        A: Clone,
+    {
+        self.to_shape(self.len()).unwrap().into_owned()
+    }
+
+    /// Return a one-dimensional view of the elements of `self` in logical
+    /// (row-major) order, or `None` if the array is not in standard layout.
+    ///
+    /// ```
+    /// use ndarray::{arr2, aview1};
+    ///
+    /// let a = arr2(&[[1, 2],
+    ///                [3, 4]]);
+    /// assert_eq!(a.flatten_view(), Some(aview1(&[1, 2, 3, 4])));
+    /// assert_eq!(a.t().flatten_view(), None);
+    /// ```
+    pub fn flatten_view(&self) -> Option<ArrayView1<'_, A>>
+    where
+        S: Data,
+    {
+        if self.is_standard_layout() {
+            Some(self.view().into_shape(self.len()).unwrap())
+        } else {
+            None
+        }
+    }
+
     /// Convert any array or array view to a dynamic dimensional array or
     /// array view (respectively).
     ///
@@ -1680,6 +2284,52 @@ where
         unsafe { Some(ArrayView::new(self.ptr, dim, broadcast_strides)) }
     }
 
+    /// Act like a larger size and/or shape array by *broadcasting* `self`
+    /// and `other` to their common shape.
+    ///
+    /// The common shape follows the NumPy broadcasting rules: the shapes
+    /// are aligned at their last axes, and each pair of axis lengths must
+    /// either be equal or include a 1, which is repeated. The arithmetic
+    /// operators between two arrays broadcast their operands this way.
+    ///
+    /// **Errors** with `IncompatibleShape` if the shapes can not be
+    /// broadcast together.
+    ///
+    /// ```
+    /// use ndarray::{arr1, arr2, aview2};
+    ///
+    /// let a = arr2(&[[1], [2], [3]]);
+    /// let b = arr1(&[10, 20]);
+    /// let (a2, b2) = a.broadcast_with(&b).unwrap();
+    /// assert_eq!(a2, aview2(&[[1, 1], [2, 2], [3, 3]]));
+    /// assert_eq!(b2, aview2(&[[10, 20]; 3]));
+    ///
+    /// assert!(a.broadcast_with(&arr2(&[[1, 2], [3, 4]])).is_err());
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn broadcast_with<'a, 'b, B, S2, E>(
+        &'a self,
+        other: &'b ArrayBase<S2, E>,
+    ) -> Result<
+        (
+            ArrayView<'a, A, <D as DimMax<E>>::Output>,
+            ArrayView<'b, B, <D as DimMax<E>>::Output>,
+        ),
+        ShapeError,
+    >
+    where
+        S: Data,
+        S2: Data<Elem = B>,
+        D: DimMax<E>,
+        E: Dimension,
+    {
+        let shape = co_broadcast::<D, E, <D as DimMax<E>>::Output>(&self.dim, &other.dim)?;
+        // Both arrays can be broadcast to the common shape by construction.
+        let view1 = self.broadcast(shape.clone()).unwrap();
+        let view2 = other.broadcast(shape).unwrap();
+        Ok((view1, view2))
+    }
+
     /// Swap axes `ax` and `bx`.
     ///
     /// This does not move any data, it just adjusts the array’s dimensions
@@ -1895,6 +2545,32 @@ where
         self.index_axis_move(axis, 0)
     }
 
+    /// Remove all axes of length 1 and return the result as a
+    /// dynamic-dimensional array or view.
+    ///
+    /// Like [`.insert_axis()`](#method.insert_axis), this only changes the
+    /// shape and strides. Use [`.index_axis_move()`](#method.index_axis_move)
+    /// with index 0 to remove a single axis of length 1 and keep a static
+    /// dimension type.
+    ///
+    /// ```
+    /// use ndarray::{arr1, Array};
+    ///
+    /// let a = Array::from_shape_vec((1, 3, 1), vec![1, 2, 3]).unwrap();
+    /// assert_eq!(a.squeeze(), arr1(&[1, 2, 3]).into_dyn());
+    /// assert_eq!(Array::from_elem((1, 1), 7).squeeze().shape(), &[]);
+    /// ```
+    pub fn squeeze(self) -> ArrayBase<S, IxDyn> {
+        let mut a = self.into_dyn();
+        for i in (0..a.ndim()).rev() {
+            if a.dim[i] == 1 {
+                a.dim = a.dim.remove_axis(Axis(i));
+                a.strides = a.strides.remove_axis(Axis(i));
+            }
+        }
+        a
+    }
+
     fn pointer_is_inbounds(&self) -> bool {
         match self.data._data_slice() {
             None => {
@@ -2048,6 +2724,290 @@ where
         }
     }
 
+    /// Return `true` if `predicate` is `true` for all elements, or if the
+    /// array is empty.
+    ///
+    /// Elements are visited in arbitrary order, and the traversal stops at
+    /// the first element for which `predicate` is `false`.
+    ///
+    /// ```
+    /// use ndarray::arr2;
+    ///
+    /// let a = arr2(&[[1., 2.],
+    ///                [3., 4.]]);
+    /// assert!(a.all(|x: &f64| x.is_finite()));
+    /// assert!(!a.all(|&x| x < 4.));
+    /// ```
+    pub fn all<F>(&self, predicate: F) -> bool
+    where
+        F: FnMut(&A) -> bool,
+        S: Data,
+    {
+        Zip::from(self).all(predicate)
+    }
+
+    /// Return `true` if `predicate` is `true` for any element; `false` if
+    /// the array is empty.
+    ///
+    /// Elements are visited in arbitrary order, and the traversal stops at
+    /// the first element for which `predicate` is `true`.
+    ///
+    /// ```
+    /// use ndarray::arr2;
+    ///
+    /// let a = arr2(&[[1., 2.],
+    ///                [3., std::f64::NAN]]);
+    /// assert!(a.any(|x| x.is_nan()));
+    /// assert!(!a.any(|&x| x < 1.));
+    /// ```
+    pub fn any<F>(&self, mut predicate: F) -> bool
+    where
+        F: FnMut(&A) -> bool,
+        S: Data,
+    {
+        !Zip::from(self).all(|x| !predicate(x))
+    }
+
+    /// Return, for each lane along `axis`, whether `predicate` is `true` for
+    /// all of its elements.
+    ///
+    /// Each lane is traversed until the first element for which `predicate`
+    /// is `false`.
+    ///
+    /// ```
+    /// use ndarray::{arr1, arr2, Axis};
+    ///
+    /// let a = arr2(&[[1, 2],
+    ///                [3, 0]]);
+    /// assert_eq!(a.all_axis(Axis(0), |&x| x > 0), arr1(&[true, false]));
+    /// assert_eq!(a.all_axis(Axis(1), |&x| x > 0), arr1(&[true, false]));
+    /// ```
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    pub fn all_axis<F>(&self, axis: Axis, mut predicate: F) -> Array<bool, D::Smaller>
+    where
+        F: FnMut(&A) -> bool,
+        S: Data,
+        D: RemoveAxis,
+    {
+        self.map_axis(axis, |lane| lane.iter().all(&mut predicate))
+    }
+
+    /// Return, for each lane along `axis`, whether `predicate` is `true` for
+    /// any of its elements.
+    ///
+    /// Each lane is traversed until the first element for which `predicate`
+    /// is `true`.
+    ///
+    /// ```
+    /// use ndarray::{arr1, arr2, Axis};
+    ///
+    /// let a = arr2(&[[1, 2],
+    ///                [3, 0]]);
+    /// assert_eq!(a.any_axis(Axis(0), |&x| x > 2), arr1(&[true, false]));
+    /// assert_eq!(a.any_axis(Axis(1), |&x| x > 2), arr1(&[false, true]));
+    /// ```
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    pub fn any_axis<F>(&self, axis: Axis, mut predicate: F) -> Array<bool, D::Smaller>
+    where
+        F: FnMut(&A) -> bool,
+        S: Data,
+        D: RemoveAxis,
+    {
+        self.map_axis(axis, |lane| lane.iter().any(&mut predicate))
+    }
+
+    /// Compare the elements of `self` and `rhs` with `>`, returning an array
+    /// of the results.
+    ///
+    /// `rhs` is broadcast to the shape of `self`; use
+    /// [`.gt_scalar()`](#method.gt_scalar) to compare with a single value.
+    /// The result composes with boolean mask methods like
+    /// [`.select_where()`](#method.select_where). The other elementwise
+    /// comparisons `ge`, `lt`, `le`, `eq_elem` and `ne_elem`, and their
+    /// `_scalar` variants, work the same way.
+    ///
+    /// **Panics** if broadcasting isn’t possible.
+    ///
+    /// ```
+    /// use ndarray::{arr1, arr2, aview0};
+    ///
+    /// let a = arr2(&[[1, 2],
+    ///                [3, 4]]);
+    /// assert_eq!(a.gt(&aview0(&2)), arr2(&[[false, false],
+    ///                                      [true, true]]));
+    /// assert_eq!(a.gt(&arr1(&[0, 3])), arr2(&[[true, false],
+    ///                                         [true, true]]));
+    /// assert_eq!(a.select_where(&a.gt_scalar(&2)), arr1(&[3, 4]));
+    /// ```
+    pub fn gt<S2, E>(&self, rhs: &ArrayBase<S2, E>) -> Array<bool, D>
+    where
+        A: PartialOrd,
+        S: Data,
+        S2: Data<Elem = A>,
+        E: Dimension,
+    {
+        self.compare_elem(rhs, |x, y| x > y)
+    }
+
+    /// Compare the elements of `self` and `rhs` with `>=`, returning an
+    /// array of the results; see [`.gt()`](#method.gt).
+    ///
+    /// **Panics** if broadcasting isn’t possible.
+    pub fn ge<S2, E>(&self, rhs: &ArrayBase<S2, E>) -> Array<bool, D>
+    where
+        A: PartialOrd,
+        S: Data,
+        S2: Data<Elem = A>,
+        E: Dimension,
+    {
+        self.compare_elem(rhs, |x, y| x >= y)
+    }
+
+    /// Compare the elements of `self` and `rhs` with `<`, returning an array
+    /// of the results; see [`.gt()`](#method.gt).
+    ///
+    /// **Panics** if broadcasting isn’t possible.
+    pub fn lt<S2, E>(&self, rhs: &ArrayBase<S2, E>) -> Array<bool, D>
+    where
+        A: PartialOrd,
+        S: Data,
+        S2: Data<Elem = A>,
+        E: Dimension,
+    {
+        self.compare_elem(rhs, |x, y| x < y)
+    }
+
+    /// Compare the elements of `self` and `rhs` with `<=`, returning an
+    /// array of the results; see [`.gt()`](#method.gt).
+    ///
+    /// **Panics** if broadcasting isn’t possible.
+    pub fn le<S2, E>(&self, rhs: &ArrayBase<S2, E>) -> Array<bool, D>
+    where
+        A: PartialOrd,
+        S: Data,
+        S2: Data<Elem = A>,
+        E: Dimension,
+    {
+        self.compare_elem(rhs, |x, y| x <= y)
+    }
+
+    /// Compare the elements of `self` and `rhs` with `==`, returning an
+    /// array of the results; see [`.gt()`](#method.gt).
+    ///
+    /// (`==` on arrays compares the whole arrays instead.)
+    ///
+    /// **Panics** if broadcasting isn’t possible.
+    pub fn eq_elem<S2, E>(&self, rhs: &ArrayBase<S2, E>) -> Array<bool, D>
+    where
+        A: PartialEq,
+        S: Data,
+        S2: Data<Elem = A>,
+        E: Dimension,
+    {
+        self.compare_elem(rhs, |x, y| x == y)
+    }
+
+    /// Compare the elements of `self` and `rhs` with `!=`, returning an
+    /// array of the results; see [`.gt()`](#method.gt).
+    ///
+    /// **Panics** if broadcasting isn’t possible.
+    pub fn ne_elem<S2, E>(&self, rhs: &ArrayBase<S2, E>) -> Array<bool, D>
+    where
+        A: PartialEq,
+        S: Data,
+        S2: Data<Elem = A>,
+        E: Dimension,
+    {
+        self.compare_elem(rhs, |x, y| x != y)
+    }
+
+    /// Compare each element of `self` with the scalar `x` using `>`,
+    /// returning an array of the results.
+    ///
+    /// ```
+    /// use ndarray::arr2;
+    ///
+    /// let a = arr2(&[[1., 2.],
+    ///                [3., 4.]]);
+    /// assert_eq!(a.gt_scalar(&2.5), arr2(&[[false, false],
+    ///                                      [true, true]]));
+    /// ```
+    pub fn gt_scalar(&self, x: &A) -> Array<bool, D>
+    where
+        A: PartialOrd,
+        S: Data,
+    {
+        self.map(|elt| elt > x)
+    }
+
+    /// Compare each element of `self` with the scalar `x` using `>=`,
+    /// returning an array of the results; see
+    /// [`.gt_scalar()`](#method.gt_scalar).
+    pub fn ge_scalar(&self, x: &A) -> Array<bool, D>
+    where
+        A: PartialOrd,
+        S: Data,
+    {
+        self.map(|elt| elt >= x)
+    }
+
+    /// Compare each element of `self` with the scalar `x` using `<`,
+    /// returning an array of the results; see
+    /// [`.gt_scalar()`](#method.gt_scalar).
+    pub fn lt_scalar(&self, x: &A) -> Array<bool, D>
+    where
+        A: PartialOrd,
+        S: Data,
+    {
+        self.map(|elt| elt < x)
+    }
+
+    /// Compare each element of `self` with the scalar `x` using `<=`,
+    /// returning an array of the results; see
+    /// [`.gt_scalar()`](#method.gt_scalar).
+    pub fn le_scalar(&self, x: &A) -> Array<bool, D>
+    where
+        A: PartialOrd,
+        S: Data,
+    {
+        self.map(|elt| elt <= x)
+    }
+
+    /// Compare each element of `self` with the scalar `x` using `==`,
+    /// returning an array of the results; see
+    /// [`.gt_scalar()`](#method.gt_scalar).
+    pub fn eq_elem_scalar(&self, x: &A) -> Array<bool, D>
+    where
+        A: PartialEq,
+        S: Data,
+    {
+        self.map(|elt| elt == x)
+    }
+
+    /// Compare each element of `self` with the scalar `x` using `!=`,
+    /// returning an array of the results; see
+    /// [`.gt_scalar()`](#method.gt_scalar).
+    pub fn ne_elem_scalar(&self, x: &A) -> Array<bool, D>
+    where
+        A: PartialEq,
+        S: Data,
+    {
+        self.map(|elt| elt != x)
+    }
+
+    fn compare_elem<S2, E, F>(&self, rhs: &ArrayBase<S2, E>, f: F) -> Array<bool, D>
+    where
+        S: Data,
+        S2: Data<Elem = A>,
+        E: Dimension,
+        F: Fn(&A, &A) -> bool,
+    {
+        let rhs = rhs.broadcast_unwrap(self.raw_dim());
+        Zip::from(self).and(&rhs).apply_collect(f)
+    }
+
     /// Call `f` by reference on each element and create a new array
     /// with the new values.
     ///
@@ -2139,7 +3099,8 @@ where
     /// Call `f` by **v**alue on each element, update the array with the new values
     /// and return it.
     ///
-    /// Elements are visited in arbitrary order.
+    /// Elements are visited in arbitrary order. See
+    /// [`.mapv_into_any()`](#method.mapv_into_any) to change the element type.
     pub fn mapv_into<F>(mut self, f: F) -> Self
     where
         S: DataMut,
@@ -2290,6 +3251,37 @@ where
         }
     }
 
+    /// Call `f` with a mutable view of each lane along `axis`, for
+    /// computations that modify whole lanes in place, like normalization.
+    ///
+    /// Lanes are visited in arbitrary order. This is the same as
+    /// `Zip::from(self.lanes_mut(axis)).apply(f)`; with the `rayon` crate
+    /// feature, the lanes can be processed in parallel by using `par_apply`
+    /// in its place.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{arr2, Axis};
+    ///
+    /// let mut a = arr2(&[[1., 3.],
+    ///                    [2., 2.]]);
+    /// // normalize each row to sum to one
+    /// a.apply_along_axis(Axis(1), |mut row| {
+    ///     let sum = row.sum();
+    ///     row /= sum;
+    /// });
+    /// assert_eq!(a, arr2(&[[0.25, 0.75],
+    ///                      [0.5, 0.5]]));
+    /// ```
+    pub fn apply_along_axis<F>(&mut self, axis: Axis, f: F)
+    where
+        S: DataMut,
+        F: FnMut(ArrayViewMut1<'_, A>),
+    {
+        Zip::from(self.lanes_mut(axis)).apply(f);
+    }
+
     /// Iterates over pairs of consecutive elements along the axis.
     ///
     /// The first argument to the closure is an element, and the second
@@ -2345,4 +3337,108 @@ where
             f(&*prev, &mut *curr)
         });
     }
+
+    /// Return a copy of the array with its elements shifted cyclically by
+    /// `shift` positions along `axis`.
+    ///
+    /// The element at index *i* along `axis` moves to index (*i* + `shift`)
+    /// modulo the length of `axis`; a negative `shift` moves elements
+    /// towards the start.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    ///
+    /// ```
+    /// use ndarray::{arr2, Axis};
+    ///
+    /// let a = arr2(&[[1, 2, 3],
+    ///                [4, 5, 6]]);
+    /// assert_eq!(a.roll(Axis(1), 1), arr2(&[[3, 1, 2],
+    ///                                       [6, 4, 5]]));
+    /// assert_eq!(a.roll(Axis(0), -3), arr2(&[[4, 5, 6],
+    ///                                        [1, 2, 3]]));
+    /// ```
+    pub fn roll(&self, axis: Axis, shift: isize) -> Array<A, D>
+    where
+        A: Clone,
+        S: Data,
+    {
+        let mut rolled = self.to_owned();
+        rolled.roll_inplace(axis, shift);
+        rolled
+    }
+
+    /// Shift the elements of the array cyclically by `shift` positions
+    /// along `axis`, in place.
+    ///
+    /// See [`.roll()`](#method.roll). Lanes along `axis` that are contiguous
+    /// in memory are rotated as slices.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    pub fn roll_inplace(&mut self, axis: Axis, shift: isize)
+    where
+        S: DataMut,
+    {
+        let len = self.len_of(axis);
+        if len == 0 {
+            return;
+        }
+        let k = ((shift % len as isize) + len as isize) as usize % len;
+        if k == 0 {
+            return;
+        }
+        for mut lane in self.lanes_mut(axis) {
+            if let Some(slc) = lane.as_slice_mut() {
+                slc.rotate_right(k);
+                continue;
+            }
+            // Rotate right by k as three reversals.
+            for &(start, end) in &[(0, len), (0, k), (k, len)] {
+                let (mut i, mut j) = (start, end);
+                while i + 1 < j {
+                    lane.swap(i, j - 1);
+                    i += 1;
+                    j -= 1;
+                }
+            }
+        }
+    }
+}
+
+#[cold]
+#[inline(never)]
+fn take_shape_error(out: &[usize], expected: &[usize]) -> ! {
+    panic!(
+        "ndarray: output of shape {:?} does not match the shape {:?} of take",
+        out, expected
+    );
+}
+
+#[cold]
+#[inline(never)]
+fn slice_assign_error(values: &[usize], info: &[SliceOrIndex], slice: &[usize]) -> ! {
+    let info: Vec<_> = info.iter().map(|s| s.to_string()).collect();
+    panic!(
+        "ndarray: could not broadcast values of shape {:?} to the slice [{}] of shape {:?}",
+        values,
+        info.join(", "),
+        slice
+    );
+}
+
+#[cold]
+#[inline(never)]
+fn gather_broadcast_error(dim: &[usize], shape: &[usize]) -> ! {
+    panic!(
+        "ndarray: could not broadcast index arrays of shapes {:?} and {:?}",
+        dim, shape
+    );
+}
+
+#[cold]
+#[inline(never)]
+fn mask_shape_error(mask: &[usize], shape: &[usize]) -> ! {
+    panic!(
+        "ndarray: mask of shape {:?} does not match array of shape {:?}",
+        mask, shape
+    );
 }
@@ -53,11 +53,13 @@ macro_rules! impl_binary_op(
 /// Perform elementwise
 #[doc=$doc]
 /// between `self` and `rhs`,
-/// and return the result (based on `self`).
+/// and return the result.
 ///
 /// `self` must be an `Array` or `ArcArray`.
 ///
-/// If their shapes disagree, `rhs` is broadcast to the shape of `self`.
+/// If their shapes disagree, `self` and `rhs` are broadcast to their common
+/// shape; see [`broadcast_with`](struct.ArrayBase.html#method.broadcast_with).
+/// The result reuses the storage of `self` if it already has that shape.
 ///
 /// **Panics** if broadcasting isn’t possible.
 impl<A, B, S, S2, D, E> $trt<ArrayBase<S2, E>> for ArrayBase<S, D>
@@ -66,11 +68,11 @@ where
     B: Clone,
     S: DataOwned<Elem=A> + DataMut,
     S2: Data<Elem=B>,
-    D: Dimension,
+    D: Dimension + DimMax<E>,
     E: Dimension,
 {
-    type Output = ArrayBase<S, D>;
-    fn $mth(self, rhs: ArrayBase<S2, E>) -> ArrayBase<S, D>
+    type Output = ArrayBase<S, <D as DimMax<E>>::Output>;
+    fn $mth(self, rhs: ArrayBase<S2, E>) -> Self::Output
     {
         self.$mth(&rhs)
     }
@@ -79,9 +81,13 @@ where
 /// Perform elementwise
 #[doc=$doc]
 /// between `self` and reference `rhs`,
-/// and return the result (based on `self`).
+/// and return the result.
+///
+/// `self` must be an `Array` or `ArcArray`.
 ///
-/// If their shapes disagree, `rhs` is broadcast to the shape of `self`.
+/// If their shapes disagree, `self` and `rhs` are broadcast to their common
+/// shape; see [`broadcast_with`](struct.ArrayBase.html#method.broadcast_with).
+/// The result reuses the storage of `self` if it already has that shape.
 ///
 /// **Panics** if broadcasting isn’t possible.
 impl<'a, A, B, S, S2, D, E> $trt<&'a ArrayBase<S2, E>> for ArrayBase<S, D>
@@ -90,16 +96,30 @@ where
     B: Clone,
     S: DataOwned<Elem=A> + DataMut,
     S2: Data<Elem=B>,
-    D: Dimension,
+    D: Dimension + DimMax<E>,
     E: Dimension,
 {
-    type Output = ArrayBase<S, D>;
-    fn $mth(mut self, rhs: &ArrayBase<S2, E>) -> ArrayBase<S, D>
+    type Output = ArrayBase<S, <D as DimMax<E>>::Output>;
+    fn $mth(self, rhs: &ArrayBase<S2, E>) -> Self::Output
     {
-        self.zip_mut_with(rhs, |x, y| {
-            *x = x.clone() $operator y.clone();
-        });
-        self
+        let shape = match co_broadcast::<D, E, <D as DimMax<E>>::Output>(&self.dim, &rhs.dim) {
+            Ok(shape) => shape,
+            Err(_) => broadcast_error(self.shape(), rhs.shape()),
+        };
+        if shape.slice() == self.shape() {
+            let mut out = self.into_dimensionality::<<D as DimMax<E>>::Output>().unwrap();
+            out.zip_mut_with(rhs, |x, y| {
+                *x = x.clone() $operator y.clone();
+            });
+            out
+        } else {
+            let lhs = self.broadcast(shape.clone()).unwrap();
+            let rhs = rhs.broadcast(shape.clone()).unwrap();
+            let v = lhs.iter().zip(rhs.iter())
+                .map(|(x, y)| x.clone() $operator y.clone())
+                .collect();
+            ArrayBase::from_shape_vec(shape, v).unwrap()
+        }
     }
 }
 
@@ -108,7 +128,8 @@ where
 /// between references `self` and `rhs`,
 /// and return the result as a new `Array`.
 ///
-/// If their shapes disagree, `rhs` is broadcast to the shape of `self`.
+/// If their shapes disagree, `self` and `rhs` are broadcast to their common
+/// shape; see [`broadcast_with`](struct.ArrayBase.html#method.broadcast_with).
 ///
 /// **Panics** if broadcasting isn’t possible.
 impl<'a, A, B, S, S2, D, E> $trt<&'a ArrayBase<S2, E>> for &'a ArrayBase<S, D>
@@ -117,13 +138,16 @@ where
     B: Clone,
     S: Data<Elem=A>,
     S2: Data<Elem=B>,
-    D: Dimension,
+    D: Dimension + DimMax<E>,
     E: Dimension,
 {
-    type Output = Array<A, D>;
-    fn $mth(self, rhs: &'a ArrayBase<S2, E>) -> Array<A, D> {
-        // FIXME: Can we co-broadcast arrays here? And how?
-        self.to_owned().$mth(rhs)
+    type Output = Array<A, <D as DimMax<E>>::Output>;
+    fn $mth(self, rhs: &'a ArrayBase<S2, E>) -> Self::Output {
+        let (lhs, rhs) = match self.broadcast_with(rhs) {
+            Ok(views) => views,
+            Err(_) => broadcast_error(self.shape(), rhs.shape()),
+        };
+        Zip::from(&lhs).and(&rhs).apply_collect(|x, y| x.clone() $operator y.clone())
     }
 }
 
@@ -223,7 +247,9 @@ impl<'a, S, D> $trt<&'a ArrayBase<S, D>> for $scalar
 
 mod arithmetic_ops {
     use super::*;
+    use crate::dimension::co_broadcast;
     use crate::imp_prelude::*;
+    use crate::{DimMax, Zip};
 
     use num_complex::Complex;
     use std::ops::*;
@@ -349,6 +375,15 @@ mod arithmetic_ops {
             self.map(Not::not)
         }
     }
+
+    #[cold]
+    #[inline(never)]
+    fn broadcast_error(shape1: &[usize], shape2: &[usize]) -> ! {
+        panic!(
+            "ndarray: could not broadcast arrays of shape {:?} and {:?} together",
+            shape1, shape2
+        );
+    }
 }
 
 mod assign_ops {
@@ -378,6 +413,23 @@ mod assign_ops {
                 }
             }
 
+            #[doc=$doc]
+            /// If their shapes disagree, `rhs` is broadcast to the shape of `self`.
+            ///
+            /// **Panics** if broadcasting isn’t possible.
+            impl<A, S, S2, D, E> $trt<ArrayBase<S2, E>> for ArrayBase<S, D>
+            where
+                A: Clone + $trt<A>,
+                S: DataMut<Elem = A>,
+                S2: Data<Elem = A>,
+                D: Dimension,
+                E: Dimension,
+            {
+                fn $method(&mut self, rhs: ArrayBase<S2, E>) {
+                    self.$method(&rhs);
+                }
+            }
+
             #[doc=$doc]
             impl<A, S, D> $trt<A> for ArrayBase<S, D>
             where
@@ -1,4 +1,7 @@
 
+use std::mem;
+use std::ptr;
+
 use crate::imp_prelude::*;
 
 /// Methods specific to `Array0`.
@@ -57,4 +60,56 @@ where
     pub fn into_raw_vec(self) -> Vec<A> {
         self.data.into_vec()
     }
+
+    /// Call `f` by **v**alue on each element and return an array of the
+    /// results, which may have a different element type than `self`.
+    ///
+    /// If `A` and `B` have the same size and alignment, and the array uses
+    /// all of its allocation, the allocation is reused for the result;
+    /// otherwise the result is a new array, like with
+    /// [`.mapv()`](struct.ArrayBase.html#method.mapv). In both cases the
+    /// result has the same memory layout as `self` if `self` is contiguous.
+    ///
+    /// Elements are visited in arbitrary order.
+    ///
+    /// ```
+    /// use ndarray::arr2;
+    ///
+    /// let a = arr2(&[[1, 2],
+    ///                [3, 4]]);
+    /// let ptr = a.as_ptr() as usize;
+    /// let b = a.mapv_into_any(|x| x as f32 / 2.);
+    /// assert_eq!(b, arr2(&[[0.5, 1.],
+    ///                      [1.5, 2.]]));
+    /// // `i32` and `f32` have the same size, so no allocation was needed
+    /// assert_eq!(b.as_ptr() as usize, ptr);
+    /// ```
+    pub fn mapv_into_any<B, F>(self, mut f: F) -> Array<B, D>
+    where
+        A: Clone,
+        F: FnMut(A) -> B,
+    {
+        let reuse = mem::size_of::<A>() == mem::size_of::<B>()
+            && mem::align_of::<A>() == mem::align_of::<B>()
+            && self.as_slice_memory_order().map(<[A]>::len) == Some(self.data.len());
+        if !reuse {
+            return self.mapv(f);
+        }
+        let dim = self.dim.clone();
+        let strides = self.strides.clone();
+        // While the elements are mapped, the vector holds both `A`s and `B`s;
+        // if `f` panics, the elements are leaked instead of dropped.
+        let mut v = mem::ManuallyDrop::new(self.data.into_vec());
+        let (ptr, len, cap) = (v.as_mut_ptr(), v.len(), v.capacity());
+        unsafe {
+            for i in 0..len {
+                let elt = ptr.add(i);
+                ptr::write(elt as *mut B, f(ptr::read(elt)));
+            }
+            // The size and alignment of `B` and `A` agree, so the allocation
+            // has the layout of a vector of `cap` elements of `B`.
+            let v = Vec::from_raw_parts(ptr as *mut B, len, cap);
+            ArrayBase::from_shape_vec_unchecked(dim.strides(strides), v)
+        }
+    }
 }
@@ -8,6 +8,7 @@
 
 use crate::imp_prelude::*;
 use crate::slice::MultiSlice;
+use crate::split_at::SplitAt;
 
 /// Methods for read-only array views.
 impl<'a, A, D> ArrayView<'a, A, D>
@@ -93,6 +94,33 @@ where
             (left.deref_into_view(), right.deref_into_view())
         }
     }
+
+    /// Split the array view along `axis` into `n` views of nearly equal
+    /// length, in order.
+    ///
+    /// If the length of `axis` is *L*, the first *L* % `n` views have length
+    /// *L* / `n` + 1 along `axis` and the rest have length *L* / `n`; some
+    /// views are empty if `n` is greater than *L*.
+    ///
+    /// To split an owned or shared array, use
+    /// [`.split_view()`](#method.split_view).
+    ///
+    /// **Panics** if `axis` is out of bounds or `n` is zero.
+    ///
+    /// ```
+    /// use ndarray::{arr2, Axis};
+    ///
+    /// let a = arr2(&[[0, 1, 2, 3, 4],
+    ///                [5, 6, 7, 8, 9]]);
+    /// let parts = a.view().split(Axis(1), 3);
+    /// assert_eq!(parts[0], arr2(&[[0, 1], [5, 6]]));
+    /// assert_eq!(parts[1], arr2(&[[2, 3], [7, 8]]));
+    /// assert_eq!(parts[2], arr2(&[[4], [9]]));
+    /// ```
+    pub fn split(self, axis: Axis, n: usize) -> Vec<Self> {
+        let len = self.len_of(axis);
+        split_into(self, axis, len, n)
+    }
 }
 
 /// Methods for read-write array views.
@@ -111,6 +139,22 @@ where
         }
     }
 
+    /// Split the array view along `axis` into `n` mutable views of nearly
+    /// equal length, in order.
+    ///
+    /// If the length of `axis` is *L*, the first *L* % `n` views have length
+    /// *L* / `n` + 1 along `axis` and the rest have length *L* / `n`; some
+    /// views are empty if `n` is greater than *L*.
+    ///
+    /// To split an owned array, use
+    /// [`.split_view_mut()`](#method.split_view_mut).
+    ///
+    /// **Panics** if `axis` is out of bounds or `n` is zero.
+    pub fn split(self, axis: Axis, n: usize) -> Vec<Self> {
+        let len = self.len_of(axis);
+        split_into(self, axis, len, n)
+    }
+
     /// Split the view into multiple disjoint slices.
     ///
     /// This is similar to [`.multi_slice_mut()`], but `.multi_slice_move()`
@@ -136,3 +180,19 @@ where
         info.multi_slice_move(self)
     }
 }
+
+/// Split `v`, of length `len` along `axis`, into `n` parts of nearly equal
+/// length, longer parts first.
+fn split_into<T: SplitAt>(v: T, axis: Axis, len: usize, n: usize) -> Vec<T> {
+    assert!(n > 0, "ndarray: cannot split into zero parts");
+    let (short, longer) = (len / n, len % n);
+    let mut parts = Vec::with_capacity(n);
+    let mut rest = v;
+    for k in 0..n {
+        let part_len = if k < longer { short + 1 } else { short };
+        let (part, tail) = rest.split_at(axis, part_len);
+        parts.push(part);
+        rest = tail;
+    }
+    parts
+}
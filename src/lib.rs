@@ -82,6 +82,10 @@
 //!   - Enable transparent BLAS support for matrix multiplication.
 //!     Uses ``blas-src`` for pluggable backend, which needs to be configured
 //!     separately.
+//! - `half`
+//!   - Optional, compatible with Rust stable
+//!   - Enables matrix multiplication of `half::f16` arrays with `f32`
+//!     accumulation, `linalg::general_mat_mul_f16`.
 //!
 //! ## Documentation
 //!
@@ -118,13 +122,17 @@ use std::marker::PhantomData;
 use std::sync::Arc;
 
 pub use crate::dimension::dim::*;
-pub use crate::dimension::{Axis, AxisDescription, Dimension, IntoDimension, RemoveAxis};
+pub use crate::dimension::{
+    Axis, AxisDescription, DimMax, Dimension, IntoDimension, RemoveAxis,
+};
 
 pub use crate::dimension::IxDynImpl;
 pub use crate::dimension::NdIndex;
 pub use crate::error::{ErrorKind, ShapeError};
 pub use crate::indexes::{indices, indices_of};
-pub use crate::slice::{Slice, SliceInfo, SliceNextDim, SliceOrIndex};
+pub use crate::slice::{
+    Ellipsis, NewAxis, Slice, SliceInDim, SliceInfo, SliceNextDim, SliceNextInDim, SliceOrIndex,
+};
 
 use crate::iterators::Baseiter;
 use crate::iterators::{ElementsBase, ElementsBaseMut, Iter, IterMut, Lanes, LanesMut};
@@ -178,10 +186,12 @@ mod linalg_traits;
 mod linspace;
 mod logspace;
 mod numeric_util;
+mod pad;
 mod partial;
 mod shape_builder;
 #[macro_use]
 mod slice;
+mod sort;
 mod split_at;
 mod stacking;
 #[macro_use]
@@ -192,6 +202,8 @@ mod dimension;
 pub use crate::zip::{FoldWhile, IntoNdProducer, NdProducer, Zip};
 
 pub use crate::layout::Layout;
+pub use crate::pad::PadMode;
+pub use crate::sort::SearchSide;
 
 /// Implementation's prelude. Common types used everywhere.
 mod imp_prelude {
@@ -479,6 +491,13 @@ pub type Ixs = isize;
 /// [`.slice_collapse()`] behaves like [`.collapse_axis()`] by preserving
 /// the number of dimensions.
 ///
+/// As in NumPy, [`NewAxis`] inserts a new axis of length 1 and [`Ellipsis`]
+/// stands for full ranges of all remaining axes, for example
+/// `s![.., NewAxis]` or `s![Ellipsis, 0]`.
+///
+/// [`NewAxis`]: struct.NewAxis.html
+/// [`Ellipsis`]: struct.Ellipsis.html
+///
 /// [`.slice()`]: #method.slice
 /// [`.slice_mut()`]: #method.slice_mut
 /// [`.slice_move()`]: #method.slice_move
@@ -641,7 +660,7 @@ pub type Ixs = isize;
 /// - `&A @ &A` which produces a new `Array`
 /// - `B @ A` which consumes `B`, updates it with the result, and returns it
 /// - `B @ &A` which consumes `B`, updates it with the result, and returns it
-/// - `C @= &A` which performs an arithmetic operation in place
+/// - `C @= &A` or `C @= A` which performs an arithmetic operation in place
 ///
 /// Note that the element type needs to implement the operator trait and the
 /// `Clone` trait.
@@ -684,10 +703,14 @@ pub type Ixs = isize;
 ///
 /// ## Broadcasting
 ///
-/// Arrays support limited *broadcasting*, where arithmetic operations with
+/// Arrays support *broadcasting*, where arithmetic operations with
 /// array operands of different sizes can be carried out by repeating the
-/// elements of the smaller dimension array. See
-/// [`.broadcast()`](#method.broadcast) for a more detailed
+/// elements along axes of length one, like in NumPy. Both operands of a
+/// binary operator are broadcast to their common shape, and the result has
+/// the larger of their dimensionalities (see [`DimMax`](trait.DimMax.html)).
+/// In place operators like `+=` only broadcast the right hand side. See
+/// [`.broadcast()`](#method.broadcast) and
+/// [`.broadcast_with()`](#method.broadcast_with) for a more detailed
 /// description.
 ///
 /// ```
@@ -707,7 +730,16 @@ pub type Ixs = isize;
 /// // We can add because the shapes are compatible even if not equal.
 /// // The `b` array is shape 1 × 2 but acts like a 4 × 2 array.
 /// assert!(
-///     c == a + b
+///     c == a + &b
+/// );
+///
+/// // Both operands are broadcast: 4 × 1 and 1 × 2 give 4 × 2.
+/// let d = arr2(&[[1.], [2.], [3.], [4.]]);
+/// assert!(
+///     &d + &b == arr2(&[[1., 2.],
+///                       [2., 3.],
+///                       [3., 4.],
+///                       [4., 5.]])
 /// );
 /// ```
 ///
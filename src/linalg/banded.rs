@@ -0,0 +1,266 @@
+// Copyright 2016 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::cmp;
+
+use num_traits::Zero;
+
+use crate::imp_prelude::*;
+use crate::LinalgScalar;
+
+#[cfg(feature = "blas")]
+use super::impl_linalg::{blas_1d_params, blas_compat_1d, blas_index, cast_as, same_type};
+#[cfg(feature = "blas")]
+use cblas_sys as blas_sys;
+#[cfg(feature = "blas")]
+use cblas_sys::{CblasNoTrans, CBLAS_LAYOUT};
+#[cfg(feature = "blas")]
+use num_complex::{Complex32 as c32, Complex64 as c64};
+
+/// A matrix whose nonzero elements lie in a band around the diagonal.
+///
+/// A *M* × *N* banded matrix with `kl` subdiagonals and `ku` superdiagonals
+/// stores only the elements `a[[i, j]]` with `i <= j + kl` and `j <= i + ku`.
+/// They are kept in an *M* × (`kl` + `ku` + 1) array `band`, row by row, in
+/// the layout used by row major BLAS:
+///
+/// `a[[i, j]]` is stored at `band[[i, kl + j - i]]`.
+///
+/// Elements of `band` that fall outside the matrix are never read.
+///
+/// ```
+/// use ndarray::{arr1, arr2};
+/// use ndarray::linalg::BandedMatrix;
+///
+/// // A tridiagonal matrix
+/// let a = arr2(&[[2., -1., 0.],
+///                [-1., 2., -1.],
+///                [0., -1., 2.]]);
+/// let banded = BandedMatrix::from_dense(&a, 1, 1);
+/// assert_eq!(banded.band(), arr2(&[[0., 2., -1.],
+///                                  [-1., 2., -1.],
+///                                  [-1., 2., 0.]]));
+/// assert_eq!(banded.dot(&arr1(&[1., 2., 3.])), a.dot(&arr1(&[1., 2., 3.])));
+/// assert_eq!(banded.to_dense(), a);
+/// ```
+#[derive(Clone, Debug)]
+pub struct BandedMatrix<A> {
+    band: Array2<A>,
+    cols: usize,
+    kl: usize,
+    ku: usize,
+}
+
+impl<A> BandedMatrix<A> {
+    /// Create a *M* × *N* banded matrix, where `shape` is (*M*, *N*), with
+    /// `kl` subdiagonals and `ku` superdiagonals, all elements zero.
+    ///
+    /// **Panics** if the size of the band storage overflows `isize`.
+    pub fn zeros(shape: (usize, usize), kl: usize, ku: usize) -> Self
+    where
+        A: Clone + Zero,
+    {
+        let (m, n) = shape;
+        let width = kl
+            .checked_add(ku)
+            .and_then(|w| w.checked_add(1))
+            .expect("ndarray: bandwidths overflow usize");
+        BandedMatrix {
+            band: Array2::zeros((m, width)),
+            cols: n,
+            kl,
+            ku,
+        }
+    }
+
+    /// Create a banded matrix from the elements of `a` that lie within `kl`
+    /// subdiagonals and `ku` superdiagonals of its diagonal.
+    ///
+    /// Elements of `a` outside the band are ignored.
+    pub fn from_dense<S>(a: &ArrayBase<S, Ix2>, kl: usize, ku: usize) -> Self
+    where
+        S: Data<Elem = A>,
+        A: Clone + Zero,
+    {
+        let mut banded = Self::zeros(a.dim(), kl, ku);
+        for (i, mut row) in banded.band.outer_iter_mut().enumerate() {
+            if let Some((lo, hi)) = band_columns(i, banded.cols, kl, ku) {
+                row.slice_mut(s![kl + lo - i..kl + hi - i])
+                    .assign(&a.slice(s![i, lo..hi]));
+            }
+        }
+        banded
+    }
+
+    /// Return the shape of the matrix as (rows, columns).
+    pub fn dim(&self) -> (usize, usize) {
+        (self.band.nrows(), self.cols)
+    }
+
+    /// Return the number of subdiagonals.
+    pub fn lower_bandwidth(&self) -> usize {
+        self.kl
+    }
+
+    /// Return the number of superdiagonals.
+    pub fn upper_bandwidth(&self) -> usize {
+        self.ku
+    }
+
+    /// Return a view of the band storage.
+    pub fn band(&self) -> ArrayView2<'_, A> {
+        self.band.view()
+    }
+
+    /// Return a mutable view of the band storage.
+    pub fn band_mut(&mut self) -> ArrayViewMut2<'_, A> {
+        self.band.view_mut()
+    }
+
+    /// Return the matrix as a dense array, with zeros outside the band.
+    pub fn to_dense(&self) -> Array2<A>
+    where
+        A: Clone + Zero,
+    {
+        let mut a = Array2::zeros(self.dim());
+        for (i, row) in self.band.outer_iter().enumerate() {
+            if let Some((lo, hi)) = band_columns(i, self.cols, self.kl, self.ku) {
+                a.slice_mut(s![i, lo..hi])
+                    .assign(&row.slice(s![self.kl + lo - i..self.kl + hi - i]));
+            }
+        }
+        a
+    }
+
+    /// Perform the matrix-vector product of `self` and `rhs`.
+    ///
+    /// If `self` is *M* × *N*, then `rhs` must have length *N* and the result
+    /// has length *M*.
+    ///
+    /// **Panics** if the shapes are incompatible.<br>
+    /// *Note:* If enabled, uses blas `gbmv` for elements of `f32, f64,
+    /// Complex32, Complex64` when memory layout allows.
+    pub fn dot<S>(&self, rhs: &ArrayBase<S, Ix1>) -> Array1<A>
+    where
+        S: Data<Elem = A>,
+        A: LinalgScalar,
+    {
+        let mut y = Array1::zeros(self.band.nrows());
+        banded_mat_vec_mul(A::one(), self, rhs, A::zero(), &mut y);
+        y
+    }
+}
+
+/// Return the range of columns, as `(lo, hi)`, that hold the band in row `i`,
+/// or `None` if the band does not meet the row.
+fn band_columns(i: usize, cols: usize, kl: usize, ku: usize) -> Option<(usize, usize)> {
+    let lo = i.saturating_sub(kl);
+    let hi = cmp::min(i.saturating_add(ku).saturating_add(1), cols);
+    if lo < hi {
+        Some((lo, hi))
+    } else {
+        None
+    }
+}
+
+/// Banded matrix-vector multiplication.
+///
+/// Compute y ← α A x + β y
+///
+/// where A is a *M* × *N* banded matrix and x is an *N*-element column vector
+/// and y an *M*-element column vector (one dimensional arrays).
+///
+/// ***Panics*** if array shapes are not compatible<br>
+/// *Note:* If enabled, uses blas `gbmv` for elements of `f32, f64, Complex32,
+/// Complex64` when memory layout allows.
+pub fn banded_mat_vec_mul<A, S2, S3>(
+    alpha: A,
+    a: &BandedMatrix<A>,
+    x: &ArrayBase<S2, Ix1>,
+    beta: A,
+    y: &mut ArrayBase<S3, Ix1>,
+) where
+    S2: Data<Elem = A>,
+    S3: DataMut<Elem = A>,
+    A: LinalgScalar,
+{
+    let (m, n) = a.dim();
+    let (k, m2) = (x.len(), y.len());
+    if n != k || m != m2 {
+        banded_shape_error(m, n, k, m2);
+    }
+
+    #[cfg(feature = "blas")]
+    macro_rules! gbmv {
+        ($ty:tt, $gbmv:ident) => {
+            if same_type::<A, $ty>()
+                && m <= blas_index::max_value() as usize
+                && n <= blas_index::max_value() as usize
+                && a.band.ncols() <= blas_index::max_value() as usize
+                && blas_compat_1d::<$ty, _>(x)
+                && blas_compat_1d::<$ty, _>(y)
+            {
+                unsafe {
+                    let (x_ptr, _, incx) = blas_1d_params(x.as_ptr(), n, x.strides()[0]);
+                    let (y_ptr, _, incy) = blas_1d_params(y.as_mut_ptr(), m, y.strides()[0]);
+                    blas_sys::$gbmv(
+                        CBLAS_LAYOUT::CblasRowMajor,
+                        CblasNoTrans,
+                        m as blas_index,
+                        n as blas_index,
+                        a.kl as blas_index,
+                        a.ku as blas_index,
+                        blas_scalar!($ty, alpha),
+                        a.band.as_ptr() as *const _,
+                        cmp::max(a.band.ncols(), 1) as blas_index,
+                        x_ptr as *const _,
+                        incx,
+                        blas_scalar!($ty, beta),
+                        y_ptr as *mut _,
+                        incy,
+                    );
+                }
+                return;
+            }
+        };
+    }
+    #[cfg(feature = "blas")]
+    gbmv!(f32, cblas_sgbmv);
+    #[cfg(feature = "blas")]
+    gbmv!(f64, cblas_dgbmv);
+    #[cfg(feature = "blas")]
+    gbmv!(c32, cblas_cgbmv);
+    #[cfg(feature = "blas")]
+    gbmv!(c64, cblas_zgbmv);
+
+    /* general */
+
+    for (i, (row, elt)) in a.band.outer_iter().zip(y).enumerate() {
+        let sum = match band_columns(i, n, a.kl, a.ku) {
+            Some((lo, hi)) => row
+                .slice(s![a.kl + lo - i..a.kl + hi - i])
+                .dot(&x.slice(s![lo..hi])),
+            None => A::zero(),
+        };
+        *elt = if beta.is_zero() {
+            alpha * sum
+        } else {
+            *elt * beta + alpha * sum
+        };
+    }
+}
+
+#[cold]
+#[inline(never)]
+fn banded_shape_error(m: usize, n: usize, k: usize, m2: usize) -> ! {
+    panic!(
+        "ndarray: inputs {} × {} and {} × 1, and output {} × 1 are not compatible for banded \
+         matrix-vector multiplication",
+        m, n, k, m2
+    );
+}
@@ -0,0 +1,111 @@
+// Copyright 2016 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::imp_prelude::*;
+use crate::NdFloat;
+
+use super::lu::columns_mut;
+use super::triangular::solve_triangular_mat_impl;
+use super::{LinalgError, LinalgErrorKind, Triangle};
+
+impl<A, S> ArrayBase<S, Ix2>
+where
+    S: Data<Elem = A>,
+    A: NdFloat,
+{
+    /// Compute the Cholesky factorization of the symmetric positive definite
+    /// matrix `self`.
+    ///
+    /// With `Triangle::Lower`, return the lower triangular L with
+    /// `self` = L Lᵀ; with `Triangle::Upper`, return the upper triangular U
+    /// with `self` = Uᵀ U. Only the `uplo` triangle of `self` is read.
+    ///
+    /// **Errors** if `self` is not square or not positive definite.
+    ///
+    /// ```
+    /// use ndarray::arr2;
+    /// use ndarray::linalg::Triangle;
+    ///
+    /// let a = arr2(&[[4., 2.],
+    ///                [2., 5.]]);
+    /// let l = a.cholesky(Triangle::Lower).unwrap();
+    /// assert_eq!(l, arr2(&[[2., 0.],
+    ///                      [1., 2.]]));
+    /// assert_eq!(a.cholesky(Triangle::Upper).unwrap(), l.t());
+    /// ```
+    pub fn cholesky(&self, uplo: Triangle) -> Result<Array2<A>, LinalgError> {
+        let (m, n) = self.dim();
+        if m != n {
+            return Err(LinalgError::from_kind(LinalgErrorKind::NotSquare));
+        }
+        // Compute L, reading the lower triangle of `a`; the transpose of the
+        // upper triangle is the lower triangle of the transpose.
+        let a = match uplo {
+            Triangle::Lower => self.view(),
+            Triangle::Upper => self.t(),
+        };
+        let mut l = Array2::zeros((n, n));
+        for j in 0..n {
+            let l_j = l.slice(s![j, ..j]);
+            let d = a[[j, j]] - l_j.dot(&l_j);
+            if d <= A::zero() || d.is_nan() {
+                return Err(LinalgError::from_kind(LinalgErrorKind::NotPositiveDefinite));
+            }
+            let d = d.sqrt();
+            l[[j, j]] = d;
+            for i in j + 1..n {
+                let x = a[[i, j]] - l.slice(s![i, ..j]).dot(&l.slice(s![j, ..j]));
+                l[[i, j]] = x / d;
+            }
+        }
+        Ok(match uplo {
+            Triangle::Lower => l,
+            Triangle::Upper => l.reversed_axes(),
+        })
+    }
+
+    /// Solve the linear system A x = b, where A is `self`, symmetric
+    /// positive definite, and `b` is a vector or a matrix of column vectors.
+    ///
+    /// Only the lower triangle of `self` is read.
+    ///
+    /// **Errors** if `self` is not square or not positive definite, or if `b`
+    /// does not have as many rows as `self`.
+    pub fn solvec<S2, D>(&self, b: &ArrayBase<S2, D>) -> Result<Array<A, D>, LinalgError>
+    where
+        S2: Data<Elem = A>,
+        D: Dimension,
+    {
+        self.solvec_into(b.to_owned())
+    }
+
+    /// Solve the linear system A x = b in place, where A is `self`, symmetric
+    /// positive definite, and `b` is a vector or a matrix of column vectors,
+    /// and return `b` holding x.
+    ///
+    /// Only the lower triangle of `self` is read.
+    ///
+    /// **Errors** if `self` is not square or not positive definite, or if `b`
+    /// does not have as many rows as `self`.
+    pub fn solvec_into<S2, D>(
+        &self,
+        mut b: ArrayBase<S2, D>,
+    ) -> Result<ArrayBase<S2, D>, LinalgError>
+    where
+        S2: DataMut<Elem = A>,
+        D: Dimension,
+    {
+        let l = self.cholesky(Triangle::Lower)?;
+        {
+            let mut x = columns_mut(&mut b, l.nrows())?;
+            solve_triangular_mat_impl(Triangle::Lower, false, &l.view(), &mut x);
+            solve_triangular_mat_impl(Triangle::Upper, false, &l.t(), &mut x);
+        }
+        Ok(b)
+    }
+}
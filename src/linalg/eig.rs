@@ -0,0 +1,279 @@
+// Copyright 2016 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use num_complex::Complex;
+
+use crate::imp_prelude::*;
+use crate::{NdFloat, Zip};
+
+use super::{LinalgError, LinalgErrorKind};
+
+/// The number of QR steps per eigenvalue after which the iteration gives up.
+const MAX_STEPS: usize = 30;
+
+/// The eigenvalues and eigenvectors of a general matrix.
+pub type Eig<A> = (Array1<Complex<A>>, Array2<Complex<A>>);
+
+/// The complex Schur form T and, if it was asked for, the unitary matrix Q.
+type Schur<A> = (Array2<Complex<A>>, Option<Array2<Complex<A>>>);
+
+impl<A, S> ArrayBase<S, Ix2>
+where
+    S: Data<Elem = A>,
+    A: NdFloat,
+{
+    /// Compute the eigenvalues and eigenvectors of the square matrix `self`.
+    ///
+    /// Return the eigenvalues, in no particular order, and a matrix whose
+    /// columns are the corresponding eigenvectors, normalized to unit length.
+    /// Both are complex, since a real matrix may have complex eigenvalues.
+    ///
+    /// The eigenvalues are computed by reducing `self` to Hessenberg form and
+    /// then to complex Schur form with shifted QR steps.
+    ///
+    /// **Errors** if `self` is not square or if the iteration does not
+    /// converge.
+    ///
+    /// ```
+    /// use ndarray::arr2;
+    /// use num_complex::Complex64;
+    ///
+    /// // A rotation by a quarter turn has eigenvalues ±i
+    /// let a = arr2(&[[0., -1.],
+    ///                [1., 0.]]);
+    /// let (w, v) = a.eig().unwrap();
+    /// let a = a.mapv(Complex64::from);
+    /// for (&w, v) in w.iter().zip(v.gencolumns()) {
+    ///     assert!((w.im.abs() - 1.).abs() < 1e-12);
+    ///     assert!((a.dot(&v) - &v * w).iter().all(|x| x.norm() < 1e-12));
+    /// }
+    /// ```
+    pub fn eig(&self) -> Result<Eig<A>, LinalgError> {
+        let (t, q) = schur(self.view(), true)?;
+        let q = q.unwrap();
+        let n = t.nrows();
+        let w = t.diag().to_owned();
+        let mut v = Array2::zeros((n, n));
+        let mut y = Array1::zeros(n);
+        // The smallest divisor allowed in the back substitution, so that
+        // repeated eigenvalues give large but finite components.
+        let small = A::epsilon() * t.iter().fold(A::zero(), |acc, x| acc.max(x.norm()));
+        for (k, mut v_k) in v.gencolumns_mut().into_iter().enumerate() {
+            // Solve (T - w_k I) y = 0 with y_k = 1 and y_i = 0 for i > k.
+            y.fill(Complex::from(A::zero()));
+            y[k] = Complex::from(A::one());
+            for i in (0..k).rev() {
+                let sum = t.slice(s![i, i + 1..=k]).dot(&y.slice(s![i + 1..=k]));
+                let mut d = t[[i, i]] - w[k];
+                if d.norm() < small {
+                    d = Complex::from(small);
+                }
+                y[i] = -sum / d;
+            }
+            v_k.assign(&q.slice(s![.., ..=k]).dot(&y.slice(s![..=k])));
+            let norm = v_k.iter().fold(A::zero(), |acc, x| acc.hypot(x.norm()));
+            if !norm.is_zero() {
+                v_k.mapv_inplace(|x| x / norm);
+            }
+        }
+        Ok((w, v))
+    }
+
+    /// Compute the eigenvalues of the square matrix `self`, in no particular
+    /// order.
+    ///
+    /// **Errors** if `self` is not square or if the iteration does not
+    /// converge.
+    pub fn eigvals(&self) -> Result<Array1<Complex<A>>, LinalgError> {
+        schur(self.view(), false).map(|(t, _)| t.diag().to_owned())
+    }
+}
+
+/// A complex plane rotation [[c, s], [-s̄, c]] with real c.
+#[derive(Copy, Clone)]
+struct Rotation<A> {
+    c: A,
+    s: Complex<A>,
+}
+
+impl<A> Rotation<A>
+where
+    A: NdFloat,
+{
+    /// Create the rotation that maps (`a`, `b`) to (r, 0).
+    fn zeroing(a: Complex<A>, b: Complex<A>) -> Self {
+        let norm = a.norm().hypot(b.norm());
+        if norm.is_zero() {
+            Rotation {
+                c: A::one(),
+                s: Complex::from(A::zero()),
+            }
+        } else if a.norm().is_zero() {
+            Rotation {
+                c: A::zero(),
+                s: b.conj() / b.norm(),
+            }
+        } else {
+            let phase = a / a.norm();
+            Rotation {
+                c: a.norm() / norm,
+                s: phase * b.conj() / norm,
+            }
+        }
+    }
+
+    /// Replace `x` and `y`, the rows being rotated, by G [x; y].
+    fn rotate_rows(&self, x: ArrayViewMut1<'_, Complex<A>>, y: ArrayViewMut1<'_, Complex<A>>) {
+        let (c, s) = (self.c, self.s);
+        Zip::from(x).and(y).apply(|x, y| {
+            let (x0, y0) = (*x, *y);
+            *x = x0 * c + s * y0;
+            *y = y0 * c - s.conj() * x0;
+        });
+    }
+
+    /// Replace `x` and `y`, the columns being rotated, by [x, y] Gᴴ.
+    fn rotate_columns(&self, x: ArrayViewMut1<'_, Complex<A>>, y: ArrayViewMut1<'_, Complex<A>>) {
+        let (c, s) = (self.c, self.s);
+        Zip::from(x).and(y).apply(|x, y| {
+            let (x0, y0) = (*x, *y);
+            *x = x0 * c + s.conj() * y0;
+            *y = y0 * c - s * x0;
+        });
+    }
+}
+
+/// Apply the similarity transform G H Gᴴ, with G rotating rows and columns
+/// `p` and `p + 1`, to `h`, and accumulate Q Gᴴ in `q`.
+fn rotate_similar<A>(
+    g: Rotation<A>,
+    h: &mut Array2<Complex<A>>,
+    q: &mut Option<Array2<Complex<A>>>,
+    p: usize,
+) where
+    A: NdFloat,
+{
+    let (x, y) = h.multi_slice_mut((s![p, ..], s![p + 1, ..]));
+    g.rotate_rows(x, y);
+    let (x, y) = h.multi_slice_mut((s![.., p], s![.., p + 1]));
+    g.rotate_columns(x, y);
+    if let Some(ref mut q) = q {
+        let (x, y) = q.multi_slice_mut((s![.., p], s![.., p + 1]));
+        g.rotate_columns(x, y);
+    }
+}
+
+/// Compute the complex Schur form T = Qᴴ A Q, with T upper triangular, and
+/// return T and, if `want_q`, Q.
+pub(super) fn schur<A>(a: ArrayView2<'_, A>, want_q: bool) -> Result<Schur<A>, LinalgError>
+where
+    A: NdFloat,
+{
+    let (m, n) = a.dim();
+    if m != n {
+        return Err(LinalgError::from_kind(LinalgErrorKind::NotSquare));
+    }
+    let mut h = a.mapv(Complex::from);
+    let mut q = if want_q { Some(Array::eye(n)) } else { None };
+    let zero = Complex::from(A::zero());
+
+    // Reduce to upper Hessenberg form.
+    for j in 0..n.saturating_sub(2) {
+        for i in (j + 2..n).rev() {
+            if h[[i, j]] != zero {
+                let g = Rotation::zeroing(h[[i - 1, j]], h[[i, j]]);
+                rotate_similar(g, &mut h, &mut q, i - 1);
+                h[[i, j]] = zero;
+            }
+        }
+    }
+
+    // Shifted QR steps on the active block `lo..hi`, deflating from the
+    // bottom whenever a subdiagonal element becomes negligible.
+    let mut hi = n;
+    let mut steps = 0;
+    let mut rotations = Vec::with_capacity(n);
+    while hi > 1 {
+        let mut lo = hi - 1;
+        while lo > 0 {
+            let scale = h[[lo - 1, lo - 1]].norm() + h[[lo, lo]].norm();
+            if h[[lo, lo - 1]].norm() <= A::epsilon() * scale {
+                h[[lo, lo - 1]] = zero;
+                break;
+            }
+            lo -= 1;
+        }
+        if lo == hi - 1 {
+            hi -= 1;
+            steps = 0;
+            continue;
+        }
+        steps += 1;
+        if steps > MAX_STEPS {
+            return Err(LinalgError::from_kind(LinalgErrorKind::NotConverged));
+        }
+
+        let shift = if steps % 10 == 0 {
+            // an exceptional shift, to break cycles
+            h[[hi - 1, hi - 1]] + Complex::from(h[[hi - 1, hi - 2]].norm() * A::from(0.75).unwrap())
+        } else {
+            wilkinson_shift(
+                h[[hi - 2, hi - 2]],
+                h[[hi - 2, hi - 1]],
+                h[[hi - 1, hi - 2]],
+                h[[hi - 1, hi - 1]],
+            )
+        };
+
+        // H - μI = Q R, then H ← R Q + μI.
+        for k in lo..hi {
+            h[[k, k]] -= shift;
+        }
+        rotations.clear();
+        for k in lo..hi - 1 {
+            let g = Rotation::zeroing(h[[k, k]], h[[k + 1, k]]);
+            let (x, y) = h.multi_slice_mut((s![k, k..], s![k + 1, k..]));
+            g.rotate_rows(x, y);
+            h[[k + 1, k]] = zero;
+            rotations.push(g);
+        }
+        for (k, g) in (lo..).zip(&rotations) {
+            let (x, y) = h.multi_slice_mut((s![..k + 2, k], s![..k + 2, k + 1]));
+            g.rotate_columns(x, y);
+            if let Some(ref mut q) = q {
+                let (x, y) = q.multi_slice_mut((s![.., k], s![.., k + 1]));
+                g.rotate_columns(x, y);
+            }
+        }
+        for k in lo..hi {
+            h[[k, k]] += shift;
+        }
+    }
+
+    for j in 0..n {
+        h.slice_mut(s![j + 1.., j]).fill(zero);
+    }
+    Ok((h, q))
+}
+
+/// Return the eigenvalue of [[a, b], [c, d]] that is closer to `d`.
+fn wilkinson_shift<A>(a: Complex<A>, b: Complex<A>, c: Complex<A>, d: Complex<A>) -> Complex<A>
+where
+    A: NdFloat,
+{
+    let two = A::one() + A::one();
+    let half_diff = (a - d) / two;
+    let disc = (half_diff * half_diff + b * c).sqrt();
+    let mean = (a + d) / two;
+    let (w1, w2) = (mean + disc, mean - disc);
+    if (w1 - d).norm() <= (w2 - d).norm() {
+        w1
+    } else {
+        w2
+    }
+}
@@ -0,0 +1,250 @@
+// Copyright 2016 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::cmp::Ordering;
+
+use num_complex::Complex;
+
+use crate::imp_prelude::*;
+use crate::NdFloat;
+
+use super::{Givens, Hermitian, LinalgError, LinalgErrorKind, Symmetric, Triangle};
+
+/// The number of sweeps over all off-diagonal elements after which the
+/// Jacobi iteration gives up.
+const MAX_SWEEPS: usize = 100;
+
+/// The eigenvalues of a Hermitian matrix and, if they were asked for, its
+/// eigenvectors.
+type HermitianEigh<A> = (Array1<A>, Option<Array2<Complex<A>>>);
+
+impl<A, S> ArrayBase<S, Ix2>
+where
+    S: Data<Elem = A>,
+    A: NdFloat,
+{
+    /// Compute the eigenvalues and eigenvectors of the symmetric matrix
+    /// `self`, whose elements are stored in its `uplo` triangle.
+    ///
+    /// Return the eigenvalues in increasing order and an orthogonal matrix
+    /// whose columns are the corresponding eigenvectors.
+    ///
+    /// The decomposition is computed with the cyclic Jacobi method. For
+    /// complex Hermitian matrices, see
+    /// [`Hermitian::eigh`](linalg/struct.Hermitian.html#method.eigh).
+    ///
+    /// **Errors** if `self` is not square or if the iteration does not
+    /// converge.
+    ///
+    /// ```
+    /// use ndarray::{arr1, arr2};
+    /// use ndarray::linalg::Triangle;
+    ///
+    /// let a = arr2(&[[2., 1.],
+    ///                [1., 2.]]);
+    /// let (w, v) = a.eigh(Triangle::Upper).unwrap();
+    /// assert!((&w - &arr1(&[1., 3.])).norm_l2() < 1e-12);
+    /// // A V = V diag(w)
+    /// assert!((a.dot(&v) - &v * &w).iter().all(|x: &f64| x.abs() < 1e-12));
+    /// ```
+    pub fn eigh(&self, uplo: Triangle) -> Result<(Array1<A>, Array2<A>), LinalgError> {
+        jacobi_eigh(self.view(), uplo, true).map(|(w, v)| (w, v.unwrap()))
+    }
+
+    /// Compute the eigenvalues of the symmetric matrix `self`, whose
+    /// elements are stored in its `uplo` triangle, in increasing order.
+    ///
+    /// **Errors** if `self` is not square or if the iteration does not
+    /// converge.
+    pub fn eigvalsh(&self, uplo: Triangle) -> Result<Array1<A>, LinalgError> {
+        jacobi_eigh(self.view(), uplo, false).map(|(w, _)| w)
+    }
+}
+
+impl<'a, A> Hermitian<ArrayView2<'a, Complex<A>>>
+where
+    A: NdFloat,
+{
+    /// Compute the eigenvalues and eigenvectors of the Hermitian matrix.
+    ///
+    /// Return the (real) eigenvalues in increasing order and a unitary
+    /// matrix whose columns are the corresponding eigenvectors. This is the
+    /// complex counterpart of
+    /// [`ArrayBase::eigh`](../struct.ArrayBase.html#method.eigh).
+    ///
+    /// The decomposition is computed with the cyclic Jacobi method, using
+    /// complex rotations.
+    ///
+    /// **Errors** if the iteration does not converge.
+    ///
+    /// ```
+    /// use ndarray::{arr1, arr2};
+    /// use ndarray::linalg::{Hermitian, Triangle};
+    /// use num_complex::Complex64;
+    ///
+    /// let i = Complex64::i();
+    /// let a = arr2(&[[2. + 0. * i, i],
+    ///                [-i, 2. + 0. * i]]);
+    /// let (w, v) = Hermitian::new(a.view(), Triangle::Upper).eigh().unwrap();
+    /// assert!((&w - &arr1(&[1., 3.])).norm_l2() < 1e-12);
+    /// // A V = V diag(w)
+    /// let vw = &v * &w.mapv(Complex64::from);
+    /// assert!((a.dot(&v) - vw).iter().all(|x| x.norm() < 1e-12));
+    /// ```
+    pub fn eigh(&self) -> Result<(Array1<A>, Array2<Complex<A>>), LinalgError> {
+        jacobi_eigh_complex(self, true).map(|(w, v)| (w, v.unwrap()))
+    }
+
+    /// Compute the eigenvalues of the Hermitian matrix in increasing order.
+    ///
+    /// **Errors** if the iteration does not converge.
+    pub fn eigvalsh(&self) -> Result<Array1<A>, LinalgError> {
+        jacobi_eigh_complex(self, false).map(|(w, _)| w)
+    }
+}
+
+fn jacobi_eigh<A>(
+    a: ArrayView2<'_, A>,
+    uplo: Triangle,
+    want_v: bool,
+) -> Result<(Array1<A>, Option<Array2<A>>), LinalgError>
+where
+    A: NdFloat,
+{
+    let (m, n) = a.dim();
+    if m != n {
+        return Err(LinalgError::from_kind(LinalgErrorKind::NotSquare));
+    }
+    // Rotate `a` with similarity transforms until it is diagonal:
+    // Vᵀ A V = diag(w).
+    let mut a = Symmetric::new(a, uplo).to_full();
+    let mut v = if want_v { Some(Array::eye(n)) } else { None };
+    let mut converged = false;
+    for _ in 0..MAX_SWEEPS {
+        let mut rotated = false;
+        for p in 0..n {
+            for q in p + 1..n {
+                let (app, aqq, apq) = (a[[p, p]], a[[q, q]], a[[p, q]]);
+                if apq.abs() <= A::epsilon() * (app * aqq).abs().sqrt() {
+                    continue;
+                }
+                rotated = true;
+                let theta = (aqq - app) / (apq + apq);
+                let t = theta.signum() / (theta.abs() + A::one().hypot(theta));
+                let c = A::one().hypot(t).recip();
+                let rotation = Givens::new(c, -c * t);
+                rotation.rotate_columns(&mut a, p, q);
+                rotation.rotate_rows(&mut a, p, q);
+                a[[p, q]] = A::zero();
+                a[[q, p]] = A::zero();
+                if let Some(ref mut v) = v {
+                    rotation.rotate_columns(v, p, q);
+                }
+            }
+        }
+        if !rotated {
+            converged = true;
+            break;
+        }
+    }
+    if !converged {
+        return Err(LinalgError::from_kind(LinalgErrorKind::NotConverged));
+    }
+
+    let mut order: Vec<_> = a.diag().iter().cloned().enumerate().collect();
+    order.sort_by(|x, y| x.1.partial_cmp(&y.1).unwrap_or(Ordering::Equal));
+    let w = order.iter().map(|&(_, w)| w).collect();
+    let v = v.map(|v| v.select(Axis(1), &order.iter().map(|&(j, _)| j).collect::<Vec<_>>()));
+    Ok((w, v))
+}
+
+fn jacobi_eigh_complex<A>(
+    a: &Hermitian<ArrayView2<'_, Complex<A>>>,
+    want_v: bool,
+) -> Result<HermitianEigh<A>, LinalgError>
+where
+    A: NdFloat,
+{
+    let n = a.view().nrows();
+    // Rotate `a` with unitary similarity transforms until it is diagonal:
+    // Vᴴ A V = diag(w).
+    //
+    // With a_pq = |a_pq| e^{iφ}, the rotation is U = D R Dᴴ, where
+    // D = diag(1, e^{-iφ}) makes the (p, q) element real and R is the real
+    // Jacobi rotation for the resulting 2 × 2 problem.
+    let mut a = a.to_full();
+    let mut v = if want_v { Some(Array::eye(n)) } else { None };
+    let mut converged = false;
+    for _ in 0..MAX_SWEEPS {
+        let mut rotated = false;
+        for p in 0..n {
+            for q in p + 1..n {
+                let (app, aqq, apq) = (a[[p, p]].re, a[[q, q]].re, a[[p, q]]);
+                let abs_pq = apq.norm();
+                if abs_pq <= A::epsilon() * (app * aqq).abs().sqrt() {
+                    continue;
+                }
+                rotated = true;
+                let phase = apq / abs_pq;
+                let theta = (aqq - app) / (abs_pq + abs_pq);
+                let t = theta.signum() / (theta.abs() + A::one().hypot(theta));
+                let c = A::one().hypot(t).recip();
+                let s = c * t;
+                // U = [[c, s e^{iφ}], [-s e^{-iφ}, c]]
+                let u_pq = phase * s;
+                let u_qp = -phase.conj() * s;
+                rotate_columns(&mut a, p, q, c, u_pq, u_qp);
+                // Uᴴ from the left
+                for k in 0..n {
+                    let (x, y) = (a[[p, k]], a[[q, k]]);
+                    a[[p, k]] = x * c + y * u_qp.conj();
+                    a[[q, k]] = x * u_pq.conj() + y * c;
+                }
+                a[[p, q]] = Complex::new(A::zero(), A::zero());
+                a[[q, p]] = Complex::new(A::zero(), A::zero());
+                a[[p, p]].im = A::zero();
+                a[[q, q]].im = A::zero();
+                if let Some(ref mut v) = v {
+                    rotate_columns(v, p, q, c, u_pq, u_qp);
+                }
+            }
+        }
+        if !rotated {
+            converged = true;
+            break;
+        }
+    }
+    if !converged {
+        return Err(LinalgError::from_kind(LinalgErrorKind::NotConverged));
+    }
+
+    let mut order: Vec<_> = a.diag().iter().map(|x| x.re).enumerate().collect();
+    order.sort_by(|x, y| x.1.partial_cmp(&y.1).unwrap_or(Ordering::Equal));
+    let w = order.iter().map(|&(_, w)| w).collect();
+    let v = v.map(|v| v.select(Axis(1), &order.iter().map(|&(j, _)| j).collect::<Vec<_>>()));
+    Ok((w, v))
+}
+
+/// Multiply columns `p` and `q` of `a` from the right by the 2 × 2 matrix
+/// [[c, u_pq], [u_qp, c]].
+fn rotate_columns<A>(
+    a: &mut Array2<Complex<A>>,
+    p: usize,
+    q: usize,
+    c: A,
+    u_pq: Complex<A>,
+    u_qp: Complex<A>,
+) where
+    A: NdFloat,
+{
+    for k in 0..a.nrows() {
+        let (x, y) = (a[[k, p]], a[[k, q]]);
+        a[[k, p]] = x * c + y * u_qp;
+        a[[k, q]] = x * u_pq + y * c;
+    }
+}
@@ -0,0 +1,318 @@
+// Copyright 2016 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::error::{from_kind, ErrorKind, ShapeError};
+use crate::imp_prelude::*;
+use crate::{CowArray, LinalgScalar};
+
+/// An operand or intermediate result, with one label per axis.
+struct Term<'a, A> {
+    labels: Vec<u8>,
+    array: CowArray<'a, A, IxDyn>,
+}
+
+/// Evaluate the Einstein summation described by `subscripts` on `operands`.
+///
+/// `subscripts` lists the axis labels of each operand, separated by commas,
+/// optionally followed by `->` and the labels of the result, like
+/// `"ij,jk->ik"` for matrix multiplication. Labels are ASCII letters.
+/// Axes with the same label must have the same length; the products of
+/// the operands are summed over all labels that are not in the result. A
+/// label repeated in one operand selects its diagonal, as in `"ii->i"`.
+///
+/// Without `->`, the result has the labels that occur exactly once, in
+/// alphabetical order, like `numpy.einsum`. Ellipses are not supported.
+///
+/// The operands are contracted pairwise, each time choosing the pair with
+/// the smallest intermediate result; each contraction is computed with
+/// (batched) matrix multiplication.
+///
+/// ***Errors*** if `subscripts` is malformed (`Unsupported`), or does not
+/// match the number or the shapes of the operands (`IncompatibleShape`).
+///
+/// ```
+/// use ndarray::linalg::einsum;
+/// use ndarray::arr2;
+///
+/// let a = arr2(&[[1., 2.],
+///                [3., 4.]]);
+/// let b = arr2(&[[0., 1.],
+///                [1., 0.]]);
+/// let c = einsum("ij,jk->ik", &[a.view().into_dyn(), b.view().into_dyn()]).unwrap();
+/// assert_eq!(c, a.dot(&b).into_dyn());
+///
+/// // the trace
+/// let t = einsum("ii", &[a.view().into_dyn()]).unwrap();
+/// assert_eq!(t[[]], 5.);
+/// ```
+pub fn einsum<A>(subscripts: &str, operands: &[ArrayViewD<'_, A>]) -> Result<ArrayD<A>, ShapeError>
+where
+    A: LinalgScalar,
+{
+    let (inputs, output) = parse_subscripts(subscripts)?;
+    if inputs.len() != operands.len() {
+        return Err(from_kind(ErrorKind::IncompatibleShape));
+    }
+    let mut lengths = Vec::new();
+    for (labels, operand) in inputs.iter().zip(operands) {
+        if labels.len() != operand.ndim() {
+            return Err(from_kind(ErrorKind::IncompatibleShape));
+        }
+        for (&label, &len) in labels.iter().zip(operand.shape()) {
+            match lengths.iter().find(|&&(l, _)| l == label) {
+                Some(&(_, known)) if known != len => {
+                    return Err(from_kind(ErrorKind::IncompatibleShape))
+                }
+                Some(_) => {}
+                None => lengths.push((label, len)),
+            }
+        }
+    }
+    let len_of = |label: u8| lengths.iter().find(|&&(l, _)| l == label).unwrap().1;
+
+    let mut terms: Vec<_> = inputs
+        .into_iter()
+        .zip(operands)
+        .map(|(labels, operand)| diagonal(labels, operand.view()))
+        .collect();
+
+    while terms.len() > 1 {
+        // Contract the pair with the smallest result next.
+        let mut best = (0, 1, usize::max_value());
+        for i in 0..terms.len() {
+            for j in i + 1..terms.len() {
+                let keep = kept_labels(&terms, &[i, j], &output);
+                let size = terms[i]
+                    .labels
+                    .iter()
+                    .chain(
+                        terms[j]
+                            .labels
+                            .iter()
+                            .filter(|l| !terms[i].labels.contains(l)),
+                    )
+                    .filter(|l| keep.contains(l))
+                    .fold(1usize, |size, &l| size.saturating_mul(len_of(l)));
+                if size < best.2 {
+                    best = (i, j, size);
+                }
+            }
+        }
+        let (i, j, _) = best;
+        let keep = kept_labels(&terms, &[i, j], &output);
+        let b = terms.remove(j);
+        let a = terms.remove(i);
+        terms.push(contract(a, b, &keep));
+    }
+
+    let term = sum_labels(terms.pop().unwrap(), &output);
+    let perm: Vec<_> = output
+        .iter()
+        .map(|l| term.labels.iter().position(|m| m == l).unwrap())
+        .collect();
+    Ok(term.array.permuted_axes(perm).into_owned())
+}
+
+/// Split `subscripts` into the labels of each operand and of the result.
+fn parse_subscripts(subscripts: &str) -> Result<(Vec<Vec<u8>>, Vec<u8>), ShapeError> {
+    let subscripts: String = subscripts.chars().filter(|c| !c.is_whitespace()).collect();
+    let mut parts = subscripts.splitn(2, "->");
+    let inputs: Vec<Vec<u8>> = parts
+        .next()
+        .unwrap()
+        .split(',')
+        .map(|term| term.bytes().collect())
+        .collect();
+    let output = parts
+        .next()
+        .map(|output| output.bytes().collect::<Vec<_>>());
+    let mut all_labels = inputs.iter().flatten().chain(output.iter().flatten());
+    if all_labels.any(|l| !l.is_ascii_alphabetic()) {
+        return Err(from_kind(ErrorKind::Unsupported));
+    }
+
+    let count = |label: u8| inputs.iter().flatten().filter(|&&l| l == label).count();
+    let output = match output {
+        Some(output) => {
+            let valid = output
+                .iter()
+                .enumerate()
+                .all(|(i, &l)| count(l) > 0 && !output[..i].contains(&l));
+            if !valid {
+                return Err(from_kind(ErrorKind::Unsupported));
+            }
+            output
+        }
+        None => {
+            let mut output: Vec<_> = inputs
+                .iter()
+                .flatten()
+                .cloned()
+                .filter(|&l| count(l) == 1)
+                .collect();
+            output.sort_unstable();
+            output
+        }
+    };
+    Ok((inputs, output))
+}
+
+/// Return the labels of the terms other than `skip`, and of the output.
+fn kept_labels<A>(terms: &[Term<'_, A>], skip: &[usize], output: &[u8]) -> Vec<u8> {
+    let mut keep = output.to_vec();
+    for (i, term) in terms.iter().enumerate() {
+        if !skip.contains(&i) {
+            keep.extend(&term.labels);
+        }
+    }
+    keep
+}
+
+/// Make a term of an operand, taking the diagonal of axes with repeated
+/// labels.
+fn diagonal<'a, A>(labels: Vec<u8>, array: ArrayViewD<'a, A>) -> Term<'a, A>
+where
+    A: Clone,
+{
+    let mut unique = Vec::with_capacity(labels.len());
+    for &l in &labels {
+        if !unique.contains(&l) {
+            unique.push(l);
+        }
+    }
+    if unique.len() == labels.len() {
+        return Term {
+            labels,
+            array: CowArray::from(array),
+        };
+    }
+    let position = |label: &u8| unique.iter().position(|l| l == label).unwrap();
+    let shape: Vec<_> = unique
+        .iter()
+        .map(|l| array.shape()[labels.iter().position(|m| m == l).unwrap()])
+        .collect();
+    let mut index = vec![0; labels.len()];
+    let diag = Array::from_shape_fn(shape, |i| {
+        for (k, label) in labels.iter().enumerate() {
+            index[k] = i[position(label)];
+        }
+        array[&index[..]].clone()
+    });
+    Term {
+        labels: unique,
+        array: CowArray::from(diag),
+    }
+}
+
+/// Sum `term` over the labels that are not in `keep`.
+fn sum_labels<'a, A>(mut term: Term<'a, A>, keep: &[u8]) -> Term<'a, A>
+where
+    A: LinalgScalar,
+{
+    for i in (0..term.labels.len()).rev() {
+        if !keep.contains(&term.labels[i]) {
+            term.array = CowArray::from(term.array.sum_axis(Axis(i)));
+            term.labels.remove(i);
+        }
+    }
+    term
+}
+
+/// Contract `a` and `b` over their common labels that are not in `keep`.
+fn contract<'a, A>(a: Term<'a, A>, b: Term<'a, A>, keep: &[u8]) -> Term<'a, A>
+where
+    A: LinalgScalar,
+{
+    // Labels only in `a` or only in `b` that are not kept are summed first.
+    let keep_a: Vec<_> = keep.iter().chain(&b.labels).cloned().collect();
+    let keep_b: Vec<_> = keep.iter().chain(&a.labels).cloned().collect();
+    let (a, b) = (sum_labels(a, &keep_a), sum_labels(b, &keep_b));
+
+    let (mut batch, mut contracted, mut free_a) = (vec![], vec![], vec![]);
+    for &l in &a.labels {
+        if !b.labels.contains(&l) {
+            free_a.push(l);
+        } else if keep.contains(&l) {
+            batch.push(l);
+        } else {
+            contracted.push(l);
+        }
+    }
+    let free_b: Vec<_> = b
+        .labels
+        .iter()
+        .cloned()
+        .filter(|l| !a.labels.contains(l))
+        .collect();
+
+    // A as a stack of matrices (batch, free_a, contracted) and B as a stack
+    // of matrices (batch, contracted, free_b).
+    let a3 = stack_of_matrices(&a, [&batch, &free_a, &contracted]);
+    let b3 = stack_of_matrices(&b, [&batch, &contracted, &free_b]);
+    let c = a3.dot(&b3);
+
+    let labels: Vec<_> = batch
+        .iter()
+        .chain(&free_a)
+        .chain(&free_b)
+        .cloned()
+        .collect();
+    let len_of = |l: &u8| match a.labels.iter().position(|m| m == l) {
+        Some(i) => a.array.shape()[i],
+        None => b.array.shape()[b.labels.iter().position(|m| m == l).unwrap()],
+    };
+    let shape: Vec<_> = labels.iter().map(len_of).collect();
+    Term {
+        labels,
+        array: CowArray::from(c.into_shape(shape).unwrap()),
+    }
+}
+
+/// Permute the axes of `term` into the order of `groups` and merge each
+/// group into one axis.
+fn stack_of_matrices<'a, A>(term: &'a Term<'_, A>, groups: [&[u8]; 3]) -> CowArray<'a, A, Ix3>
+where
+    A: Clone,
+{
+    let position = |l: &u8| term.labels.iter().position(|m| m == l).unwrap();
+    let perm: Vec<_> = groups.iter().flat_map(|g| g.iter().map(position)).collect();
+    let len = |g: &[u8]| g.iter().map(|l| term.array.shape()[position(l)]).product();
+    let shape = (len(groups[0]), len(groups[1]), len(groups[2]));
+    let permuted = term.array.view().permuted_axes(perm);
+    if permuted.is_standard_layout() {
+        CowArray::from(permuted.into_shape(shape).unwrap())
+    } else {
+        let standard = permuted.as_standard_layout().into_owned();
+        CowArray::from(standard.into_shape(shape).unwrap())
+    }
+}
+
+/// Evaluate an Einstein summation of arrays.
+///
+/// Uses the [`einsum`][1] function, calling `ArrayView::from(&a).into_dyn()`
+/// on each argument `a`.
+///
+/// [1]: linalg/fn.einsum.html
+///
+/// ***Panics*** if the `einsum` function would return an error.
+///
+/// ```
+/// use ndarray::{arr1, arr2, einsum};
+///
+/// let a = arr2(&[[1., 2.],
+///                [3., 4.]]);
+/// let x = arr1(&[1., 1.]);
+/// assert_eq!(einsum!("ij,j->i", a, x), arr1(&[3., 7.]).into_dyn());
+/// assert_eq!(einsum!("i,i", x, x)[[]], 2.);
+/// ```
+#[macro_export]
+macro_rules! einsum {
+    ($subscripts:expr, $( $array:expr ),+ ) => {
+        $crate::linalg::einsum($subscripts, &[ $($crate::ArrayView::from(&$array).into_dyn() ),* ]).unwrap()
+    }
+}
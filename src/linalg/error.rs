@@ -0,0 +1,85 @@
+// Copyright 2016 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+use std::error::Error;
+use std::fmt;
+
+/// An error from a linear algebra routine.
+#[derive(Clone)]
+pub struct LinalgError {
+    // we want to be able to change this representation later
+    repr: LinalgErrorKind,
+}
+
+impl LinalgError {
+    /// Return the `LinalgErrorKind` of this error.
+    #[inline]
+    pub fn kind(&self) -> LinalgErrorKind {
+        self.repr
+    }
+
+    /// Create a new `LinalgError`
+    pub fn from_kind(error: LinalgErrorKind) -> Self {
+        LinalgError { repr: error }
+    }
+}
+
+/// Error code for an error from a linear algebra routine.
+///
+/// This enumeration is not exhaustive. The representation of the enum
+/// is not guaranteed.
+#[derive(Copy, Clone, Debug)]
+pub enum LinalgErrorKind {
+    /// the matrix is not square
+    NotSquare = 1,
+    /// incompatible shapes of the operands
+    IncompatibleShape,
+    /// the matrix is singular
+    Singular,
+    /// the matrix is not positive definite
+    NotPositiveDefinite,
+    /// an iterative algorithm did not converge
+    NotConverged,
+    #[doc(hidden)]
+    __Incomplete,
+}
+
+impl PartialEq for LinalgErrorKind {
+    #[inline(always)]
+    fn eq(&self, rhs: &Self) -> bool {
+        *self as u8 == *rhs as u8
+    }
+}
+
+impl PartialEq for LinalgError {
+    #[inline(always)]
+    fn eq(&self, rhs: &Self) -> bool {
+        self.repr == rhs.repr
+    }
+}
+
+impl Error for LinalgError {}
+
+impl fmt::Display for LinalgError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let description = match self.kind() {
+            LinalgErrorKind::NotSquare => "the matrix is not square",
+            LinalgErrorKind::IncompatibleShape => "incompatible shapes",
+            LinalgErrorKind::Singular => "the matrix is singular",
+            LinalgErrorKind::NotPositiveDefinite => "the matrix is not positive definite",
+            LinalgErrorKind::NotConverged => "the algorithm did not converge",
+            LinalgErrorKind::__Incomplete => "this error variant is not in use",
+        };
+        write!(f, "LinalgError/{:?}: {}", self.kind(), description)
+    }
+}
+
+impl fmt::Debug for LinalgError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self)
+    }
+}
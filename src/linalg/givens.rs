@@ -0,0 +1,165 @@
+// Copyright 2016 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::imp_prelude::*;
+use crate::{NdFloat, Zip};
+
+#[cfg(feature = "blas")]
+use super::impl_linalg::{blas_1d_params, blas_compat_1d, cast_as};
+#[cfg(feature = "blas")]
+use cblas_sys as blas_sys;
+
+/// A Givens (plane) rotation.
+///
+/// The rotation with cosine *c* and sine *s* maps each pair of elements
+/// (*x*, *y*) to (*c x* + *s y*, *c y* − *s x*).
+///
+/// ```
+/// use ndarray::arr2;
+/// use ndarray::linalg::Givens;
+///
+/// // Zero the element below the diagonal in the first column
+/// let mut a = arr2(&[[3_f64, 1.],
+///                    [4., 2.]]);
+/// let (rotation, r) = Givens::zeroing(a[[0, 0]], a[[1, 0]]);
+/// assert_eq!(r, 5.);
+/// rotation.rotate_rows(&mut a, 0, 1);
+/// assert!((a[[0, 0]] - 5.).abs() < 1e-12);
+/// assert!(a[[1, 0]].abs() < 1e-12);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Givens<A> {
+    c: A,
+    s: A,
+}
+
+impl<A> Givens<A>
+where
+    A: NdFloat,
+{
+    /// Create the rotation with cosine `c` and sine `s`.
+    ///
+    /// The rotation is only orthogonal if *c*² + *s*² = 1; this is not
+    /// checked.
+    pub fn new(c: A, s: A) -> Self {
+        Givens { c, s }
+    }
+
+    /// Create the rotation that maps (`a`, `b`) to (*r*, 0) and return it
+    /// together with *r*. This is `rotg` in BLAS.
+    ///
+    /// *r* has the sign of `a` (or of `b` if `a` is zero), so that the
+    /// cosine is never negative when `a` is nonzero.
+    pub fn zeroing(a: A, b: A) -> (Self, A) {
+        if b.is_zero() {
+            (Givens::new(A::one(), A::zero()), a)
+        } else if a.is_zero() {
+            (Givens::new(A::zero(), A::one()), b)
+        } else {
+            let r = a.hypot(b) * a.signum();
+            (Givens::new(a / r, b / r), r)
+        }
+    }
+
+    /// Return the cosine of the rotation.
+    pub fn cos(&self) -> A {
+        self.c
+    }
+
+    /// Return the sine of the rotation.
+    pub fn sin(&self) -> A {
+        self.s
+    }
+
+    /// Apply the rotation to the pairs of elements of `x` and `y`. This is
+    /// `rot` in BLAS.
+    ///
+    /// ***Panics*** if `x` and `y` have different lengths.<br>
+    /// *Note:* If enabled, uses blas `rot` for elements of `f32, f64` when
+    /// memory layout allows.
+    pub fn rotate<S1, S2>(&self, x: &mut ArrayBase<S1, Ix1>, y: &mut ArrayBase<S2, Ix1>)
+    where
+        S1: DataMut<Elem = A>,
+        S2: DataMut<Elem = A>,
+    {
+        assert_eq!(
+            x.len(),
+            y.len(),
+            "ndarray: vectors of different lengths can not be rotated"
+        );
+        self.rotate_impl(&mut x.view_mut(), &mut y.view_mut());
+    }
+
+    /// Apply the rotation to rows `i` and `j` of `a`.
+    ///
+    /// This replaces `a` with *G* `a`, where *G* is the identity matrix with
+    /// the rotation in rows and columns `i` and `j`.
+    ///
+    /// ***Panics*** if `i` and `j` are equal or out of bounds.
+    pub fn rotate_rows<S>(&self, a: &mut ArrayBase<S, Ix2>, i: usize, j: usize)
+    where
+        S: DataMut<Elem = A>,
+    {
+        assert_ne!(i, j, "ndarray: can not rotate a row with itself");
+        let (mut x, mut y) = a.multi_slice_mut((s![i, ..], s![j, ..]));
+        self.rotate_impl(&mut x, &mut y);
+    }
+
+    /// Apply the rotation to columns `i` and `j` of `a`.
+    ///
+    /// This replaces `a` with `a` *G*ᵀ, where *G* is the identity matrix with
+    /// the rotation in rows and columns `i` and `j`.
+    ///
+    /// ***Panics*** if `i` and `j` are equal or out of bounds.
+    pub fn rotate_columns<S>(&self, a: &mut ArrayBase<S, Ix2>, i: usize, j: usize)
+    where
+        S: DataMut<Elem = A>,
+    {
+        assert_ne!(i, j, "ndarray: can not rotate a column with itself");
+        let (mut x, mut y) = a.multi_slice_mut((s![.., i], s![.., j]));
+        self.rotate_impl(&mut x, &mut y);
+    }
+
+    fn rotate_impl(&self, x: &mut ArrayViewMut1<'_, A>, y: &mut ArrayViewMut1<'_, A>) {
+        let (c, s) = (self.c, self.s);
+
+        #[cfg(feature = "blas")]
+        macro_rules! rot {
+            ($ty:ty, $rot:ident) => {
+                if blas_compat_1d::<$ty, _>(x) && blas_compat_1d::<$ty, _>(y) {
+                    unsafe {
+                        let (x_ptr, n, incx) =
+                            blas_1d_params(x.as_mut_ptr(), x.len(), x.strides()[0]);
+                        let (y_ptr, _, incy) =
+                            blas_1d_params(y.as_mut_ptr(), y.len(), y.strides()[0]);
+                        blas_sys::$rot(
+                            n,
+                            x_ptr as *mut _,
+                            incx,
+                            y_ptr as *mut _,
+                            incy,
+                            cast_as(&c),
+                            cast_as(&s),
+                        );
+                    }
+                    return;
+                }
+            };
+        }
+        #[cfg(feature = "blas")]
+        rot!(f32, cblas_srot);
+        #[cfg(feature = "blas")]
+        rot!(f64, cblas_drot);
+
+        Zip::from(x).and(y).apply(|x, y| {
+            let (x0, y0) = (*x, *y);
+            *x = c * x0 + s * y0;
+            *y = c * y0 - s * x0;
+        });
+    }
+}
@@ -0,0 +1,79 @@
+// Copyright 2016 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use half::f16;
+
+use crate::imp_prelude::*;
+
+use super::general_mat_mul;
+use super::impl_linalg::general_dot_shape_error;
+
+/// Side of the square tiles of the output that are computed at a time.
+const TILE: usize = 64;
+
+/// Half precision matrix multiplication with single precision accumulation.
+///
+/// Compute C ← α A B + β C
+///
+/// where A and B hold `f16` elements and C holds `f32` elements. The array
+/// shapes must agree in the way that if `a` is *M* × *N*, then `b` is
+/// *N* × *K* and `c` is *M* × *K*.
+///
+/// The inputs are converted to `f32` one tile at a time, so only a few rows
+/// of `a` and columns of `b` are held in single precision at once. The
+/// products are computed and accumulated as `f32` by [`general_mat_mul`].
+///
+/// ***Panics*** if array shapes are not compatible
+///
+/// [`general_mat_mul`]: fn.general_mat_mul.html
+///
+/// **Requires crate feature `"half"`**
+///
+/// ```
+/// use half::f16;
+/// use ndarray::{arr2, Array2};
+/// use ndarray::linalg::general_mat_mul_f16;
+///
+/// let a = arr2(&[[1., 2.],
+///                [3., 4.]]).mapv(f16::from_f32);
+/// let mut c = Array2::zeros((2, 2));
+/// general_mat_mul_f16(1., &a, &a, 0., &mut c);
+/// assert_eq!(c, arr2(&[[7., 10.],
+///                      [15., 22.]]));
+/// ```
+pub fn general_mat_mul_f16<S1, S2, S3>(
+    alpha: f32,
+    a: &ArrayBase<S1, Ix2>,
+    b: &ArrayBase<S2, Ix2>,
+    beta: f32,
+    c: &mut ArrayBase<S3, Ix2>,
+) where
+    S1: Data<Elem = f16>,
+    S2: Data<Elem = f16>,
+    S3: DataMut<Elem = f32>,
+{
+    let ((m, k), (k2, n)) = (a.dim(), b.dim());
+    let (m2, n2) = c.dim();
+    if k != k2 || m != m2 || n != n2 {
+        general_dot_shape_error(m, k, k2, n, m2, n2);
+    }
+
+    for (b_tile, mut c_cols) in b
+        .axis_chunks_iter(Axis(1), TILE)
+        .zip(c.axis_chunks_iter_mut(Axis(1), TILE))
+    {
+        let b_tile = b_tile.mapv(f32::from);
+        for (a_tile, mut c_tile) in a
+            .axis_chunks_iter(Axis(0), TILE)
+            .zip(c_cols.axis_chunks_iter_mut(Axis(0), TILE))
+        {
+            let a_tile = a_tile.mapv(f32::from);
+            general_mat_mul(alpha, &a_tile, &b_tile, beta, &mut c_tile);
+        }
+    }
+}
@@ -0,0 +1,276 @@
+// Copyright 2016 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use num_complex::Complex;
+use num_traits::{Float, Zero};
+
+use crate::imp_prelude::*;
+
+use super::general_mat_mul;
+use super::Triangle;
+
+#[cfg(feature = "blas")]
+use super::impl_linalg::{blas_index, blas_layout, blas_stride, cast_as, same_type};
+#[cfg(feature = "blas")]
+use cblas_sys as blas_sys;
+#[cfg(feature = "blas")]
+use cblas_sys::{CblasLeft, CblasNoTrans, CBLAS_LAYOUT};
+
+/// A square complex matrix that is known to be Hermitian.
+///
+/// Only the elements in the `uplo` triangle of the wrapped matrix are read;
+/// the elements of the other triangle are taken to be the complex
+/// conjugates of their mirror images, and the imaginary parts of the
+/// diagonal elements are taken to be zero.
+#[derive(Copy, Clone, Debug)]
+pub struct Hermitian<T> {
+    matrix: T,
+    uplo: Triangle,
+}
+
+impl<'a, T> Hermitian<ArrayView2<'a, Complex<T>>>
+where
+    T: Float,
+{
+    /// Wrap the square matrix `matrix`, whose elements are stored in its
+    /// `uplo` triangle.
+    ///
+    /// ***Panics*** if `matrix` is not square.
+    pub fn new(matrix: ArrayView2<'a, Complex<T>>, uplo: Triangle) -> Self {
+        let (m, n) = matrix.dim();
+        assert_eq!(m, n, "ndarray: a hermitian matrix must be square");
+        Hermitian { matrix, uplo }
+    }
+
+    /// Return the triangle of the matrix that holds the elements.
+    pub fn uplo(&self) -> Triangle {
+        self.uplo
+    }
+
+    /// Return a view of the wrapped matrix, including the unread triangle.
+    pub fn view(&self) -> ArrayView2<'a, Complex<T>> {
+        self.matrix
+    }
+
+    /// Return an owned matrix with both triangles filled in.
+    pub fn to_full(&self) -> Array2<Complex<T>> {
+        let a = &self.matrix;
+        let upper = self.uplo == Triangle::Upper;
+        Array::from_shape_fn(a.raw_dim(), |(i, j)| {
+            if i == j {
+                Complex::new(a[[i, i]].re, T::zero())
+            } else if (i < j) == upper {
+                a[[i, j]]
+            } else {
+                a[[j, i]].conj()
+            }
+        })
+    }
+}
+
+/// Hermitian rank-k update.
+///
+/// Compute C ← α A Aᴴ + β C
+///
+/// where A is a *N* × *K* complex matrix, C is a *N* × *N* Hermitian matrix
+/// and α and β are real. Only the `uplo` triangle of `c` is read and written;
+/// the elements of the other triangle are left unchanged, and the imaginary
+/// parts of the diagonal elements are set to zero.
+///
+/// ***Panics*** if array shapes are not compatible<br>
+/// *Note:* If enabled, uses blas `herk` for elements of `Complex32,
+/// Complex64` when memory layout allows.
+///
+/// ```
+/// use ndarray::arr2;
+/// use ndarray::linalg::{hermitian_rank_k_update, Triangle};
+/// use num_complex::Complex64;
+///
+/// let i = Complex64::i();
+/// let a = arr2(&[[1. + i],
+///                [2. * i]]);
+/// let mut c = arr2(&[[0. * i, 0. * i],
+///                    [0. * i, 0. * i]]);
+/// hermitian_rank_k_update(1., &a, 0., &mut c, Triangle::Upper);
+/// assert_eq!(c, arr2(&[[2. + 0. * i, 2. - 2. * i],
+///                      [0. * i, 4. + 0. * i]]));
+/// ```
+pub fn hermitian_rank_k_update<T, S1, S2>(
+    alpha: T,
+    a: &ArrayBase<S1, Ix2>,
+    beta: T,
+    c: &mut ArrayBase<S2, Ix2>,
+    uplo: Triangle,
+) where
+    S1: Data<Elem = Complex<T>>,
+    S2: DataMut<Elem = Complex<T>>,
+    T: Float + 'static,
+{
+    let (n, k) = a.dim();
+    let (m2, n2) = c.dim();
+    if m2 != n || n2 != n {
+        hermitian_shape_error(n, k, k, n, m2, n2);
+    }
+
+    #[cfg(feature = "blas")]
+    macro_rules! herk {
+        ($ty:ty, $herk:ident) => {
+            if same_type::<T, $ty>() {
+                // Read in the other memory order, `a` would give the
+                // conjugate of the result, so the layouts must agree.
+                if let Some(layout) = common_layout(
+                    blas_layout::<Complex<$ty>, _>(a),
+                    blas_layout::<Complex<$ty>, _>(c),
+                ) {
+                    let a_stride = blas_stride(a, layout);
+                    let c_stride = blas_stride(c, layout);
+                    unsafe {
+                        blas_sys::$herk(
+                            layout,
+                            uplo.to_cblas(),
+                            CblasNoTrans,
+                            n as blas_index,
+                            k as blas_index,
+                            cast_as::<T, $ty>(&alpha),
+                            a.as_ptr() as *const _,
+                            a_stride,
+                            cast_as::<T, $ty>(&beta),
+                            c.as_mut_ptr() as *mut _,
+                            c_stride,
+                        );
+                    }
+                    return;
+                }
+            }
+        };
+    }
+    #[cfg(feature = "blas")]
+    herk!(f32, cblas_cherk);
+    #[cfg(feature = "blas")]
+    herk!(f64, cblas_zherk);
+
+    /* general */
+
+    for i in 0..n {
+        let cols = match uplo {
+            Triangle::Upper => i..n,
+            Triangle::Lower => 0..i + 1,
+        };
+        for j in cols {
+            let sum = a
+                .row(i)
+                .iter()
+                .zip(a.row(j))
+                .fold(Complex::zero(), |acc, (&x, y)| acc + x * y.conj());
+            let elt = &mut c[[i, j]];
+            *elt = if beta.is_zero() {
+                sum * alpha
+            } else {
+                sum * alpha + *elt * beta
+            };
+            if i == j {
+                elt.im = T::zero();
+            }
+        }
+    }
+}
+
+/// Hermitian matrix multiplication.
+///
+/// Compute C ← α A B + β C
+///
+/// where A is a *M* × *M* Hermitian matrix, B is a *M* × *N* matrix and
+/// C is a *M* × *N* matrix.
+///
+/// ***Panics*** if array shapes are not compatible<br>
+/// *Note:* If enabled, uses blas `hemm` for elements of `Complex32,
+/// Complex64` when memory layout allows.
+pub fn hermitian_mat_mul<T, S2, S3>(
+    alpha: Complex<T>,
+    a: Hermitian<ArrayView2<'_, Complex<T>>>,
+    b: &ArrayBase<S2, Ix2>,
+    beta: Complex<T>,
+    c: &mut ArrayBase<S3, Ix2>,
+) where
+    S2: Data<Elem = Complex<T>>,
+    S3: DataMut<Elem = Complex<T>>,
+    T: Float + 'static,
+{
+    let hermitian = a;
+    let a = a.matrix;
+    let ((m, _), (m2, n)) = (a.dim(), b.dim());
+    let (m3, n2) = c.dim();
+    if m != m2 || m != m3 || n != n2 {
+        hermitian_shape_error(m, m, m2, n, m3, n2);
+    }
+
+    #[cfg(feature = "blas")]
+    macro_rules! hemm {
+        ($ty:ty, $hemm:ident) => {
+            if same_type::<T, $ty>() {
+                // hemm has no transpose flags, and `a` read in the other
+                // memory order is its conjugate, so all layouts must agree.
+                let layout = common_layout(
+                    blas_layout::<Complex<$ty>, _>(&a),
+                    blas_layout::<Complex<$ty>, _>(b),
+                );
+                if let Some(layout) = common_layout(layout, blas_layout::<Complex<$ty>, _>(c)) {
+                    let a_stride = blas_stride(&a, layout);
+                    let b_stride = blas_stride(b, layout);
+                    let c_stride = blas_stride(c, layout);
+                    unsafe {
+                        blas_sys::$hemm(
+                            layout,
+                            CblasLeft,
+                            hermitian.uplo.to_cblas(),
+                            m as blas_index,
+                            n as blas_index,
+                            &alpha as *const Complex<T> as *const _,
+                            a.as_ptr() as *const _,
+                            a_stride,
+                            b.as_ptr() as *const _,
+                            b_stride,
+                            &beta as *const Complex<T> as *const _,
+                            c.as_mut_ptr() as *mut _,
+                            c_stride,
+                        );
+                    }
+                    return;
+                }
+            }
+        };
+    }
+    #[cfg(feature = "blas")]
+    hemm!(f32, cblas_chemm);
+    #[cfg(feature = "blas")]
+    hemm!(f64, cblas_zhemm);
+
+    /* general */
+
+    general_mat_mul(alpha, &hermitian.to_full(), b, beta, c);
+}
+
+/// Return the layout of two matrices if they have the same one.
+#[cfg(feature = "blas")]
+fn common_layout(a: Option<CBLAS_LAYOUT>, b: Option<CBLAS_LAYOUT>) -> Option<CBLAS_LAYOUT> {
+    match (a, b) {
+        (Some(CBLAS_LAYOUT::CblasRowMajor), Some(CBLAS_LAYOUT::CblasRowMajor))
+        | (Some(CBLAS_LAYOUT::CblasColMajor), Some(CBLAS_LAYOUT::CblasColMajor)) => a,
+        _ => None,
+    }
+}
+
+#[cold]
+#[inline(never)]
+fn hermitian_shape_error(m: usize, k: usize, k2: usize, n: usize, c1: usize, c2: usize) -> ! {
+    panic!(
+        "ndarray: inputs {} × {}, {} × {}, and output {} × {} are not compatible for hermitian \
+         matrix multiplication",
+        m, k, k2, n, c1, c2
+    );
+}
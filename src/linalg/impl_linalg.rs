@@ -9,21 +9,21 @@
 use crate::imp_prelude::*;
 use crate::numeric_util;
 
-use crate::{LinalgScalar, Zip};
+use crate::{LinalgScalar, NdFloat, Zip};
 
 use std::any::TypeId;
 
 #[cfg(feature = "blas")]
 use std::cmp;
 #[cfg(feature = "blas")]
-use std::mem::swap;
-#[cfg(feature = "blas")]
 use std::os::raw::c_int;
 
 #[cfg(feature = "blas")]
 use cblas_sys as blas_sys;
 #[cfg(feature = "blas")]
-use cblas_sys::{CblasNoTrans, CblasRowMajor, CblasTrans, CBLAS_LAYOUT};
+use cblas_sys::{CblasNoTrans, CblasTrans, CBLAS_LAYOUT, CBLAS_TRANSPOSE};
+#[cfg(feature = "blas")]
+use num_complex::{Complex32 as c32, Complex64 as c64};
 
 /// len of vector before we use blas
 #[cfg(feature = "blas")]
@@ -33,7 +33,26 @@ const DOT_BLAS_CUTOFF: usize = 32;
 const GEMM_BLAS_CUTOFF: usize = 7;
 #[cfg(feature = "blas")]
 #[allow(non_camel_case_types)]
-type blas_index = c_int; // blas index type
+pub(super) type blas_index = c_int; // blas index type
+
+/// Return early from a vector reduction with the result of the blas routine
+/// `$func`, if the element type is `$ty` and the memory layout allows.
+///
+/// The routine sees the elements in memory order, which must not affect the
+/// result. Zero strides are excluded, since blas treats a non-positive
+/// increment as an empty vector.
+#[cfg(feature = "blas")]
+macro_rules! blas_reduce_1d {
+    ($v:expr, $ty:ty, $func:ident) => {
+        if $v.len() >= DOT_BLAS_CUTOFF && blas_compat_1d::<$ty, _>($v) && $v.strides()[0] != 0 {
+            unsafe {
+                let (ptr, n, inc) = blas_1d_params($v.as_ptr(), $v.len(), $v.strides()[0]);
+                let ret = blas_sys::$func(n, ptr as *const $ty, inc.abs());
+                return cast_as::<$ty, A>(&ret);
+            }
+        }
+    };
+}
 
 impl<A, S> ArrayBase<S, Ix1>
 where
@@ -54,8 +73,8 @@ where
     /// shape *N*.
     ///
     /// **Panics** if the array shapes are incompatible.<br>
-    /// *Note:* If enabled, uses blas `dot` for elements of `f32, f64` when memory
-    /// layout allows.
+    /// *Note:* If enabled, uses blas `dot` for elements of `f32, f64` (and `dotu` for
+    /// `Complex32, Complex64`) when memory layout allows.
     pub fn dot<Rhs>(&self, rhs: &Rhs) -> <Self as Dot<Rhs>>::Output
     where
         Self: Dot<Rhs>,
@@ -63,6 +82,155 @@ where
         Dot::dot(self, rhs)
     }
 
+    /// Return the Euclidean norm (L2 norm) of the vector.
+    ///
+    /// The sum of squares is scaled as it is accumulated, so that it does
+    /// not overflow or underflow unless the result does. The norm is infinite
+    /// if any element is infinite, and NaN if any element is NaN.
+    ///
+    /// *Note:* If enabled, uses blas `nrm2` for elements of `f32, f64` when
+    /// memory layout allows.
+    ///
+    /// ```
+    /// use ndarray::arr1;
+    ///
+    /// assert_eq!(arr1(&[3., -4.]).norm_l2(), 5.);
+    /// ```
+    pub fn norm_l2(&self) -> A
+    where
+        A: NdFloat,
+    {
+        #[cfg(feature = "blas")]
+        blas_reduce_1d!(self, f32, cblas_snrm2);
+        #[cfg(feature = "blas")]
+        blas_reduce_1d!(self, f64, cblas_dnrm2);
+
+        let mut scale = A::zero();
+        let mut ssq = A::one();
+        let mut infinite = false;
+        for &x in self {
+            if x.is_infinite() {
+                // skip it, since scaling by an infinite element gives NaN
+                infinite = true;
+            } else if !x.is_zero() {
+                let ax = x.abs();
+                if scale < ax {
+                    ssq = A::one() + ssq * (scale / ax) * (scale / ax);
+                    scale = ax;
+                } else {
+                    ssq += (ax / scale) * (ax / scale);
+                }
+            }
+        }
+        if infinite && !ssq.is_nan() {
+            A::infinity()
+        } else {
+            scale * ssq.sqrt()
+        }
+    }
+
+    /// Return the sum of the absolute values of the elements (L1 norm).
+    ///
+    /// *Note:* If enabled, uses blas `asum` for elements of `f32, f64` when
+    /// memory layout allows.
+    ///
+    /// ```
+    /// use ndarray::arr1;
+    ///
+    /// assert_eq!(arr1(&[3., -4.]).norm_l1(), 7.);
+    /// ```
+    pub fn norm_l1(&self) -> A
+    where
+        A: NdFloat,
+    {
+        #[cfg(feature = "blas")]
+        blas_reduce_1d!(self, f32, cblas_sasum);
+        #[cfg(feature = "blas")]
+        blas_reduce_1d!(self, f64, cblas_dasum);
+
+        self.fold(A::zero(), |acc, &x| acc + x.abs())
+    }
+
+    /// Return the largest absolute value of the elements (L∞ norm), or zero
+    /// if the vector is empty.
+    ///
+    /// ```
+    /// use ndarray::arr1;
+    ///
+    /// assert_eq!(arr1(&[3., -4.]).norm_max(), 4.);
+    /// ```
+    pub fn norm_max(&self) -> A
+    where
+        A: NdFloat,
+    {
+        self.fold(A::zero(), |acc, &x| acc.max(x.abs()))
+    }
+
+    /// Return the index of the first element with the largest absolute
+    /// value, or `None` if the vector is empty.
+    ///
+    /// If the vector contains NaN, the index of the first NaN is returned
+    /// instead, like [`.argmax()`](#method.argmax).
+    ///
+    /// *Note:* If enabled, uses blas `iamax` for elements of `f32, f64` when
+    /// memory layout allows.
+    ///
+    /// ```
+    /// use ndarray::arr1;
+    /// use std::f64::NAN;
+    ///
+    /// assert_eq!(arr1(&[1., -4., 2., 4.]).argmax_abs(), Some(1));
+    /// assert_eq!(arr1(&[1., -4., NAN, NAN]).argmax_abs(), Some(2));
+    /// ```
+    pub fn argmax_abs(&self) -> Option<usize>
+    where
+        A: NdFloat,
+    {
+        if self.is_empty() {
+            return None;
+        }
+        // Checked up front, since what blas `iamax` returns for NaN depends
+        // on the implementation.
+        if let Some(i) = self.iter().position(|x| x.is_nan()) {
+            return Some(i);
+        }
+
+        #[cfg(feature = "blas")]
+        macro_rules! iamax {
+            ($ty:ty, $func:ident) => {
+                // Only for non-negative strides: reversing the vector would
+                // change which of several equal elements is found first.
+                if self.len() >= DOT_BLAS_CUTOFF
+                    && blas_compat_1d::<$ty, _>(self)
+                    && self.strides()[0] > 0
+                {
+                    unsafe {
+                        let i = blas_sys::$func(
+                            self.len() as blas_index,
+                            self.as_ptr() as *const $ty,
+                            self.strides()[0] as blas_index,
+                        );
+                        return Some(i as usize);
+                    }
+                }
+            };
+        }
+        #[cfg(feature = "blas")]
+        iamax!(f32, cblas_isamax);
+        #[cfg(feature = "blas")]
+        iamax!(f64, cblas_idamax);
+
+        let mut index = 0;
+        let mut max = self[0].abs();
+        for (i, &x) in self.iter().enumerate().skip(1) {
+            if x.abs() > max {
+                index = i;
+                max = x.abs();
+            }
+        }
+        Some(index)
+    }
+
     fn dot_generic<S2>(&self, rhs: &ArrayBase<S2, Ix1>) -> A
     where
         S2: Data<Elem = A>,
@@ -124,8 +292,34 @@ where
                 }};
             }
 
+            // complex dot products return through an out pointer
+            macro_rules! dotu {
+                ($ty:ty, $func:ident) => {{
+                    if blas_compat_1d::<$ty, _>(self) && blas_compat_1d::<$ty, _>(rhs) {
+                        unsafe {
+                            let (lhs_ptr, n, incx) =
+                                blas_1d_params(self.ptr.as_ptr(), self.len(), self.strides()[0]);
+                            let (rhs_ptr, _, incy) =
+                                blas_1d_params(rhs.ptr.as_ptr(), rhs.len(), rhs.strides()[0]);
+                            let mut ret = <$ty>::new(0., 0.);
+                            blas_sys::$func(
+                                n,
+                                lhs_ptr as *const _,
+                                incx,
+                                rhs_ptr as *const _,
+                                incy,
+                                &mut ret as *mut $ty as *mut _,
+                            );
+                            return cast_as::<$ty, A>(&ret);
+                        }
+                    }
+                }};
+            }
+
             dot! {f32, cblas_sdot};
             dot! {f64, cblas_ddot};
+            dotu! {c32, cblas_cdotu_sub};
+            dotu! {c64, cblas_zdotu_sub};
         }
         self.dot_generic(rhs)
     }
@@ -137,7 +331,7 @@ where
 /// which agrees with our pointer for non-negative strides, but
 /// is at the opposite end for negative strides.
 #[cfg(feature = "blas")]
-unsafe fn blas_1d_params<A>(
+pub(super) unsafe fn blas_1d_params<A>(
     ptr: *const A,
     len: usize,
     stride: isize,
@@ -157,7 +351,8 @@ unsafe fn blas_1d_params<A>(
 /// Matrix Multiplication
 ///
 /// For two-dimensional arrays, the dot method computes the matrix
-/// multiplication.
+/// multiplication. For three-dimensional arrays, it computes a batch of
+/// matrix multiplications along the first axis.
 pub trait Dot<Rhs> {
     /// The result of the operation.
     ///
@@ -180,8 +375,8 @@ where
     /// of complex operands, and thus not their inner product).
     ///
     /// **Panics** if the arrays are not of the same length.<br>
-    /// *Note:* If enabled, uses blas `dot` for elements of `f32, f64` when memory
-    /// layout allows.
+    /// *Note:* If enabled, uses blas `dot` for elements of `f32, f64` (and `dotu` for
+    /// `Complex32, Complex64`) when memory layout allows.
     fn dot(&self, rhs: &ArrayBase<S2, Ix1>) -> A {
         self.dot_impl(rhs)
     }
@@ -225,9 +420,10 @@ where
     /// **Panics** if shapes are incompatible or the number of elements in the
     /// result would overflow `isize`.
     ///
-    /// *Note:* If enabled, uses blas `gemv/gemm` for elements of `f32, f64`
-    /// when memory layout allows. The default matrixmultiply backend
-    /// is otherwise used for `f32, f64` for all memory layouts.
+    /// *Note:* If enabled, uses blas `gemv/gemm` for elements of `f32, f64,
+    /// Complex32, Complex64` when memory layout allows. The default
+    /// matrixmultiply backend is otherwise used for `f32, f64` for all memory
+    /// layouts.
     ///
     /// ```
     /// use ndarray::arr2;
@@ -265,6 +461,16 @@ where
             dot_shape_error(m, k, k2, n);
         }
 
+        // Tiny square products skip the call overhead of the general kernels
+        if m == k && k == n {
+            match m {
+                2 => return small_mat_mul(2, &a, &b),
+                3 => return small_mat_mul(3, &a, &b),
+                4 => return small_mat_mul(4, &a, &b),
+                _ => {}
+            }
+        }
+
         let lhs_s0 = a.strides()[0];
         let rhs_s0 = b.strides()[0];
         let column_major = lhs_s0 == 1 && rhs_s0 == 1;
@@ -280,6 +486,55 @@ where
     }
 }
 
+/// Return the product of the *N* × *N* matrices `a` and `b`.
+///
+/// Inlined into call sites where `n` is a small constant, so that the loops
+/// are unrolled.
+#[inline(always)]
+fn small_mat_mul<A>(n: usize, a: &ArrayView2<'_, A>, b: &ArrayView2<'_, A>) -> Array2<A>
+where
+    A: LinalgScalar,
+{
+    debug_assert!(a.dim() == (n, n) && b.dim() == (n, n));
+    let mut v = Vec::with_capacity(n * n);
+    for i in 0..n {
+        for j in 0..n {
+            let mut sum = A::zero();
+            for k in 0..n {
+                unsafe {
+                    sum = sum + *a.uget((i, k)) * *b.uget((k, j));
+                }
+            }
+            v.push(sum);
+        }
+    }
+    unsafe { Array::from_shape_vec_unchecked((n, n), v) }
+}
+
+/// Return the product of the *N* × *N* matrix `a` and the *N*-element vector
+/// `x`.
+///
+/// Inlined into call sites where `n` is a small constant, so that the loops
+/// are unrolled.
+#[inline(always)]
+fn small_mat_vec_mul<A>(n: usize, a: &ArrayView2<'_, A>, x: &ArrayView1<'_, A>) -> Array1<A>
+where
+    A: LinalgScalar,
+{
+    debug_assert!(a.dim() == (n, n) && x.dim() == n);
+    let mut v = Vec::with_capacity(n);
+    for i in 0..n {
+        let mut sum = A::zero();
+        for k in 0..n {
+            unsafe {
+                sum = sum + *a.uget((i, k)) * *x.uget(k);
+            }
+        }
+        v.push(sum);
+    }
+    Array::from(v)
+}
+
 /// Assumes that `m` and `n` are ≤ `isize::MAX`.
 #[cold]
 #[inline(never)]
@@ -296,7 +551,14 @@ fn dot_shape_error(m: usize, k: usize, k2: usize, n: usize) -> ! {
 
 #[cold]
 #[inline(never)]
-fn general_dot_shape_error(m: usize, k: usize, k2: usize, n: usize, c1: usize, c2: usize) -> ! {
+pub(super) fn general_dot_shape_error(
+    m: usize,
+    k: usize,
+    k2: usize,
+    n: usize,
+    c1: usize,
+    c2: usize,
+) -> ! {
     panic!("ndarray: inputs {} × {}, {} × {}, and output {} × {} are not compatible for matrix multiplication",
            m, k, k2, n, c1, c2);
 }
@@ -323,6 +585,16 @@ where
             dot_shape_error(m, a, n, 1);
         }
 
+        if m == a {
+            let (lhs, rhs) = (self.view(), rhs.view());
+            match m {
+                2 => return small_mat_vec_mul(2, &lhs, &rhs),
+                3 => return small_mat_vec_mul(3, &lhs, &rhs),
+                4 => return small_mat_vec_mul(4, &lhs, &rhs),
+                _ => {}
+            }
+        }
+
         // Avoid initializing the memory in vec -- set it during iteration
         unsafe {
             let mut c = Array::uninitialized(m);
@@ -332,6 +604,180 @@ where
     }
 }
 
+impl<A, S> ArrayBase<S, Ix3>
+where
+    S: Data<Elem = A>,
+{
+    /// Perform batched matrix multiplication of arrays `self` and `rhs`.
+    ///
+    /// Both arrays are treated as stacks of matrices along their first axis,
+    /// and the matrices are multiplied pairwise. The array shapes must agree
+    /// in the way that if `self` is *B* × *M* × *N*, then `rhs` is
+    /// *B* × *N* × *K*.
+    ///
+    /// Return a result array with shape *B* × *M* × *K*.
+    ///
+    /// **Panics** if shapes are incompatible or the number of elements in the
+    /// result would overflow `isize`.
+    ///
+    /// *Note:* Each product in the batch is computed like the two-dimensional
+    /// `dot`, so the same blas and matrixmultiply backends are used.
+    ///
+    /// ```
+    /// use ndarray::arr3;
+    ///
+    /// let a = arr3(&[[[1., 2.],
+    ///                 [0., 1.]],
+    ///                [[2., 0.],
+    ///                 [0., 2.]]]);
+    /// let b = arr3(&[[[1., 2.],
+    ///                 [2., 3.]],
+    ///                [[1., 0.],
+    ///                 [1., 1.]]]);
+    ///
+    /// assert!(
+    ///     a.dot(&b) == arr3(&[[[5., 8.],
+    ///                          [2., 3.]],
+    ///                         [[2., 0.],
+    ///                          [2., 2.]]])
+    /// );
+    /// ```
+    pub fn dot<Rhs>(&self, rhs: &Rhs) -> <Self as Dot<Rhs>>::Output
+    where
+        Self: Dot<Rhs>,
+    {
+        Dot::dot(self, rhs)
+    }
+}
+
+impl<A, S, S2> Dot<ArrayBase<S2, Ix3>> for ArrayBase<S, Ix3>
+where
+    S: Data<Elem = A>,
+    S2: Data<Elem = A>,
+    A: LinalgScalar,
+{
+    type Output = Array3<A>;
+    fn dot(&self, rhs: &ArrayBase<S2, Ix3>) -> Array3<A> {
+        let ((batch, m, k), (batch2, k2, n)) = (self.dim(), rhs.dim());
+        let len_ok = match batch.checked_mul(m).and_then(|len| len.checked_mul(n)) {
+            Some(len) => len <= ::std::isize::MAX as usize,
+            None => false,
+        };
+        if batch != batch2 || k != k2 || !len_ok {
+            batch_dot_shape_error(batch, m, k, batch2, k2, n);
+        }
+
+        // A is Copy so this is safe
+        let mut v = Vec::with_capacity(batch * m * n);
+        let mut c;
+        unsafe {
+            v.set_len(batch * m * n);
+            c = Array::from_shape_vec_unchecked((batch, m, n), v);
+        }
+        Zip::from(self.outer_iter())
+            .and(rhs.outer_iter())
+            .and(c.outer_iter_mut())
+            .apply(|a, b, mut c| mat_mul_impl(A::one(), &a, &b, A::zero(), &mut c));
+        c
+    }
+}
+
+#[cold]
+#[inline(never)]
+fn batch_dot_shape_error(b: usize, m: usize, k: usize, b2: usize, k2: usize, n: usize) -> ! {
+    panic!("ndarray: inputs {} × {} × {} and {} × {} × {} are not compatible for batched matrix multiplication",
+           b, m, k, b2, k2, n);
+}
+
+impl<A, S> ArrayBase<S, Ix2>
+where
+    S: DataMut<Elem = A>,
+{
+    /// Rank-1 update of a matrix.
+    ///
+    /// Compute A ← A + α x yᵀ
+    ///
+    /// where A is `self`, a *M* × *N* matrix, x is an *M*-element vector and
+    /// y is an *N*-element vector.
+    ///
+    /// ***Panics*** if array shapes are not compatible<br>
+    /// *Note:* If enabled, uses blas `ger` (`geru` for complex elements) for
+    /// elements of `f32, f64, Complex32, Complex64` when memory layout allows.
+    ///
+    /// ```
+    /// use ndarray::{arr1, arr2};
+    ///
+    /// let mut a = arr2(&[[1., 0.],
+    ///                    [0., 1.]]);
+    /// a.general_rank1_update(2., &arr1(&[1., 2.]), &arr1(&[3., 4.]));
+    /// assert_eq!(a, arr2(&[[7., 8.],
+    ///                      [12., 17.]]));
+    /// ```
+    pub fn general_rank1_update<S1, S2>(
+        &mut self,
+        alpha: A,
+        x: &ArrayBase<S1, Ix1>,
+        y: &ArrayBase<S2, Ix1>,
+    ) where
+        S1: Data<Elem = A>,
+        S2: Data<Elem = A>,
+        A: LinalgScalar,
+    {
+        let (m, n) = self.dim();
+        if x.len() != m || y.len() != n {
+            general_dot_shape_error(x.len(), 1, 1, y.len(), m, n);
+        }
+
+        #[cfg(feature = "blas")]
+        macro_rules! ger {
+            ($ty:tt, $ger:ident) => {
+                if let Some(layout) = blas_layout::<$ty, _>(self) {
+                    // blas rejects a zero increment, so broadcast vectors
+                    // take the general path.
+                    if blas_compat_1d::<$ty, _>(x)
+                        && blas_compat_1d::<$ty, _>(y)
+                        && x.strides()[0] != 0
+                        && y.strides()[0] != 0
+                    {
+                        let a_stride = blas_stride(self, layout);
+                        unsafe {
+                            let (x_ptr, _, incx) = blas_1d_params(x.as_ptr(), m, x.strides()[0]);
+                            let (y_ptr, _, incy) = blas_1d_params(y.as_ptr(), n, y.strides()[0]);
+                            blas_sys::$ger(
+                                layout,
+                                m as blas_index,
+                                n as blas_index,
+                                blas_scalar!($ty, alpha),
+                                x_ptr as *const _,
+                                incx,
+                                y_ptr as *const _,
+                                incy,
+                                self.as_mut_ptr() as *mut _,
+                                a_stride,
+                            );
+                        }
+                        return;
+                    }
+                }
+            };
+        }
+        #[cfg(feature = "blas")]
+        ger!(f32, cblas_sger);
+        #[cfg(feature = "blas")]
+        ger!(f64, cblas_dger);
+        #[cfg(feature = "blas")]
+        ger!(c32, cblas_cgeru);
+        #[cfg(feature = "blas")]
+        ger!(c64, cblas_zgeru);
+
+        /* general */
+
+        Zip::from(self.outer_iter_mut())
+            .and(x)
+            .apply(|mut row, &xi| row.scaled_add(alpha * xi, y));
+    }
+}
+
 impl<A, S, D> ArrayBase<S, D>
 where
     S: Data<Elem = A>,
@@ -343,7 +789,9 @@ where
     ///
     /// If their shapes disagree, `rhs` is broadcast to the shape of `self`.
     ///
-    /// **Panics** if broadcasting isn’t possible.
+    /// **Panics** if broadcasting isn’t possible.<br>
+    /// *Note:* If enabled, uses blas `axpy` for elements of `f32, f64,
+    /// Complex32, Complex64` when the shapes agree and memory layout allows.
     pub fn scaled_add<S2, E>(&mut self, alpha: A, rhs: &ArrayBase<S2, E>)
     where
         S: DataMut,
@@ -351,8 +799,136 @@ where
         A: LinalgScalar,
         E: Dimension,
     {
+        #[cfg(feature = "blas")]
+        macro_rules! axpy {
+            ($ty:tt, $axpy:ident) => {
+                if same_type::<A, $ty>()
+                    && self.len() >= DOT_BLAS_CUTOFF
+                    && self.len() <= blas_index::max_value() as usize
+                    && self.shape() == rhs.shape()
+                {
+                    if self.ndim() == 1 {
+                        let (x_stride, y_stride) = (rhs.strides()[0], self.strides()[0]);
+                        if blas_compat_stride(x_stride) && blas_compat_stride(y_stride) {
+                            unsafe {
+                                let (x_ptr, n, incx) =
+                                    blas_1d_params(rhs.as_ptr(), rhs.len(), x_stride);
+                                let (y_ptr, _, incy) =
+                                    blas_1d_params(self.as_mut_ptr(), self.len(), y_stride);
+                                blas_sys::$axpy(
+                                    n,
+                                    blas_scalar!($ty, alpha),
+                                    x_ptr as *const _,
+                                    incx,
+                                    y_ptr as *mut _,
+                                    incy,
+                                );
+                            }
+                            return;
+                        }
+                    } else if self.strides() == rhs.strides() {
+                        // Equal shapes and strides: the elements correspond
+                        // in memory order.
+                        if let Some(x) = rhs.as_slice_memory_order() {
+                            if let Some(y) = self.as_slice_memory_order_mut() {
+                                unsafe {
+                                    blas_sys::$axpy(
+                                        y.len() as blas_index,
+                                        blas_scalar!($ty, alpha),
+                                        x.as_ptr() as *const _,
+                                        1,
+                                        y.as_mut_ptr() as *mut _,
+                                        1,
+                                    );
+                                }
+                                return;
+                            }
+                        }
+                    }
+                }
+            };
+        }
+        #[cfg(feature = "blas")]
+        axpy!(f32, cblas_saxpy);
+        #[cfg(feature = "blas")]
+        axpy!(f64, cblas_daxpy);
+        #[cfg(feature = "blas")]
+        axpy!(c32, cblas_caxpy);
+        #[cfg(feature = "blas")]
+        axpy!(c64, cblas_zaxpy);
+
         self.zip_mut_with(rhs, move |y, &x| *y = *y + (alpha * x));
     }
+
+    /// Perform the operation `self *= alpha` in place, where `alpha` is a
+    /// scalar. This operation is also known as `scal` in BLAS.
+    ///
+    /// *Note:* If enabled, uses blas `scal` for elements of `f32, f64,
+    /// Complex32, Complex64` when memory layout allows.
+    ///
+    /// ```
+    /// use ndarray::arr2;
+    ///
+    /// let mut a = arr2(&[[1., 2.],
+    ///                    [3., 4.]]);
+    /// a.scale(-2.);
+    /// assert_eq!(a, arr2(&[[-2., -4.],
+    ///                      [-6., -8.]]));
+    /// ```
+    pub fn scale(&mut self, alpha: A)
+    where
+        S: DataMut,
+        A: LinalgScalar,
+    {
+        #[cfg(feature = "blas")]
+        macro_rules! scal {
+            ($ty:tt, $scal:ident) => {
+                if same_type::<A, $ty>()
+                    && self.len() >= DOT_BLAS_CUTOFF
+                    && self.len() <= blas_index::max_value() as usize
+                {
+                    if self.ndim() == 1 {
+                        let stride = self.strides()[0];
+                        if blas_compat_stride(stride) {
+                            unsafe {
+                                let (ptr, n, inc) =
+                                    blas_1d_params(self.as_mut_ptr(), self.len(), stride);
+                                // The order of the elements doesn't matter, and
+                                // scal does nothing for a negative increment.
+                                blas_sys::$scal(
+                                    n,
+                                    blas_scalar!($ty, alpha),
+                                    ptr as *mut _,
+                                    inc.abs(),
+                                );
+                            }
+                            return;
+                        }
+                    } else if let Some(x) = self.as_slice_memory_order_mut() {
+                        unsafe {
+                            blas_sys::$scal(
+                                x.len() as blas_index,
+                                blas_scalar!($ty, alpha),
+                                x.as_mut_ptr() as *mut _,
+                                1,
+                            );
+                        }
+                        return;
+                    }
+                }
+            };
+        }
+        #[cfg(feature = "blas")]
+        scal!(f32, cblas_sscal);
+        #[cfg(feature = "blas")]
+        scal!(f64, cblas_dscal);
+        #[cfg(feature = "blas")]
+        scal!(c32, cblas_cscal);
+        #[cfg(feature = "blas")]
+        scal!(c64, cblas_zscal);
+
+        self.map_inplace(move |x| *x = *x * alpha);
+    }
 }
 
 // mat_mul_impl uses ArrayView arguments to send all array kinds into
@@ -372,86 +948,56 @@ fn mat_mul_impl<A>(
 {
     // size cutoff for using BLAS
     let cut = GEMM_BLAS_CUTOFF;
-    let ((mut m, a), (_, mut n)) = (lhs.dim(), rhs.dim());
-    if !(m > cut || n > cut || a > cut) || !(same_type::<A, f32>() || same_type::<A, f64>()) {
+    let ((m, k), (_, n)) = (lhs.dim(), rhs.dim());
+    if !(m > cut || n > cut || k > cut) || !blas_element_type::<A>() {
         return mat_mul_general(alpha, lhs, rhs, beta, c);
     }
-    {
-        // Use `c` for c-order and `f` for an f-order matrix
-        // We can handle c * c, f * f generally and
-        // c * f and f * c if the `f` matrix is square.
-        let mut lhs_ = lhs.view();
-        let mut rhs_ = rhs.view();
-        let mut c_ = c.view_mut();
-        let lhs_s0 = lhs_.strides()[0];
-        let rhs_s0 = rhs_.strides()[0];
-        let both_f = lhs_s0 == 1 && rhs_s0 == 1;
-        let mut lhs_trans = CblasNoTrans;
-        let mut rhs_trans = CblasNoTrans;
-        if both_f {
-            // A^t B^t = C^t => B A = C
-            let lhs_t = lhs_.reversed_axes();
-            lhs_ = rhs_.reversed_axes();
-            rhs_ = lhs_t;
-            c_ = c_.reversed_axes();
-            swap(&mut m, &mut n);
-        } else if lhs_s0 == 1 && m == a {
-            lhs_ = lhs_.reversed_axes();
-            lhs_trans = CblasTrans;
-        } else if rhs_s0 == 1 && a == n {
-            rhs_ = rhs_.reversed_axes();
-            rhs_trans = CblasTrans;
-        }
 
-        macro_rules! gemm {
-            ($ty:ty, $gemm:ident) => {
-                if blas_row_major_2d::<$ty, _>(&lhs_)
-                    && blas_row_major_2d::<$ty, _>(&rhs_)
-                    && blas_row_major_2d::<$ty, _>(&c_)
-                {
-                    let (m, k) = match lhs_trans {
-                        CblasNoTrans => lhs_.dim(),
-                        _ => {
-                            let (rows, cols) = lhs_.dim();
-                            (cols, rows)
-                        }
-                    };
-                    let n = match rhs_trans {
-                        CblasNoTrans => rhs_.raw_dim()[1],
-                        _ => rhs_.raw_dim()[0],
-                    };
-                    // adjust strides, these may [1, 1] for column matrices
-                    let lhs_stride = cmp::max(lhs_.strides()[0] as blas_index, k as blas_index);
-                    let rhs_stride = cmp::max(rhs_.strides()[0] as blas_index, n as blas_index);
-                    let c_stride = cmp::max(c_.strides()[0] as blas_index, n as blas_index);
-
-                    // gemm is C ← αA^Op B^Op + βC
-                    // Where Op is notrans/trans/conjtrans
-                    unsafe {
-                        blas_sys::$gemm(
-                            CblasRowMajor,
-                            lhs_trans,
-                            rhs_trans,
-                            m as blas_index,               // m, rows of Op(a)
-                            n as blas_index,               // n, cols of Op(b)
-                            k as blas_index,               // k, cols of Op(a)
-                            cast_as(&alpha),               // alpha
-                            lhs_.ptr.as_ptr() as *const _, // a
-                            lhs_stride,                    // lda
-                            rhs_.ptr.as_ptr() as *const _, // b
-                            rhs_stride,                    // ldb
-                            cast_as(&beta),                // beta
-                            c_.ptr.as_ptr() as *mut _,     // c
-                            c_stride,                      // ldc
-                        );
-                    }
-                    return;
+    // Each of `lhs`, `rhs` and `c` may be either row major (c-order) or
+    // column major (f-order). The cblas layout is picked to match `c`, and
+    // an operand stored in the other order is passed with a transpose flag
+    // instead of being copied.
+    macro_rules! gemm {
+        ($ty:tt, $gemm:ident) => {
+            if let (Some(lhs_layout), Some(rhs_layout), Some(c_layout)) = (
+                blas_layout::<$ty, _>(lhs),
+                blas_layout::<$ty, _>(rhs),
+                blas_layout::<$ty, _>(c),
+            ) {
+                let lhs_trans = blas_transpose(lhs_layout, c_layout);
+                let rhs_trans = blas_transpose(rhs_layout, c_layout);
+                let lhs_stride = blas_stride(lhs, lhs_layout);
+                let rhs_stride = blas_stride(rhs, rhs_layout);
+                let c_stride = blas_stride(c, c_layout);
+
+                // gemm is C ← αA^Op B^Op + βC
+                // Where Op is notrans/trans/conjtrans
+                unsafe {
+                    blas_sys::$gemm(
+                        c_layout,
+                        lhs_trans,
+                        rhs_trans,
+                        m as blas_index,              // m, rows of Op(a)
+                        n as blas_index,              // n, cols of Op(b)
+                        k as blas_index,              // k, cols of Op(a)
+                        blas_scalar!($ty, alpha),     // alpha
+                        lhs.ptr.as_ptr() as *const _, // a
+                        lhs_stride,                   // lda
+                        rhs.ptr.as_ptr() as *const _, // b
+                        rhs_stride,                   // ldb
+                        blas_scalar!($ty, beta),      // beta
+                        c.ptr.as_ptr() as *mut _,     // c
+                        c_stride,                     // ldc
+                    );
                 }
-            };
-        }
-        gemm!(f32, cblas_sgemm);
-        gemm!(f64, cblas_dgemm);
+                return;
+            }
+        };
     }
+    gemm!(f32, cblas_sgemm);
+    gemm!(f64, cblas_dgemm);
+    gemm!(c32, cblas_cgemm);
+    gemm!(c64, cblas_zgemm);
     mat_mul_general(alpha, lhs, rhs, beta, c)
 }
 
@@ -552,9 +1098,9 @@ fn mat_mul_general<A>(
 /// if `a` is *M* × *N*, then `b` is *N* × *K* and `c` is *M* × *K*.
 ///
 /// ***Panics*** if array shapes are not compatible<br>
-/// *Note:* If enabled, uses blas `gemm` for elements of `f32, f64` when memory
-/// layout allows.  The default matrixmultiply backend is otherwise used for
-/// `f32, f64` for all memory layouts.
+/// *Note:* If enabled, uses blas `gemm` for elements of `f32, f64, Complex32,
+/// Complex64` when memory layout allows.  The default matrixmultiply backend is
+/// otherwise used for `f32, f64` for all memory layouts.
 pub fn general_mat_mul<A, S1, S2, S3>(
     alpha: A,
     a: &ArrayBase<S1, Ix2>,
@@ -584,8 +1130,8 @@ pub fn general_mat_mul<A, S1, S2, S3>(
 /// y an *M*-element column vector (one dimensional arrays).
 ///
 /// ***Panics*** if array shapes are not compatible<br>
-/// *Note:* If enabled, uses blas `gemv` for elements of `f32, f64` when memory
-/// layout allows.
+/// *Note:* If enabled, uses blas `gemv` for elements of `f32, f64, Complex32,
+/// Complex64` when memory layout allows.
 #[allow(clippy::collapsible_if)]
 pub fn general_mat_vec_mul<A, S1, S2, S3>(
     alpha: A,
@@ -604,41 +1150,46 @@ pub fn general_mat_vec_mul<A, S1, S2, S3>(
     if k != k2 || m != m2 {
         general_dot_shape_error(m, k, k2, 1, m2, 1);
     } else {
+        // A matrix with reversed rows (columns) is the unreversed matrix with
+        // the output (input) vector reversed, which blas accepts.
+        #[cfg(feature = "blas")]
+        let (a_blas, x_blas, mut y_blas) = {
+            let (mut a, mut x, mut y) = (a.view(), x.view(), y.view_mut());
+            if a.strides()[0] < 0 {
+                a.invert_axis(Axis(0));
+                y.invert_axis(Axis(0));
+            }
+            if a.strides()[1] < 0 {
+                a.invert_axis(Axis(1));
+                x.invert_axis(Axis(0));
+            }
+            (a, x, y)
+        };
         #[cfg(feature = "blas")]
         macro_rules! gemv {
-            ($ty:ty, $gemv:ident) => {
-                if let Some(layout) = blas_layout::<$ty, _>(&a) {
-                    if blas_compat_1d::<$ty, _>(&x) && blas_compat_1d::<$ty, _>(&y) {
-                        // Determine stride between rows or columns. Note that the stride is
-                        // adjusted to at least `k` or `m` to handle the case of a matrix with a
-                        // trivial (length 1) dimension, since the stride for the trivial dimension
-                        // may be arbitrary.
+            ($ty:tt, $gemv:ident) => {
+                if let Some(layout) = blas_layout::<$ty, _>(&a_blas) {
+                    if blas_compat_1d::<$ty, _>(&x_blas) && blas_compat_1d::<$ty, _>(&y_blas) {
                         let a_trans = CblasNoTrans;
-                        let a_stride = match layout {
-                            CBLAS_LAYOUT::CblasRowMajor => {
-                                a.strides()[0].max(k as isize) as blas_index
-                            }
-                            CBLAS_LAYOUT::CblasColMajor => {
-                                a.strides()[1].max(m as isize) as blas_index
-                            }
-                        };
-
-                        let x_stride = x.strides()[0] as blas_index;
-                        let y_stride = y.strides()[0] as blas_index;
+                        let a_stride = blas_stride(&a_blas, layout);
 
                         unsafe {
+                            let (x_ptr, _, x_stride) =
+                                blas_1d_params(x_blas.as_ptr(), k, x_blas.strides()[0]);
+                            let (y_ptr, _, y_stride) =
+                                blas_1d_params(y_blas.as_mut_ptr(), m, y_blas.strides()[0]);
                             blas_sys::$gemv(
                                 layout,
                                 a_trans,
-                                m as blas_index,            // m, rows of Op(a)
-                                k as blas_index,            // n, cols of Op(a)
-                                cast_as(&alpha),            // alpha
-                                a.ptr.as_ptr() as *const _, // a
-                                a_stride,                   // lda
-                                x.ptr.as_ptr() as *const _, // x
+                                m as blas_index,             // m, rows of Op(a)
+                                k as blas_index,             // n, cols of Op(a)
+                                blas_scalar!($ty, alpha),    // alpha
+                                a_blas.as_ptr() as *const _, // a
+                                a_stride,                    // lda
+                                x_ptr as *const _,           // x
                                 x_stride,
-                                cast_as(&beta),           // beta
-                                y.ptr.as_ptr() as *mut _, // x
+                                blas_scalar!($ty, beta), // beta
+                                y_ptr as *mut _,         // y
                                 y_stride,
                             );
                         }
@@ -651,6 +1202,10 @@ pub fn general_mat_vec_mul<A, S1, S2, S3>(
         gemv!(f32, cblas_sgemv);
         #[cfg(feature = "blas")]
         gemv!(f64, cblas_dgemv);
+        #[cfg(feature = "blas")]
+        gemv!(c32, cblas_cgemv);
+        #[cfg(feature = "blas")]
+        gemv!(c64, cblas_zgemv);
 
         /* general */
 
@@ -666,22 +1221,58 @@ pub fn general_mat_vec_mul<A, S1, S2, S3>(
     }
 }
 
+/// Outer product of two vectors.
+///
+/// Return the *M* × *N* matrix x yᵀ, where x is an *M*-element vector and y
+/// is an *N*-element vector.
+///
+/// ***Panics*** if the number of elements in the result would overflow
+/// `isize`.<br>
+/// *Note:* If enabled, uses blas `ger` (`geru` for complex elements) for
+/// elements of `f32, f64, Complex32, Complex64` when memory layout allows.
+///
+/// ```
+/// use ndarray::linalg::outer;
+/// use ndarray::{arr1, arr2};
+///
+/// let c = outer(&arr1(&[1., 2.]), &arr1(&[3., 4., 5.]));
+/// assert_eq!(c, arr2(&[[3., 4., 5.],
+///                      [6., 8., 10.]]));
+/// ```
+pub fn outer<A, S1, S2>(x: &ArrayBase<S1, Ix1>, y: &ArrayBase<S2, Ix1>) -> Array2<A>
+where
+    S1: Data<Elem = A>,
+    S2: Data<Elem = A>,
+    A: LinalgScalar,
+{
+    let mut c = Array::zeros((x.len(), y.len()));
+    c.general_rank1_update(A::one(), x, y);
+    c
+}
+
 #[inline(always)]
 /// Return `true` if `A` and `B` are the same type
-fn same_type<A: 'static, B: 'static>() -> bool {
+pub(super) fn same_type<A: 'static, B: 'static>() -> bool {
     TypeId::of::<A>() == TypeId::of::<B>()
 }
 
 // Read pointer to type `A` as type `B`.
 //
 // **Panics** if `A` and `B` are not the same type
-fn cast_as<A: 'static + Copy, B: 'static + Copy>(a: &A) -> B {
+pub(super) fn cast_as<A: 'static + Copy, B: 'static + Copy>(a: &A) -> B {
     assert!(same_type::<A, B>());
     unsafe { ::std::ptr::read(a as *const _ as *const B) }
 }
 
+/// Return `true` if `A` is one of the element types supported by BLAS:
+/// `f32`, `f64`, `Complex32` or `Complex64`.
+#[cfg(feature = "blas")]
+pub(super) fn blas_element_type<A: 'static>() -> bool {
+    same_type::<A, f32>() || same_type::<A, f64>() || same_type::<A, c32>() || same_type::<A, c64>()
+}
+
 #[cfg(feature = "blas")]
-fn blas_compat_1d<A, S>(a: &ArrayBase<S, Ix1>) -> bool
+pub(super) fn blas_compat_1d<A, S>(a: &ArrayBase<S, Ix1>) -> bool
 where
     S: Data,
     A: 'static,
@@ -693,11 +1284,13 @@ where
     if a.len() > blas_index::max_value() as usize {
         return false;
     }
-    let stride = a.strides()[0];
-    if stride > blas_index::max_value() as isize || stride < blas_index::min_value() as isize {
-        return false;
-    }
-    true
+    blas_compat_stride(a.strides()[0])
+}
+
+/// Return `true` if `stride` can be passed to BLAS as a vector increment.
+#[cfg(feature = "blas")]
+fn blas_compat_stride(stride: isize) -> bool {
+    stride <= blas_index::max_value() as isize && stride >= blas_index::min_value() as isize
 }
 
 #[cfg(feature = "blas")]
@@ -759,7 +1352,7 @@ fn is_blas_2d(dim: &Ix2, stride: &Ix2, order: MemoryOrder) -> bool {
 }
 
 #[cfg(feature = "blas")]
-fn blas_layout<A, S>(a: &ArrayBase<S, Ix2>) -> Option<CBLAS_LAYOUT>
+pub(super) fn blas_layout<A, S>(a: &ArrayBase<S, Ix2>) -> Option<CBLAS_LAYOUT>
 where
     S: Data,
     A: 'static,
@@ -774,6 +1367,36 @@ where
     }
 }
 
+/// Return the transpose flag that makes a matrix stored in `layout` read
+/// correctly in a call using the cblas layout `target`.
+#[cfg(feature = "blas")]
+pub(super) fn blas_transpose(layout: CBLAS_LAYOUT, target: CBLAS_LAYOUT) -> CBLAS_TRANSPOSE {
+    match (layout, target) {
+        (CBLAS_LAYOUT::CblasRowMajor, CBLAS_LAYOUT::CblasRowMajor)
+        | (CBLAS_LAYOUT::CblasColMajor, CBLAS_LAYOUT::CblasColMajor) => CblasNoTrans,
+        _ => CblasTrans,
+    }
+}
+
+/// Return the leading dimension of `a` when it is stored in `layout`.
+///
+/// This is the stride between rows (row major) or columns (column major).
+/// It is adjusted to at least the length of the other axis to handle the case
+/// of a matrix with a trivial (length 1) dimension, since the stride for the
+/// trivial dimension may be arbitrary.
+#[cfg(feature = "blas")]
+pub(super) fn blas_stride<S>(a: &ArrayBase<S, Ix2>, layout: CBLAS_LAYOUT) -> blas_index
+where
+    S: RawData,
+{
+    let (m, n) = a.dim();
+    let stride = match layout {
+        CBLAS_LAYOUT::CblasRowMajor => cmp::max(a.strides()[0], n as isize),
+        CBLAS_LAYOUT::CblasColMajor => cmp::max(a.strides()[1], m as isize),
+    };
+    cmp::max(stride, 1) as blas_index
+}
+
 #[cfg(test)]
 #[cfg(feature = "blas")]
 mod blas_tests {
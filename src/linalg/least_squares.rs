@@ -0,0 +1,153 @@
+// Copyright 2016 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::cmp;
+
+use crate::imp_prelude::*;
+use crate::NdFloat;
+
+use super::{LinalgError, LinalgErrorKind};
+
+/// The solution of a linear least squares problem.
+///
+/// Created by [`.least_squares()`](../struct.ArrayBase.html#method.least_squares).
+#[derive(Clone, Debug)]
+pub struct LeastSquares<A, D>
+where
+    D: Dimension,
+{
+    solution: Array<A, D>,
+    residual_sum_of_squares: Option<Array<A, D::Smaller>>,
+    rank: usize,
+    singular_values: Array1<A>,
+}
+
+impl<A, D> LeastSquares<A, D>
+where
+    D: Dimension,
+{
+    /// Return the solution x, of minimum norm among all least squares
+    /// solutions.
+    pub fn solution(&self) -> &Array<A, D> {
+        &self.solution
+    }
+
+    /// Return the solution x, of minimum norm among all least squares
+    /// solutions, consuming `self`.
+    pub fn into_solution(self) -> Array<A, D> {
+        self.solution
+    }
+
+    /// Return the sum of squares of the residual A x - b, one for each
+    /// column of b.
+    ///
+    /// This is `None` unless A has more rows than columns and full column
+    /// rank, since otherwise A x = b can be solved exactly.
+    pub fn residual_sum_of_squares(&self) -> Option<&Array<A, D::Smaller>> {
+        self.residual_sum_of_squares.as_ref()
+    }
+
+    /// Return the effective rank of A: the number of singular values that
+    /// were not treated as zero.
+    pub fn rank(&self) -> usize {
+        self.rank
+    }
+
+    /// Return the singular values of A, in decreasing order.
+    pub fn singular_values(&self) -> &Array1<A> {
+        &self.singular_values
+    }
+}
+
+impl<A, S> ArrayBase<S, Ix2>
+where
+    S: Data<Elem = A>,
+    A: NdFloat,
+{
+    /// Solve the linear least squares problem of minimizing ‖A x − b‖, where
+    /// A is `self` and `b` is a vector or a matrix of column vectors.
+    ///
+    /// The system may be over- or underdetermined, and A may be rank
+    /// deficient: singular values smaller than the largest one times the
+    /// machine epsilon times the larger dimension of A are treated as zero,
+    /// and the solution of minimum norm is returned.
+    ///
+    /// The problem is solved with the singular value decomposition of A.
+    ///
+    /// **Errors** if `b` does not have as many rows as `self`, or if the
+    /// singular value decomposition does not converge.
+    ///
+    /// ```
+    /// use ndarray::{arr1, arr2};
+    ///
+    /// // Fit a line y = c0 + c1 t through three points
+    /// let a = arr2(&[[1_f64, 0.],
+    ///                [1., 1.],
+    ///                [1., 2.]]);
+    /// let y = arr1(&[1., 2., 4.]);
+    /// let result = a.least_squares(&y).unwrap();
+    /// assert_eq!(result.rank(), 2);
+    /// let c = result.solution();
+    /// assert!((c - &arr1(&[5. / 6., 1.5])).norm_l2() < 1e-12);
+    /// let rss = result.residual_sum_of_squares().unwrap();
+    /// assert!((rss[()] - 1. / 6.).abs() < 1e-12);
+    /// ```
+    pub fn least_squares<S2, D>(
+        &self,
+        b: &ArrayBase<S2, D>,
+    ) -> Result<LeastSquares<A, D>, LinalgError>
+    where
+        S2: Data<Elem = A>,
+        D: RemoveAxis,
+    {
+        let (m, n) = self.dim();
+        let b_cols = match b.ndim() {
+            1 => b
+                .view()
+                .into_dimensionality::<Ix1>()
+                .ok()
+                .map(|b| b.insert_axis(Axis(1))),
+            2 => b.view().into_dimensionality::<Ix2>().ok(),
+            _ => None,
+        }
+        .filter(|b| b.nrows() == m)
+        .ok_or_else(|| LinalgError::from_kind(LinalgErrorKind::IncompatibleShape))?;
+
+        let (u, s, vt) = self.svd(true, true)?;
+        let (u, vt) = (u.unwrap(), vt.unwrap());
+        let cutoff = s.get(0).map_or(A::zero(), |&s0| {
+            s0 * A::epsilon() * A::from(cmp::max(m, n)).unwrap()
+        });
+        let rank = s.iter().take_while(|&&s| s > cutoff).count();
+
+        // x = V Σ⁺ Uᵀ b
+        let mut c = u.slice(s![.., ..rank]).t().dot(&b_cols);
+        for (mut row, &s) in c.outer_iter_mut().zip(&s) {
+            row.scale(s.recip());
+        }
+        let x = vt.slice(s![..rank, ..]).t().dot(&c);
+
+        let residual_sum_of_squares = if m > n && rank == n {
+            let r = self.dot(&x) - b_cols;
+            let sums = r.gencolumns().into_iter().map(|r| r.dot(&r)).collect();
+            let shape = b.raw_dim().remove_axis(Axis(0));
+            Some(Array::from_shape_vec(shape, sums).unwrap())
+        } else {
+            None
+        };
+        let mut shape = b.raw_dim();
+        shape[0] = n;
+        let solution = Array::from_shape_vec(shape, x.iter().cloned().collect()).unwrap();
+        Ok(LeastSquares {
+            solution,
+            residual_sum_of_squares,
+            rank,
+            singular_values: s,
+        })
+    }
+}
@@ -0,0 +1,297 @@
+// Copyright 2016 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::imp_prelude::*;
+use crate::{NdFloat, Zip};
+
+use super::triangular::solve_triangular_mat_impl;
+use super::{LinalgError, LinalgErrorKind, Triangle};
+
+/// The LU decomposition of a square matrix, with partial pivoting.
+///
+/// The decomposition is P A = L U, where P is a permutation matrix, L is
+/// lower triangular with a unit diagonal and U is upper triangular.
+///
+/// Created by [`.factorize_lu()`](../struct.ArrayBase.html#method.factorize_lu).
+///
+/// ```
+/// use ndarray::{arr1, arr2};
+///
+/// let a = arr2(&[[4., 3.],
+///                [6., 3.]]);
+/// let lu = a.factorize_lu().unwrap();
+/// let x = lu.solve(&arr1(&[10., 12.])).unwrap();
+/// assert!((x - arr1(&[1., 2.])).norm_l2() < 1e-12);
+/// ```
+#[derive(Clone, Debug)]
+pub struct LU<A> {
+    // L below the diagonal and U on and above it
+    lu: Array2<A>,
+    // row `k` was swapped with row `pivots[k]` in step `k`
+    pivots: Vec<usize>,
+}
+
+impl<A, S> ArrayBase<S, Ix2>
+where
+    S: Data<Elem = A>,
+    A: NdFloat,
+{
+    /// Compute the LU decomposition of the square matrix `self`, using
+    /// partial pivoting.
+    ///
+    /// The decomposition succeeds for singular matrices too; solving with it
+    /// then returns an error.
+    ///
+    /// **Errors** if `self` is not square.
+    pub fn factorize_lu(&self) -> Result<LU<A>, LinalgError> {
+        let (m, n) = self.dim();
+        if m != n {
+            return Err(LinalgError::from_kind(LinalgErrorKind::NotSquare));
+        }
+        let mut lu = self.to_owned();
+        let mut pivots = Vec::with_capacity(n);
+        for k in 0..n {
+            let p = k + lu.slice(s![k.., k]).argmax_abs().unwrap();
+            pivots.push(p);
+            if p != k {
+                let (row_k, row_p) = lu.multi_slice_mut((s![k, ..], s![p, ..]));
+                Zip::from(row_k).and(row_p).apply(std::mem::swap);
+            }
+            let pivot = lu[[k, k]];
+            if pivot.is_zero() {
+                continue;
+            }
+            let (mut l_col, u_row, mut rest) =
+                lu.multi_slice_mut((s![k + 1.., k], s![k, k + 1..], s![k + 1.., k + 1..]));
+            l_col.scale(pivot.recip());
+            rest.general_rank1_update(-A::one(), &l_col, &u_row);
+        }
+        Ok(LU { lu, pivots })
+    }
+
+    /// Solve the linear system A x = b, where A is `self` and `b` is a
+    /// vector or a matrix of column vectors.
+    ///
+    /// This computes the LU decomposition of `self` first; use
+    /// [`.factorize_lu()`](#method.factorize_lu) to solve several systems
+    /// with the same matrix.
+    ///
+    /// **Errors** if `self` is not square or is singular, or if `b` does not
+    /// have as many rows as `self`.
+    pub fn solve<S2, D>(&self, b: &ArrayBase<S2, D>) -> Result<Array<A, D>, LinalgError>
+    where
+        S2: Data<Elem = A>,
+        D: Dimension,
+    {
+        self.factorize_lu()?.solve(b)
+    }
+
+    /// Solve the linear system A x = b in place, where A is `self` and `b`
+    /// is a vector or a matrix of column vectors, and return `b` holding x.
+    ///
+    /// **Errors** if `self` is not square or is singular, or if `b` does not
+    /// have as many rows as `self`.
+    pub fn solve_into<S2, D>(&self, b: ArrayBase<S2, D>) -> Result<ArrayBase<S2, D>, LinalgError>
+    where
+        S2: DataMut<Elem = A>,
+        D: Dimension,
+    {
+        self.factorize_lu()?.solve_into(b)
+    }
+
+    /// Compute the inverse of the square matrix `self`.
+    ///
+    /// **Errors** if `self` is not square or is singular.
+    ///
+    /// ```
+    /// use ndarray::arr2;
+    ///
+    /// let a = arr2(&[[2., 1.],
+    ///                [4., 3.]]);
+    /// assert_eq!(a.inv().unwrap(), arr2(&[[1.5, -0.5],
+    ///                                     [-2., 1.]]));
+    /// ```
+    pub fn inv(&self) -> Result<Array2<A>, LinalgError> {
+        self.factorize_lu()?.inv()
+    }
+
+    /// Compute the determinant of the square matrix `self`.
+    ///
+    /// The determinant of a singular matrix is zero. For large matrices, it
+    /// easily overflows or underflows; see [`.sln_det()`](#method.sln_det).
+    ///
+    /// **Errors** if `self` is not square.
+    ///
+    /// ```
+    /// use ndarray::arr2;
+    ///
+    /// let a = arr2(&[[2., 1.],
+    ///                [4., 3.]]);
+    /// assert_eq!(a.det().unwrap(), 2.);
+    /// ```
+    pub fn det(&self) -> Result<A, LinalgError> {
+        self.factorize_lu().map(|lu| lu.det())
+    }
+
+    /// Compute the sign and the natural logarithm of the absolute value of
+    /// the determinant of the square matrix `self`.
+    ///
+    /// The determinant is the sign times the exponential of the logarithm,
+    /// but the logarithm does not overflow. For a singular matrix, the sign
+    /// is zero and the logarithm is negative infinity.
+    ///
+    /// **Errors** if `self` is not square.
+    pub fn sln_det(&self) -> Result<(A, A), LinalgError> {
+        self.factorize_lu().map(|lu| lu.sln_det())
+    }
+}
+
+impl<A> LU<A>
+where
+    A: NdFloat,
+{
+    /// Return the unit lower triangular factor L.
+    pub fn l(&self) -> Array2<A> {
+        let n = self.lu.nrows();
+        Array::from_shape_fn((n, n), |(i, j)| {
+            if i > j {
+                self.lu[[i, j]]
+            } else if i == j {
+                A::one()
+            } else {
+                A::zero()
+            }
+        })
+    }
+
+    /// Return the upper triangular factor U.
+    pub fn u(&self) -> Array2<A> {
+        let n = self.lu.nrows();
+        Array::from_shape_fn(
+            (n, n),
+            |(i, j)| {
+                if i <= j {
+                    self.lu[[i, j]]
+                } else {
+                    A::zero()
+                }
+            },
+        )
+    }
+
+    /// Return the row interchanges of the permutation P: in step `k`, row
+    /// `k` was swapped with row `pivots[k]`.
+    ///
+    /// This is the pivot vector of LAPACK `getrf`, counted from zero.
+    pub fn pivots(&self) -> &[usize] {
+        &self.pivots
+    }
+
+    /// Solve the linear system A x = b, where `b` is a vector or a matrix of
+    /// column vectors.
+    ///
+    /// **Errors** if A is singular, or if `b` does not have as many rows as
+    /// A.
+    pub fn solve<S, D>(&self, b: &ArrayBase<S, D>) -> Result<Array<A, D>, LinalgError>
+    where
+        S: Data<Elem = A>,
+        D: Dimension,
+    {
+        self.solve_into(b.to_owned())
+    }
+
+    /// Solve the linear system A x = b in place, where `b` is a vector or a
+    /// matrix of column vectors, and return `b` holding x.
+    ///
+    /// **Errors** if A is singular, or if `b` does not have as many rows as
+    /// A.
+    pub fn solve_into<S, D>(&self, mut b: ArrayBase<S, D>) -> Result<ArrayBase<S, D>, LinalgError>
+    where
+        S: DataMut<Elem = A>,
+        D: Dimension,
+    {
+        self.solve_mat_inplace(&mut columns_mut(&mut b, self.lu.nrows())?)?;
+        Ok(b)
+    }
+
+    /// Compute the inverse of A.
+    ///
+    /// **Errors** if A is singular.
+    pub fn inv(&self) -> Result<Array2<A>, LinalgError> {
+        self.solve_into(Array::eye(self.lu.nrows()))
+    }
+
+    /// Compute the determinant of A.
+    pub fn det(&self) -> A {
+        self.lu
+            .diag()
+            .iter()
+            .fold(self.permutation_sign(), |acc, &x| acc * x)
+    }
+
+    /// Compute the sign and the natural logarithm of the absolute value of
+    /// the determinant of A.
+    pub fn sln_det(&self) -> (A, A) {
+        let mut sign = self.permutation_sign();
+        let mut ln = A::zero();
+        for &x in self.lu.diag() {
+            sign *= x.signum();
+            ln += x.abs().ln();
+        }
+        if ln == A::neg_infinity() {
+            sign = A::zero();
+        }
+        (sign, ln)
+    }
+
+    /// Return the determinant of P, which is -1 for an odd number of row
+    /// interchanges and 1 otherwise.
+    fn permutation_sign(&self) -> A {
+        let swaps = self.pivots.iter().enumerate().filter(|&(k, &p)| p != k);
+        swaps.fold(A::one(), |sign, _| -sign)
+    }
+
+    fn solve_mat_inplace(&self, b: &mut ArrayViewMut2<'_, A>) -> Result<(), LinalgError> {
+        if self.lu.diag().iter().any(|x| x.is_zero()) {
+            return Err(LinalgError::from_kind(LinalgErrorKind::Singular));
+        }
+        for (k, &p) in self.pivots.iter().enumerate() {
+            if p != k {
+                let (row_k, row_p) = b.multi_slice_mut((s![k, ..], s![p, ..]));
+                Zip::from(row_k).and(row_p).apply(std::mem::swap);
+            }
+        }
+        let lu = self.lu.view();
+        solve_triangular_mat_impl(Triangle::Lower, true, &lu, b);
+        solve_triangular_mat_impl(Triangle::Upper, false, &lu, b);
+        Ok(())
+    }
+}
+
+/// View the right hand side `b` of a linear system with `n` equations, a
+/// vector or a matrix of column vectors, as a matrix.
+pub(super) fn columns_mut<A, S, D>(
+    b: &mut ArrayBase<S, D>,
+    n: usize,
+) -> Result<ArrayViewMut2<'_, A>, LinalgError>
+where
+    S: DataMut<Elem = A>,
+    D: Dimension,
+{
+    let b = match b.ndim() {
+        1 => b
+            .view_mut()
+            .into_dimensionality::<Ix1>()
+            .ok()
+            .map(|b| b.insert_axis(Axis(1))),
+        2 => b.view_mut().into_dimensionality::<Ix2>().ok(),
+        _ => None,
+    };
+    b.filter(|b| b.nrows() == n)
+        .ok_or_else(|| LinalgError::from_kind(LinalgErrorKind::IncompatibleShape))
+}
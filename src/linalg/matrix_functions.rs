@@ -0,0 +1,292 @@
+// Copyright 2016 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use num_complex::Complex;
+
+use crate::imp_prelude::*;
+use crate::NdFloat;
+
+use super::eig::schur;
+use super::triangular::solve_triangular_mat_impl;
+use super::{LinalgError, LinalgErrorKind, Triangle};
+
+/// The coefficients of the numerator of the [13/13] Padé approximant of the
+/// exponential; the denominator has the same coefficients with alternating
+/// signs.
+const PADE_13: [f64; 14] = [
+    64_764_752_532_480_000.,
+    32_382_376_266_240_000.,
+    7_771_770_303_897_600.,
+    1_187_353_796_428_800.,
+    129_060_195_264_000.,
+    10_559_470_521_600.,
+    670_442_572_800.,
+    33_522_128_640.,
+    1_323_241_920.,
+    40_840_800.,
+    960_960.,
+    16_380.,
+    182.,
+    1.,
+];
+
+/// The largest 1-norm for which the [13/13] Padé approximant of the
+/// exponential is accurate to double precision.
+const THETA_13: f64 = 5.371_920_351_148_152;
+
+/// Gauss–Legendre nodes and weights on [0, 1], used to approximate
+/// log(I + X) = ∫₀¹ X (I + t X)⁻¹ dt.
+const GAUSS_LEGENDRE_8: [(f64, f64); 8] = [
+    (0.019_855_071_751_231_856, 0.050_614_268_145_188_13),
+    (0.101_666_761_293_186_63, 0.111_190_517_226_687_24),
+    (0.237_233_795_041_835_5, 0.156_853_322_938_943_64),
+    (0.408_282_678_752_175_1, 0.181_341_891_689_181),
+    (0.591_717_321_247_825, 0.181_341_891_689_181),
+    (0.762_766_204_958_164_5, 0.156_853_322_938_943_64),
+    (0.898_333_238_706_813_4, 0.111_190_517_226_687_24),
+    (0.980_144_928_248_768_1, 0.050_614_268_145_188_13),
+];
+
+/// The number of square roots taken by `.logm()` after which it gives up.
+const MAX_SQRTS: usize = 64;
+
+impl<A, S> ArrayBase<S, Ix2>
+where
+    S: Data<Elem = A>,
+    A: NdFloat,
+{
+    /// Compute the matrix exponential of the square matrix `self`.
+    ///
+    /// The exponential is computed by scaling and squaring with a [13/13]
+    /// Padé approximant.
+    ///
+    /// **Errors** if `self` is not square.
+    ///
+    /// ```
+    /// use ndarray::arr2;
+    ///
+    /// let a = arr2(&[[0., 1.],
+    ///                [0., 0.]]);
+    /// let e = a.expm().unwrap();
+    /// assert!((e - arr2(&[[1., 1.],
+    ///                     [0., 1.]])).iter().all(|x: &f64| x.abs() < 1e-12));
+    /// ```
+    pub fn expm(&self) -> Result<Array2<A>, LinalgError> {
+        let (m, n) = self.dim();
+        if m != n {
+            return Err(LinalgError::from_kind(LinalgErrorKind::NotSquare));
+        }
+        let theta = A::from(THETA_13).unwrap();
+        let norm = self.opnorm_one();
+        let squarings = if norm > theta {
+            (norm / theta).log2().ceil().to_i32().unwrap_or(0)
+        } else {
+            0
+        };
+        let two = A::one() + A::one();
+        let a = self.mapv(|x| x * two.powi(-squarings));
+
+        let b = |k: usize| A::from(PADE_13[k]).unwrap();
+        let ident = Array2::<A>::eye(n);
+        let a2 = a.dot(&a);
+        let a4 = a2.dot(&a2);
+        let a6 = a2.dot(&a4);
+        let u = &a6 * b(13) + &a4 * b(11) + &a2 * b(9);
+        let u = a6.dot(&u) + &a6 * b(7) + &a4 * b(5) + &a2 * b(3) + &ident * b(1);
+        let u = a.dot(&u);
+        let v = &a6 * b(12) + &a4 * b(10) + &a2 * b(8);
+        let v = a6.dot(&v) + &a6 * b(6) + &a4 * b(4) + &a2 * b(2) + &ident * b(0);
+
+        // The denominator V - U is well conditioned for norms up to θ₁₃.
+        let mut e = (&v - &u).solve_into(v + u)?;
+        for _ in 0..squarings {
+            e = e.dot(&e);
+        }
+        Ok(e)
+    }
+
+    /// Raise the square matrix `self` to the integer power `n`.
+    ///
+    /// The power is computed by repeated squaring, with about 2 log₂ |`n`|
+    /// matrix multiplications. A negative power is a power of the inverse;
+    /// the zeroth power is the identity.
+    ///
+    /// **Errors** if `self` is not square, or if `n` is negative and `self`
+    /// is singular.
+    ///
+    /// ```
+    /// use ndarray::arr2;
+    ///
+    /// let a = arr2(&[[1., 1.],
+    ///                [1., 0.]]);
+    /// assert_eq!(a.matrix_power(10).unwrap(), arr2(&[[89., 55.],
+    ///                                                [55., 34.]]));
+    /// assert_eq!(arr2(&[[2., 0.],
+    ///                   [0., 4.]]).matrix_power(-2).unwrap(), arr2(&[[0.25, 0.],
+    ///                                                               [0., 0.0625]]));
+    /// ```
+    pub fn matrix_power(&self, n: i32) -> Result<Array2<A>, LinalgError> {
+        let (rows, cols) = self.dim();
+        if rows != cols {
+            return Err(LinalgError::from_kind(LinalgErrorKind::NotSquare));
+        }
+        let mut base = if n < 0 { self.inv()? } else { self.to_owned() };
+        // `wrapping_abs` maps `i32::MIN` to itself, which is 2³¹ as `u32`.
+        let mut k = n.wrapping_abs() as u32;
+        let mut power = Array2::eye(rows);
+        let mut first = true;
+        while k > 0 {
+            if k & 1 == 1 {
+                power = if first {
+                    base.clone()
+                } else {
+                    power.dot(&base)
+                };
+                first = false;
+            }
+            k >>= 1;
+            if k > 0 {
+                base = base.dot(&base);
+            }
+        }
+        Ok(power)
+    }
+
+    /// Compute the principal square root of the square matrix `self`: the
+    /// matrix X with X X = `self` whose eigenvalues have positive real
+    /// parts.
+    ///
+    /// The result is complex, since the square root of a real matrix need not
+    /// be real. It is real, up to rounding errors, if `self` has no
+    /// eigenvalues on the closed negative real axis.
+    ///
+    /// The square root is computed from the complex Schur form of `self`.
+    ///
+    /// **Errors** if `self` is not square, if the Schur form does not
+    /// converge, or if `self` is singular and has no square root.
+    ///
+    /// ```
+    /// use ndarray::arr2;
+    ///
+    /// let a = arr2(&[[33., 24.],
+    ///                [48., 57.]]);
+    /// let x = a.sqrtm().unwrap();
+    /// let expected = arr2(&[[5., 2.],
+    ///                       [4., 7.]]);
+    /// assert!((x.mapv(|x| x.re) - expected).iter().all(|x: &f64| x.abs() < 1e-12));
+    /// assert!(x.iter().all(|x| x.im.abs() < 1e-12));
+    /// ```
+    pub fn sqrtm(&self) -> Result<Array2<Complex<A>>, LinalgError> {
+        let (t, q) = schur(self.view(), true)?;
+        let r = sqrt_triangular(&t)?;
+        Ok(unitary_similar(&q.unwrap(), &r))
+    }
+
+    /// Compute the principal logarithm of the square matrix `self`: the
+    /// matrix X with exp(X) = `self` whose eigenvalues have imaginary parts
+    /// in (-π, π].
+    ///
+    /// The result is complex, since the logarithm of a real matrix need not
+    /// be real. It is real, up to rounding errors, if `self` has no
+    /// eigenvalues on the closed negative real axis.
+    ///
+    /// The logarithm is computed from the complex Schur form of `self` by
+    /// inverse scaling and squaring: square roots are taken until the
+    /// triangular factor is close to the identity, where the logarithm is
+    /// approximated by Gauss–Legendre quadrature.
+    ///
+    /// **Errors** if `self` is not square, if the Schur form does not
+    /// converge, or if `self` is singular.
+    ///
+    /// ```
+    /// use ndarray::arr2;
+    ///
+    /// // the generator of a rotation by half a radian
+    /// let a = arr2(&[[0., -0.5],
+    ///                [0.5, 0.]]);
+    /// let x = a.expm().unwrap().logm().unwrap();
+    /// assert!((x.mapv(|x| x.re) - a).iter().all(|x: &f64| x.abs() < 1e-12));
+    /// assert!(x.iter().all(|x| x.im.abs() < 1e-12));
+    /// ```
+    pub fn logm(&self) -> Result<Array2<Complex<A>>, LinalgError> {
+        let (mut t, q) = schur(self.view(), true)?;
+        let n = t.nrows();
+        if t.diag().iter().any(|x| x.norm().is_zero()) {
+            return Err(LinalgError::from_kind(LinalgErrorKind::Singular));
+        }
+        let ident = Array2::<Complex<A>>::eye(n);
+        let quarter = A::from(0.25).unwrap();
+        let mut sqrts = 0;
+        while norm_one(&(&t - &ident)) > quarter {
+            if sqrts == MAX_SQRTS {
+                return Err(LinalgError::from_kind(LinalgErrorKind::NotConverged));
+            }
+            t = sqrt_triangular(&t)?;
+            sqrts += 1;
+        }
+
+        // log(I + X) ≈ Σ wₖ X (I + tₖ X)⁻¹; all factors are upper triangular.
+        let x = t - &ident;
+        let mut log = Array2::zeros((n, n));
+        for &(node, weight) in &GAUSS_LEGENDRE_8 {
+            let node = Complex::from(A::from(node).unwrap());
+            let weight = Complex::from(A::from(weight).unwrap());
+            let denom = x.mapv(|x| x * node) + &ident;
+            let mut y = x.clone();
+            solve_triangular_mat_impl(Triangle::Upper, false, &denom.view(), &mut y.view_mut());
+            log.scaled_add(weight, &y);
+        }
+        let two = A::one() + A::one();
+        log.mapv_inplace(|x| x * two.powi(sqrts as i32));
+        Ok(unitary_similar(&q.unwrap(), &log))
+    }
+}
+
+/// Compute the principal square root of the upper triangular matrix `t`.
+fn sqrt_triangular<A>(t: &Array2<Complex<A>>) -> Result<Array2<Complex<A>>, LinalgError>
+where
+    A: NdFloat,
+{
+    let n = t.nrows();
+    let zero = Complex::from(A::zero());
+    let mut r = Array2::zeros((n, n));
+    for j in 0..n {
+        r[[j, j]] = t[[j, j]].sqrt();
+        for i in (0..j).rev() {
+            let sum = r.slice(s![i, i + 1..j]).dot(&r.slice(s![i + 1..j, j]));
+            let numer = t[[i, j]] - sum;
+            let denom = r[[i, i]] + r[[j, j]];
+            r[[i, j]] = if denom != zero {
+                numer / denom
+            } else if numer == zero {
+                zero
+            } else {
+                return Err(LinalgError::from_kind(LinalgErrorKind::Singular));
+            };
+        }
+    }
+    Ok(r)
+}
+
+/// Return Q T Qᴴ.
+fn unitary_similar<A>(q: &Array2<Complex<A>>, t: &Array2<Complex<A>>) -> Array2<Complex<A>>
+where
+    A: NdFloat,
+{
+    q.dot(t).dot(&q.t().mapv(|x| x.conj()))
+}
+
+/// Return the operator 1-norm of the complex matrix `a`.
+fn norm_one<A>(a: &Array2<Complex<A>>) -> A
+where
+    A: NdFloat,
+{
+    a.gencolumns().into_iter().fold(A::zero(), |acc, col| {
+        acc.max(col.iter().fold(A::zero(), |sum, x| sum + x.norm()))
+    })
+}
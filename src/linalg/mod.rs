@@ -8,8 +8,60 @@
 
 //! Linear algebra.
 
+pub use self::banded::{banded_mat_vec_mul, BandedMatrix};
+pub use self::eig::Eig;
+pub use self::einsum::einsum;
+pub use self::error::{LinalgError, LinalgErrorKind};
+pub use self::givens::Givens;
+#[cfg(feature = "half")]
+pub use self::half_precision::general_mat_mul_f16;
+pub use self::hermitian::{hermitian_mat_mul, hermitian_rank_k_update, Hermitian};
 pub use self::impl_linalg::general_mat_mul;
 pub use self::impl_linalg::general_mat_vec_mul;
+pub use self::impl_linalg::outer;
 pub use self::impl_linalg::Dot;
+pub use self::least_squares::LeastSquares;
+pub use self::lu::LU;
+pub use self::svd::SVD;
+pub use self::symmetric::{symmetric_mat_mul, symmetric_rank_k_update, Symmetric};
+pub use self::tensordot::tensordot;
+pub use self::triangular::{solve_triangular_mat, solve_triangular_vec, Triangle};
 
+/// Pass a scalar argument to a cblas routine: real scalars by value,
+/// complex scalars by pointer.
+#[cfg(feature = "blas")]
+macro_rules! blas_scalar {
+    (f32, $var:ident) => {
+        cast_as(&$var)
+    };
+    (f64, $var:ident) => {
+        cast_as(&$var)
+    };
+    (c32, $var:ident) => {
+        &$var as *const A as *const _
+    };
+    (c64, $var:ident) => {
+        &$var as *const A as *const _
+    };
+}
+
+mod banded;
+mod cholesky;
+mod eig;
+mod eigh;
+mod einsum;
+mod error;
+mod givens;
+#[cfg(feature = "half")]
+mod half_precision;
+mod hermitian;
 mod impl_linalg;
+mod least_squares;
+mod lu;
+mod matrix_functions;
+mod norm;
+mod qr;
+mod svd;
+mod symmetric;
+mod tensordot;
+mod triangular;
@@ -0,0 +1,117 @@
+// Copyright 2016 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::imp_prelude::*;
+use crate::NdFloat;
+
+use super::LinalgError;
+
+impl<A, S> ArrayBase<S, Ix2>
+where
+    S: Data<Elem = A>,
+    A: NdFloat,
+{
+    /// Return the operator 1-norm of the matrix: the largest sum of the
+    /// absolute values of a column.
+    ///
+    /// ```
+    /// use ndarray::arr2;
+    ///
+    /// let a = arr2(&[[1., -2.],
+    ///                [3., 4.]]);
+    /// assert_eq!(a.opnorm_one(), 6.);
+    /// ```
+    pub fn opnorm_one(&self) -> A {
+        self.gencolumns()
+            .into_iter()
+            .fold(A::zero(), |acc, col| acc.max(col.norm_l1()))
+    }
+
+    /// Return the operator ∞-norm of the matrix: the largest sum of the
+    /// absolute values of a row.
+    ///
+    /// ```
+    /// use ndarray::arr2;
+    ///
+    /// let a = arr2(&[[1., -2.],
+    ///                [3., 4.]]);
+    /// assert_eq!(a.opnorm_inf(), 7.);
+    /// ```
+    pub fn opnorm_inf(&self) -> A {
+        self.genrows()
+            .into_iter()
+            .fold(A::zero(), |acc, row| acc.max(row.norm_l1()))
+    }
+
+    /// Return the Frobenius norm of the matrix: the square root of the sum
+    /// of the squares of its elements.
+    ///
+    /// Like [`.norm_l2()`](#method.norm_l2), this does not overflow or
+    /// underflow unless the result does.
+    ///
+    /// ```
+    /// use ndarray::arr2;
+    ///
+    /// let a = arr2(&[[1., -2.],
+    ///                [2., 4.]]);
+    /// assert!((a.opnorm_fro() - 5_f64).abs() < 1e-12);
+    /// ```
+    pub fn opnorm_fro(&self) -> A {
+        let row_norms: Array1<A> = self
+            .genrows()
+            .into_iter()
+            .map(|row| row.norm_l2())
+            .collect();
+        row_norms.norm_l2()
+    }
+
+    /// Return the spectral norm (operator 2-norm) of the matrix: its
+    /// largest singular value.
+    ///
+    /// **Errors** if the singular value decomposition does not converge.
+    ///
+    /// ```
+    /// use ndarray::arr2;
+    ///
+    /// let a = arr2(&[[3., 0.],
+    ///                [4., 5.]]);
+    /// assert!((a.opnorm_two().unwrap() - 45_f64.sqrt()).abs() < 1e-12);
+    /// ```
+    pub fn opnorm_two(&self) -> Result<A, LinalgError> {
+        let s = self.svd_values_only()?;
+        Ok(s.get(0).cloned().unwrap_or_else(A::zero))
+    }
+
+    /// Return the condition number of the matrix in the 2-norm: the ratio of
+    /// its largest to its smallest singular value.
+    ///
+    /// The condition number of a rank deficient matrix is infinite, and that
+    /// of an empty matrix is zero.
+    ///
+    /// **Errors** if the singular value decomposition does not converge.
+    ///
+    /// ```
+    /// use ndarray::arr2;
+    ///
+    /// let a = arr2(&[[3., 0.],
+    ///                [4., 5.]]);
+    /// assert!((a.cond().unwrap() - 3_f64).abs() < 1e-12);
+    /// ```
+    pub fn cond(&self) -> Result<A, LinalgError> {
+        let s = self.svd_values_only()?;
+        let (max, min) = match s.len() {
+            0 => return Ok(A::zero()),
+            k => (s[0], s[k - 1]),
+        };
+        if min.is_zero() {
+            Ok(A::infinity())
+        } else {
+            Ok(max / min)
+        }
+    }
+}
@@ -0,0 +1,114 @@
+// Copyright 2016 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::cmp;
+
+use crate::imp_prelude::*;
+use crate::NdFloat;
+
+use super::general_mat_vec_mul;
+
+impl<A, S> ArrayBase<S, Ix2>
+where
+    S: Data<Elem = A>,
+    A: NdFloat,
+{
+    /// Compute the QR decomposition of `self` and return (Q, R).
+    ///
+    /// If `self` is *M* × *N*, Q is an *M* × *M* orthogonal matrix and R is an
+    /// *M* × *N* upper triangular matrix such that `self` = Q R.
+    ///
+    /// The decomposition is computed with Householder reflections.
+    ///
+    /// ```
+    /// use ndarray::{arr2, Array2};
+    ///
+    /// let a = arr2(&[[3_f64, 1.],
+    ///                [4., 2.],
+    ///                [0., 2.]]);
+    /// let (q, r) = a.qr();
+    /// assert_eq!((q.dim(), r.dim()), ((3, 3), (3, 2)));
+    /// assert_eq!(r[[1, 0]], 0.);
+    /// assert!((q.dot(&r) - &a).iter().all(|x| x.abs() < 1e-12));
+    /// assert!((q.t().dot(&q) - Array2::<f64>::eye(3)).iter().all(|x| x.abs() < 1e-12));
+    /// ```
+    pub fn qr(&self) -> (Array2<A>, Array2<A>) {
+        let m = self.nrows();
+        let (reflectors, r) = householder_qr(self);
+        let q = householder_q(&reflectors, Array::eye(m));
+        (q, r)
+    }
+
+    /// Compute the thin QR decomposition of `self` and return (Q, R).
+    ///
+    /// If `self` is *M* × *N* and *K* is the smaller of *M* and *N*, Q is an
+    /// *M* × *K* matrix with orthonormal columns and R is a *K* × *N* upper
+    /// triangular matrix such that `self` = Q R.
+    pub fn qr_thin(&self) -> (Array2<A>, Array2<A>) {
+        let (m, n) = self.dim();
+        let k = cmp::min(m, n);
+        let (reflectors, r) = householder_qr(self);
+        let mut q = Array::zeros((m, k));
+        q.diag_mut().fill(A::one());
+        let q = householder_q(&reflectors, q);
+        (q, r.slice_move(s![..k, ..]))
+    }
+}
+
+/// Reduce `a` to upper triangular form R with Householder reflections and
+/// return the unit vectors *v* of the reflections I − 2 *v* *v*ᵀ, in order,
+/// and R.
+///
+/// The reflection of step `k` only touches rows `k..`, so its vector is
+/// stored without the leading zeros.
+fn householder_qr<A, S>(a: &ArrayBase<S, Ix2>) -> (Vec<Array1<A>>, Array2<A>)
+where
+    S: Data<Elem = A>,
+    A: NdFloat,
+{
+    let (m, n) = a.dim();
+    let mut r = a.to_owned();
+    let mut reflectors = Vec::with_capacity(cmp::min(m, n));
+    for k in 0..cmp::min(m, n) {
+        let mut v = r.slice(s![k.., k]).to_owned();
+        let norm = v.norm_l2();
+        // reflect onto the axis away from x, to avoid cancellation
+        let alpha = if v[0] < A::zero() { norm } else { -norm };
+        v[0] -= alpha;
+        let v_norm = v.norm_l2();
+        if !v_norm.is_zero() {
+            v.scale(v_norm.recip());
+            reflect(&v, r.slice_mut(s![k.., k..]));
+        }
+        r.slice_mut(s![k + 1.., k]).fill(A::zero());
+        reflectors.push(v);
+    }
+    (reflectors, r)
+}
+
+/// Apply the reflections of `householder_qr` to `q` from the left, last
+/// first, so that the first columns of the identity give the columns of Q.
+fn householder_q<A>(reflectors: &[Array1<A>], mut q: Array2<A>) -> Array2<A>
+where
+    A: NdFloat,
+{
+    for (k, v) in reflectors.iter().enumerate().rev() {
+        reflect(v, q.slice_mut(s![k.., ..]));
+    }
+    q
+}
+
+/// Replace `a` by (I − 2 *v* *v*ᵀ) `a`.
+fn reflect<A>(v: &Array1<A>, mut a: ArrayViewMut2<'_, A>)
+where
+    A: NdFloat,
+{
+    let mut w = Array::zeros(a.ncols());
+    general_mat_vec_mul(A::one(), &a.t(), v, A::zero(), &mut w);
+    a.general_rank1_update(-(A::one() + A::one()), v, &w);
+}
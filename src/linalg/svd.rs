@@ -0,0 +1,182 @@
+// Copyright 2016 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::cmp::Ordering;
+
+use crate::imp_prelude::*;
+use crate::NdFloat;
+
+use super::{Givens, LinalgError, LinalgErrorKind};
+
+/// The number of sweeps over all pairs of columns after which the Jacobi
+/// iteration gives up.
+const MAX_SWEEPS: usize = 100;
+
+/// The singular value decomposition (U, singular values, Vᵀ), where U and
+/// Vᵀ are only present if they were asked for.
+pub type SVD<A> = (Option<Array2<A>>, Array1<A>, Option<Array2<A>>);
+
+impl<A, S> ArrayBase<S, Ix2>
+where
+    S: Data<Elem = A>,
+    A: NdFloat,
+{
+    /// Compute the singular value decomposition `self` = U Σ Vᵀ.
+    ///
+    /// If `self` is *M* × *N* and *K* is the smaller of *M* and *N*, return
+    /// the *M* × *M* orthogonal matrix U if `compute_u` is true, the *K*
+    /// singular values in decreasing order, and the *N* × *N* orthogonal
+    /// matrix Vᵀ if `compute_vt` is true.
+    ///
+    /// The decomposition is computed with the one-sided Jacobi method, which
+    /// finds even small singular values to high relative accuracy.
+    ///
+    /// **Errors** if the iteration does not converge.
+    ///
+    /// ```
+    /// use ndarray::{arr1, arr2, Array2};
+    ///
+    /// let a = arr2(&[[3., 0.],
+    ///                [4., 5.]]);
+    /// let (u, s, vt) = a.svd(true, true).unwrap();
+    /// let (u, vt) = (u.unwrap(), vt.unwrap());
+    /// assert!((&s - &arr1(&[45_f64.sqrt(), 5_f64.sqrt()])).norm_l2() < 1e-12);
+    ///
+    /// let sigma = Array2::from_diag(&s);
+    /// assert!((u.dot(&sigma).dot(&vt) - &a).iter().all(|x| x.abs() < 1e-12));
+    /// ```
+    pub fn svd(&self, compute_u: bool, compute_vt: bool) -> Result<SVD<A>, LinalgError> {
+        let (m, n) = self.dim();
+        if m >= n {
+            let (u, s, v) = jacobi_svd(self.view(), compute_u, compute_vt)?;
+            Ok((u, s, v.map(|v| v.reversed_axes())))
+        } else {
+            // Aᵀ = V Σ Uᵀ
+            let (v, s, u) = jacobi_svd(self.t(), compute_vt, compute_u)?;
+            Ok((u, s, v.map(|v| v.reversed_axes())))
+        }
+    }
+
+    /// Compute the singular values of `self`, in decreasing order.
+    ///
+    /// This is faster than [`.svd()`](#method.svd), since the singular
+    /// vectors are not accumulated.
+    ///
+    /// **Errors** if the iteration does not converge.
+    pub fn svd_values_only(&self) -> Result<Array1<A>, LinalgError> {
+        let (m, n) = self.dim();
+        let a = if m >= n { self.view() } else { self.t() };
+        jacobi_svd(a, false, false).map(|(_, s, _)| s)
+    }
+
+    /// Compute the Moore–Penrose pseudo-inverse of `self`.
+    ///
+    /// Singular values up to `rcond` times the largest singular value are
+    /// treated as zero. If `self` is *M* × *N*, the result is *N* × *M*.
+    ///
+    /// **Errors** if the singular value decomposition does not converge.
+    ///
+    /// ```
+    /// use ndarray::arr2;
+    ///
+    /// let a = arr2(&[[1., 2.],
+    ///                [2., 4.]]);
+    /// let a_pinv = a.pinv(1e-12).unwrap();
+    /// assert!((a_pinv - arr2(&[[0.04, 0.08],
+    ///                          [0.08, 0.16]])).iter().all(|x: &f64| x.abs() < 1e-12));
+    /// ```
+    pub fn pinv(&self, rcond: A) -> Result<Array2<A>, LinalgError> {
+        let (u, s, vt) = self.svd(true, true)?;
+        let (u, vt) = (u.unwrap(), vt.unwrap());
+        let cutoff = s.get(0).map_or(A::zero(), |&s0| s0 * rcond);
+        let rank = s.iter().take_while(|&&s| s > cutoff).count();
+        // V Σ⁺ Uᵀ
+        let mut v = vt.slice_move(s![..rank, ..]).reversed_axes();
+        for (mut col, &s) in v.gencolumns_mut().into_iter().zip(&s) {
+            col.scale(s.recip());
+        }
+        Ok(v.dot(&u.slice(s![.., ..rank]).t()))
+    }
+}
+
+/// One-sided Jacobi SVD of the *M* × *N* matrix `a` with *M* ≥ *N*.
+///
+/// Return (U, Σ, V), with U *M* × *M* and V *N* × *N*, such that
+/// `a` = U Σ Vᵀ.
+fn jacobi_svd<A>(a: ArrayView2<'_, A>, want_u: bool, want_v: bool) -> Result<SVD<A>, LinalgError>
+where
+    A: NdFloat,
+{
+    let (m, n) = a.dim();
+    debug_assert!(m >= n);
+    // Rotate pairs of columns of `w` = `a` V until they are all orthogonal;
+    // then `w` = U Σ.
+    let mut w = a.to_owned();
+    // Columns this small are zero to working precision; rotating them only
+    // shuffles rounding errors around.
+    let negligible = A::epsilon() * A::epsilon() * w.iter().fold(A::zero(), |acc, &x| acc + x * x);
+    let mut v = if want_v { Some(Array::eye(n)) } else { None };
+    let mut converged = false;
+    for _ in 0..MAX_SWEEPS {
+        let mut rotated = false;
+        for p in 0..n {
+            for q in p + 1..n {
+                let (x, y) = (w.column(p), w.column(q));
+                let (alpha, beta, gamma) = (x.dot(&x), y.dot(&y), x.dot(&y));
+                if alpha <= negligible
+                    || beta <= negligible
+                    || gamma.abs() <= A::epsilon() * (alpha * beta).sqrt()
+                {
+                    continue;
+                }
+                rotated = true;
+                let zeta = (beta - alpha) / (gamma + gamma);
+                let t = zeta.signum() / (zeta.abs() + A::one().hypot(zeta));
+                let c = A::one().hypot(t).recip();
+                let rotation = Givens::new(c, -c * t);
+                rotation.rotate_columns(&mut w, p, q);
+                if let Some(ref mut v) = v {
+                    rotation.rotate_columns(v, p, q);
+                }
+            }
+        }
+        if !rotated {
+            converged = true;
+            break;
+        }
+    }
+    if !converged {
+        return Err(LinalgError::from_kind(LinalgErrorKind::NotConverged));
+    }
+
+    let norms = w.gencolumns().into_iter().map(|c| c.norm_l2());
+    let mut order: Vec<_> = norms.enumerate().collect();
+    order.sort_by(|x, y| y.1.partial_cmp(&x.1).unwrap_or(Ordering::Equal));
+    let s = order.iter().map(|&(_, s)| s).collect::<Array1<_>>();
+    let v = v.map(|v| v.select(Axis(1), &order.iter().map(|&(j, _)| j).collect::<Vec<_>>()));
+
+    let u = if want_u {
+        // Columns for singular values that are zero to working precision
+        // are not determined by `w`; complete the basis instead.
+        let tol = s
+            .get(0)
+            .map_or(A::zero(), |&s0| s0 * A::epsilon() * A::from(m).unwrap());
+        let rank = s.iter().take_while(|&&s| s > tol).count();
+        let mut u_rank = Array::zeros((m, rank));
+        for (mut col, &(j, s)) in u_rank.gencolumns_mut().into_iter().zip(&order) {
+            col.assign(&w.column(j));
+            col.scale(s.recip());
+        }
+        let (mut u, _) = u_rank.qr();
+        u.slice_mut(s![.., ..rank]).assign(&u_rank);
+        Some(u)
+    } else {
+        None
+    };
+    Ok((u, s, v))
+}
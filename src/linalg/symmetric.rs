@@ -0,0 +1,281 @@
+// Copyright 2016 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::imp_prelude::*;
+use crate::LinalgScalar;
+
+use super::general_mat_mul;
+use super::Triangle;
+
+#[cfg(feature = "blas")]
+use super::impl_linalg::{blas_index, blas_layout, blas_stride, blas_transpose, cast_as};
+#[cfg(feature = "blas")]
+use cblas_sys as blas_sys;
+#[cfg(feature = "blas")]
+use cblas_sys::{CblasLeft, CBLAS_LAYOUT};
+#[cfg(feature = "blas")]
+use num_complex::{Complex32 as c32, Complex64 as c64};
+
+/// A square matrix that is known to be symmetric.
+///
+/// Only the elements in the `uplo` triangle of the wrapped matrix are read;
+/// the elements of the other triangle are taken to be their mirror images.
+#[derive(Copy, Clone, Debug)]
+pub struct Symmetric<T> {
+    matrix: T,
+    uplo: Triangle,
+}
+
+impl<'a, A> Symmetric<ArrayView2<'a, A>> {
+    /// Wrap the square matrix `matrix`, whose elements are stored in its
+    /// `uplo` triangle.
+    ///
+    /// ***Panics*** if `matrix` is not square.
+    pub fn new(matrix: ArrayView2<'a, A>, uplo: Triangle) -> Self {
+        let (m, n) = matrix.dim();
+        assert_eq!(m, n, "ndarray: a symmetric matrix must be square");
+        Symmetric { matrix, uplo }
+    }
+
+    /// Return the triangle of the matrix that holds the elements.
+    pub fn uplo(&self) -> Triangle {
+        self.uplo
+    }
+
+    /// Return a view of the wrapped matrix, including the unread triangle.
+    pub fn view(&self) -> ArrayView2<'a, A> {
+        self.matrix
+    }
+
+    /// Return an owned matrix with both triangles filled in.
+    pub fn to_full(&self) -> Array2<A>
+    where
+        A: Clone,
+    {
+        let a = &self.matrix;
+        let upper = self.uplo == Triangle::Upper;
+        Array::from_shape_fn(a.raw_dim(), |(i, j)| {
+            if (i <= j) == upper || i == j {
+                a[[i, j]].clone()
+            } else {
+                a[[j, i]].clone()
+            }
+        })
+    }
+}
+
+/// Symmetric rank-k update.
+///
+/// Compute C ← α A Aᵀ + β C
+///
+/// where A is a *N* × *K* matrix and C is a *N* × *N* symmetric matrix.
+/// Only the `uplo` triangle of `c` is read and written; the elements of the
+/// other triangle are left unchanged.
+///
+/// ***Panics*** if array shapes are not compatible<br>
+/// *Note:* If enabled, uses blas `syrk` for elements of `f32, f64, Complex32,
+/// Complex64` when memory layout allows.
+///
+/// ```
+/// use ndarray::arr2;
+/// use ndarray::linalg::{symmetric_rank_k_update, Triangle};
+///
+/// let a = arr2(&[[1., 2.],
+///                [3., 4.]]);
+/// let mut c = arr2(&[[1., -1.],
+///                    [-1., 1.]]);
+/// symmetric_rank_k_update(1., &a, 1., &mut c, Triangle::Lower);
+/// assert_eq!(c, arr2(&[[6., -1.],
+///                      [10., 26.]]));
+/// ```
+pub fn symmetric_rank_k_update<A, S1, S2>(
+    alpha: A,
+    a: &ArrayBase<S1, Ix2>,
+    beta: A,
+    c: &mut ArrayBase<S2, Ix2>,
+    uplo: Triangle,
+) where
+    S1: Data<Elem = A>,
+    S2: DataMut<Elem = A>,
+    A: LinalgScalar,
+{
+    let (n, k) = a.dim();
+    let (m2, n2) = c.dim();
+    if m2 != n || n2 != n {
+        symmetric_shape_error(n, k, k, n, m2, n2);
+    }
+
+    #[cfg(feature = "blas")]
+    macro_rules! syrk {
+        ($ty:tt, $syrk:ident) => {
+            if let (Some(a_layout), Some(c_layout)) =
+                (blas_layout::<$ty, _>(a), blas_layout::<$ty, _>(c))
+            {
+                let a_trans = blas_transpose(a_layout, c_layout);
+                let a_stride = blas_stride(a, a_layout);
+                let c_stride = blas_stride(c, c_layout);
+                unsafe {
+                    blas_sys::$syrk(
+                        c_layout,
+                        uplo.to_cblas(),
+                        a_trans,
+                        n as blas_index,
+                        k as blas_index,
+                        blas_scalar!($ty, alpha),
+                        a.as_ptr() as *const _,
+                        a_stride,
+                        blas_scalar!($ty, beta),
+                        c.as_mut_ptr() as *mut _,
+                        c_stride,
+                    );
+                }
+                return;
+            }
+        };
+    }
+    #[cfg(feature = "blas")]
+    syrk!(f32, cblas_ssyrk);
+    #[cfg(feature = "blas")]
+    syrk!(f64, cblas_dsyrk);
+    #[cfg(feature = "blas")]
+    syrk!(c32, cblas_csyrk);
+    #[cfg(feature = "blas")]
+    syrk!(c64, cblas_zsyrk);
+
+    /* general */
+
+    for i in 0..n {
+        let cols = match uplo {
+            Triangle::Upper => i..n,
+            Triangle::Lower => 0..i + 1,
+        };
+        for j in cols {
+            let x = alpha * a.row(i).dot(&a.row(j));
+            let elt = &mut c[[i, j]];
+            *elt = if beta.is_zero() { x } else { *elt * beta + x };
+        }
+    }
+}
+
+/// Symmetric matrix multiplication.
+///
+/// Compute C ← α A B + β C
+///
+/// where A is a *M* × *M* symmetric matrix, B is a *M* × *N* matrix and
+/// C is a *M* × *N* matrix.
+///
+/// ***Panics*** if array shapes are not compatible<br>
+/// *Note:* If enabled, uses blas `symm` for elements of `f32, f64, Complex32,
+/// Complex64` when memory layout allows.
+///
+/// ```
+/// use ndarray::arr2;
+/// use ndarray::linalg::{symmetric_mat_mul, Symmetric, Triangle};
+///
+/// // The lower triangle is not read.
+/// let a = arr2(&[[2., 1.],
+///                [0., 3.]]);
+/// let b = arr2(&[[1., 0.],
+///                [0., 1.]]);
+/// let mut c = arr2(&[[0., 0.],
+///                    [0., 0.]]);
+/// symmetric_mat_mul(1., Symmetric::new(a.view(), Triangle::Upper), &b, 0., &mut c);
+/// assert_eq!(c, arr2(&[[2., 1.],
+///                      [1., 3.]]));
+/// ```
+pub fn symmetric_mat_mul<A, S2, S3>(
+    alpha: A,
+    a: Symmetric<ArrayView2<'_, A>>,
+    b: &ArrayBase<S2, Ix2>,
+    beta: A,
+    c: &mut ArrayBase<S3, Ix2>,
+) where
+    S2: Data<Elem = A>,
+    S3: DataMut<Elem = A>,
+    A: LinalgScalar,
+{
+    let uplo = a.uplo;
+    let a = a.matrix;
+    let ((m, _), (m2, n)) = (a.dim(), b.dim());
+    let (m3, n2) = c.dim();
+    if m != m2 || m != m3 || n != n2 {
+        symmetric_shape_error(m, m, m2, n, m3, n2);
+    }
+
+    #[cfg(feature = "blas")]
+    macro_rules! symm {
+        ($ty:tt, $symm:ident) => {
+            if let (Some(a_layout), Some(b_layout), Some(c_layout)) = (
+                blas_layout::<$ty, _>(&a),
+                blas_layout::<$ty, _>(b),
+                blas_layout::<$ty, _>(c),
+            ) {
+                // symm has no transpose flag for b; fall through if its
+                // layout does not match c's.
+                let same_layout = match (b_layout, c_layout) {
+                    (CBLAS_LAYOUT::CblasRowMajor, CBLAS_LAYOUT::CblasRowMajor)
+                    | (CBLAS_LAYOUT::CblasColMajor, CBLAS_LAYOUT::CblasColMajor) => true,
+                    _ => false,
+                };
+                if same_layout {
+                    // `a` read in the other order is the same matrix, with
+                    // its elements in the other triangle.
+                    let a_uplo = match (a_layout, c_layout) {
+                        (CBLAS_LAYOUT::CblasRowMajor, CBLAS_LAYOUT::CblasRowMajor)
+                        | (CBLAS_LAYOUT::CblasColMajor, CBLAS_LAYOUT::CblasColMajor) => uplo,
+                        _ => uplo.transpose(),
+                    };
+                    let a_stride = blas_stride(&a, a_layout);
+                    let b_stride = blas_stride(b, b_layout);
+                    let c_stride = blas_stride(c, c_layout);
+                    unsafe {
+                        blas_sys::$symm(
+                            c_layout,
+                            CblasLeft,
+                            a_uplo.to_cblas(),
+                            m as blas_index,
+                            n as blas_index,
+                            blas_scalar!($ty, alpha),
+                            a.as_ptr() as *const _,
+                            a_stride,
+                            b.as_ptr() as *const _,
+                            b_stride,
+                            blas_scalar!($ty, beta),
+                            c.as_mut_ptr() as *mut _,
+                            c_stride,
+                        );
+                    }
+                    return;
+                }
+            }
+        };
+    }
+    #[cfg(feature = "blas")]
+    symm!(f32, cblas_ssymm);
+    #[cfg(feature = "blas")]
+    symm!(f64, cblas_dsymm);
+    #[cfg(feature = "blas")]
+    symm!(c32, cblas_csymm);
+    #[cfg(feature = "blas")]
+    symm!(c64, cblas_zsymm);
+
+    /* general */
+
+    let a = Symmetric { matrix: a, uplo }.to_full();
+    general_mat_mul(alpha, &a, b, beta, c);
+}
+
+#[cold]
+#[inline(never)]
+fn symmetric_shape_error(m: usize, k: usize, k2: usize, n: usize, c1: usize, c2: usize) -> ! {
+    panic!(
+        "ndarray: inputs {} × {}, {} × {}, and output {} × {} are not compatible for symmetric \
+         matrix multiplication",
+        m, k, k2, n, c1, c2
+    );
+}
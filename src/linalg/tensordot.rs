@@ -0,0 +1,124 @@
+// Copyright 2016 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::imp_prelude::*;
+use crate::LinalgScalar;
+
+/// Tensor contraction of `a` and `b` over the axes `a_axes` of `a` and
+/// `b_axes` of `b`.
+///
+/// The lengths of `a_axes[i]` in `a` and `b_axes[i]` in `b` must be equal;
+/// the result is the sum of the products of `a` and `b` over these axis
+/// pairs. Its axes are the remaining axes of `a`, followed by the remaining
+/// axes of `b`, each in their original order — like `numpy.tensordot`.
+///
+/// Both arrays are transposed and reshaped into matrices, copying only if
+/// their memory layout requires it, and multiplied with a single
+/// matrix multiplication.
+///
+/// **Panics** if `a_axes` and `b_axes` have different lengths, contain an
+/// axis twice or out of bounds, or pair axes of different lengths.
+///
+/// ```
+/// use ndarray::linalg::tensordot;
+/// use ndarray::{Array, Axis};
+///
+/// let a = Array::from_shape_fn((3, 4, 5), |(i, j, k)| (i + j * k) as f64);
+/// let b = Array::from_shape_fn((4, 3, 2), |(i, j, k)| (i * j + k) as f64);
+/// let c = tensordot(&a, &b, &[Axis(1), Axis(0)], &[Axis(0), Axis(1)]);
+/// assert_eq!(c.shape(), &[5, 2]);
+///
+/// let mut c_00 = 0.;
+/// for i in 0..3 {
+///     for j in 0..4 {
+///         c_00 += a[[i, j, 0]] * b[[j, i, 0]];
+///     }
+/// }
+/// assert_eq!(c[[0, 0]], c_00);
+/// ```
+pub fn tensordot<A, S1, S2, D1, D2>(
+    a: &ArrayBase<S1, D1>,
+    b: &ArrayBase<S2, D2>,
+    a_axes: &[Axis],
+    b_axes: &[Axis],
+) -> ArrayD<A>
+where
+    S1: Data<Elem = A>,
+    S2: Data<Elem = A>,
+    D1: Dimension,
+    D2: Dimension,
+    A: LinalgScalar,
+{
+    let compatible = a_axes.len() == b_axes.len()
+        && distinct_axes(a_axes, a.ndim())
+        && distinct_axes(b_axes, b.ndim())
+        && a_axes
+            .iter()
+            .zip(b_axes)
+            .all(|(&i, &j)| a.len_of(i) == b.len_of(j));
+    if !compatible {
+        tensordot_shape_error(a.shape(), a_axes, b.shape(), b_axes);
+    }
+
+    let a_free = free_axes(a_axes, a.ndim());
+    let b_free = free_axes(b_axes, b.ndim());
+    let m = a_free.iter().map(|&i| a.shape()[i]).product();
+    let n = b_free.iter().map(|&i| b.shape()[i]).product();
+    let k = a_axes.iter().map(|&i| a.len_of(i)).product();
+
+    // A with the free axes first, B with the contracted axes first
+    let a_perm: Vec<_> = a_free
+        .iter()
+        .cloned()
+        .chain(a_axes.iter().map(|i| i.index()))
+        .collect();
+    let b_perm: Vec<_> = b_axes
+        .iter()
+        .map(|i| i.index())
+        .chain(b_free.iter().cloned())
+        .collect();
+    let a_t = a.view().into_dyn().permuted_axes(a_perm);
+    let b_t = b.view().into_dyn().permuted_axes(b_perm);
+    let a_t = a_t.as_standard_layout();
+    let b_t = b_t.as_standard_layout();
+    let a_mat = a_t.view().into_shape((m, k)).unwrap();
+    let b_mat = b_t.view().into_shape((k, n)).unwrap();
+
+    let shape: Vec<_> = a_free
+        .iter()
+        .map(|&i| a.shape()[i])
+        .chain(b_free.iter().map(|&i| b.shape()[i]))
+        .collect();
+    a_mat.dot(&b_mat).into_shape(shape).unwrap()
+}
+
+/// Return true if `axes` are in bounds for `ndim` dimensions and distinct.
+fn distinct_axes(axes: &[Axis], ndim: usize) -> bool {
+    axes.iter()
+        .enumerate()
+        .all(|(i, &ax)| ax.index() < ndim && axes[..i].iter().all(|&other| other != ax))
+}
+
+/// Return the axes out of `ndim` that are not in `axes`, in order.
+fn free_axes(axes: &[Axis], ndim: usize) -> Vec<usize> {
+    (0..ndim).filter(|&i| !axes.contains(&Axis(i))).collect()
+}
+
+#[cold]
+#[inline(never)]
+fn tensordot_shape_error(
+    a_shape: &[usize],
+    a_axes: &[Axis],
+    b_shape: &[usize],
+    b_axes: &[Axis],
+) -> ! {
+    panic!(
+        "ndarray: axes {:?} of shape {:?} and axes {:?} of shape {:?} are not compatible for tensordot",
+        a_axes, a_shape, b_axes, b_shape
+    );
+}
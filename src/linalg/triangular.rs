@@ -0,0 +1,266 @@
+// Copyright 2016 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::imp_prelude::*;
+use crate::LinalgScalar;
+
+#[cfg(feature = "blas")]
+use super::impl_linalg::{
+    blas_1d_params, blas_compat_1d, blas_index, blas_layout, blas_stride, blas_transpose, cast_as,
+};
+#[cfg(feature = "blas")]
+use cblas_sys as blas_sys;
+#[cfg(feature = "blas")]
+use cblas_sys::{CblasLeft, CblasNoTrans, CblasNonUnit, CblasUnit, CBLAS_LAYOUT, CBLAS_UPLO};
+#[cfg(feature = "blas")]
+use num_complex::{Complex32 as c32, Complex64 as c64};
+
+/// Which triangle of a square matrix holds its elements.
+///
+/// The elements of the other triangle are never read.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Triangle {
+    /// The upper triangle, including the diagonal.
+    Upper,
+    /// The lower triangle, including the diagonal.
+    Lower,
+}
+
+impl Triangle {
+    /// Return the triangle that holds the elements of the transposed matrix.
+    #[inline]
+    pub fn transpose(self) -> Self {
+        match self {
+            Triangle::Upper => Triangle::Lower,
+            Triangle::Lower => Triangle::Upper,
+        }
+    }
+
+    #[cfg(feature = "blas")]
+    pub(super) fn to_cblas(self) -> CBLAS_UPLO {
+        match self {
+            Triangle::Upper => CBLAS_UPLO::CblasUpper,
+            Triangle::Lower => CBLAS_UPLO::CblasLower,
+        }
+    }
+}
+
+/// Triangular solve with a vector right hand side.
+///
+/// Solve A x = b for x, where A is an *N* × *N* triangular matrix whose
+/// elements are stored in the `uplo` triangle of `a`, and b is an
+/// *N*-element vector. The solution overwrites `b`.
+///
+/// No check is made for a singular `a`; a zero on the diagonal produces
+/// infinite or NaN elements for floating point types.
+///
+/// ***Panics*** if array shapes are not compatible<br>
+/// *Note:* If enabled, uses blas `trsv` for elements of `f32, f64, Complex32,
+/// Complex64` when memory layout allows.
+///
+/// ```
+/// use ndarray::arr1;
+/// use ndarray::arr2;
+/// use ndarray::linalg::{solve_triangular_vec, Triangle};
+///
+/// let a = arr2(&[[2., 1.],
+///                [0., 4.]]);
+/// let mut b = arr1(&[4., 8.]);
+/// solve_triangular_vec(Triangle::Upper, &a, &mut b);
+/// assert_eq!(b, arr1(&[1., 2.]));
+/// ```
+pub fn solve_triangular_vec<A, S1, S2>(
+    uplo: Triangle,
+    a: &ArrayBase<S1, Ix2>,
+    b: &mut ArrayBase<S2, Ix1>,
+) where
+    S1: Data<Elem = A>,
+    S2: DataMut<Elem = A>,
+    A: LinalgScalar,
+{
+    let (m, n) = a.dim();
+    let k = b.len();
+    if m != n || n != k {
+        triangular_shape_error(m, n, k, 1);
+    }
+    solve_triangular_vec_impl(uplo, false, &a.view(), &mut b.view_mut());
+}
+
+/// Triangular solve with a matrix right hand side.
+///
+/// Solve A X = B for X, where A is an *N* × *N* triangular matrix whose
+/// elements are stored in the `uplo` triangle of `a`, and B is an
+/// *N* × *K* matrix. The solution overwrites `b`.
+///
+/// No check is made for a singular `a`; a zero on the diagonal produces
+/// infinite or NaN elements for floating point types.
+///
+/// ***Panics*** if array shapes are not compatible<br>
+/// *Note:* If enabled, uses blas `trsm` for elements of `f32, f64, Complex32,
+/// Complex64` when memory layout allows.
+pub fn solve_triangular_mat<A, S1, S2>(
+    uplo: Triangle,
+    a: &ArrayBase<S1, Ix2>,
+    b: &mut ArrayBase<S2, Ix2>,
+) where
+    S1: Data<Elem = A>,
+    S2: DataMut<Elem = A>,
+    A: LinalgScalar,
+{
+    let (m, n) = a.dim();
+    let (k, k2) = b.dim();
+    if m != n || n != k {
+        triangular_shape_error(m, n, k, k2);
+    }
+    solve_triangular_mat_impl(uplo, false, &a.view(), &mut b.view_mut());
+}
+
+#[cold]
+#[inline(never)]
+fn triangular_shape_error(m: usize, n: usize, k: usize, k2: usize) -> ! {
+    panic!(
+        "ndarray: inputs {} × {} and {} × {} are not compatible for triangular solve",
+        m, n, k, k2
+    );
+}
+
+/// Solve A x = b in place, where `a` is square and `b` has matching length.
+///
+/// If `unit_diag` is true, the diagonal of `a` is assumed to be all ones and
+/// is not read.
+pub(super) fn solve_triangular_vec_impl<A>(
+    uplo: Triangle,
+    unit_diag: bool,
+    a: &ArrayView2<'_, A>,
+    b: &mut ArrayViewMut1<'_, A>,
+) where
+    A: LinalgScalar,
+{
+    #[cfg(feature = "blas")]
+    macro_rules! trsv {
+        ($ty:ty, $trsv:ident) => {
+            if let Some(layout) = blas_layout::<$ty, _>(a) {
+                if blas_compat_1d::<$ty, _>(b) {
+                    let n = b.len();
+                    let a_stride = blas_stride(a, layout);
+                    let diag = if unit_diag { CblasUnit } else { CblasNonUnit };
+                    unsafe {
+                        let (b_ptr, n, incx) = blas_1d_params(b.as_ptr(), n, b.strides()[0]);
+                        blas_sys::$trsv(
+                            layout,
+                            uplo.to_cblas(),
+                            CblasNoTrans,
+                            diag,
+                            n,
+                            a.as_ptr() as *const _,
+                            a_stride,
+                            b_ptr as *mut _,
+                            incx,
+                        );
+                    }
+                    return;
+                }
+            }
+        };
+    }
+    #[cfg(feature = "blas")]
+    trsv!(f32, cblas_strsv);
+    #[cfg(feature = "blas")]
+    trsv!(f64, cblas_dtrsv);
+    #[cfg(feature = "blas")]
+    trsv!(c32, cblas_ctrsv);
+    #[cfg(feature = "blas")]
+    trsv!(c64, cblas_ztrsv);
+
+    /* general */
+
+    let n = b.len();
+    match uplo {
+        Triangle::Lower => {
+            for i in 0..n {
+                let sum = a.slice(s![i, ..i]).dot(&b.slice(s![..i]));
+                let x = b[i] - sum;
+                b[i] = if unit_diag { x } else { x / a[[i, i]] };
+            }
+        }
+        Triangle::Upper => {
+            for i in (0..n).rev() {
+                let sum = a.slice(s![i, i + 1..]).dot(&b.slice(s![i + 1..]));
+                let x = b[i] - sum;
+                b[i] = if unit_diag { x } else { x / a[[i, i]] };
+            }
+        }
+    }
+}
+
+/// Solve A X = B in place, where `a` is square and `b` has matching rows.
+///
+/// If `unit_diag` is true, the diagonal of `a` is assumed to be all ones and
+/// is not read.
+pub(super) fn solve_triangular_mat_impl<A>(
+    uplo: Triangle,
+    unit_diag: bool,
+    a: &ArrayView2<'_, A>,
+    b: &mut ArrayViewMut2<'_, A>,
+) where
+    A: LinalgScalar,
+{
+    #[cfg(feature = "blas")]
+    macro_rules! trsm {
+        ($ty:tt, $trsm:ident) => {
+            if let (Some(a_layout), Some(b_layout)) =
+                (blas_layout::<$ty, _>(a), blas_layout::<$ty, _>(b))
+            {
+                let (m, n) = b.dim();
+                // If `a` is stored in the other order, blas reads its
+                // transpose, whose elements are in the other triangle.
+                let a_trans = blas_transpose(a_layout, b_layout);
+                let a_uplo = match (a_layout, b_layout) {
+                    (CBLAS_LAYOUT::CblasRowMajor, CBLAS_LAYOUT::CblasRowMajor)
+                    | (CBLAS_LAYOUT::CblasColMajor, CBLAS_LAYOUT::CblasColMajor) => uplo,
+                    _ => uplo.transpose(),
+                };
+                let a_stride = blas_stride(a, a_layout);
+                let b_stride = blas_stride(b, b_layout);
+                let diag = if unit_diag { CblasUnit } else { CblasNonUnit };
+                let alpha = A::one();
+                unsafe {
+                    blas_sys::$trsm(
+                        b_layout,
+                        CblasLeft,
+                        a_uplo.to_cblas(),
+                        a_trans,
+                        diag,
+                        m as blas_index,
+                        n as blas_index,
+                        blas_scalar!($ty, alpha),
+                        a.as_ptr() as *const _,
+                        a_stride,
+                        b.as_mut_ptr() as *mut _,
+                        b_stride,
+                    );
+                }
+                return;
+            }
+        };
+    }
+    #[cfg(feature = "blas")]
+    trsm!(f32, cblas_strsm);
+    #[cfg(feature = "blas")]
+    trsm!(f64, cblas_dtrsm);
+    #[cfg(feature = "blas")]
+    trsm!(c32, cblas_ctrsm);
+    #[cfg(feature = "blas")]
+    trsm!(c64, cblas_ztrsm);
+
+    /* general */
+
+    for mut column in b.axis_iter_mut(Axis(1)) {
+        solve_triangular_vec_impl(uplo, unit_diag, a, &mut column);
+    }
+}
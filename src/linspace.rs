@@ -15,6 +15,23 @@ pub struct Linspace<F> {
     step: F,
     index: usize,
     len: usize,
+    /// The index and the exact value of the last element, if it is not
+    /// computed from `start` and `step`.
+    end: Option<(usize, F)>,
+}
+
+impl<F> Linspace<F>
+where
+    F: Float,
+{
+    #[inline]
+    fn value(&self, i: usize) -> F {
+        match self.end {
+            Some((last, end)) if i == last => end,
+            // Calculate the value just like numpy.linspace does
+            _ => self.start + self.step * F::from(i).unwrap(),
+        }
+    }
 }
 
 impl<F> Iterator for Linspace<F>
@@ -28,10 +45,9 @@ where
         if self.index >= self.len {
             None
         } else {
-            // Calculate the value just like numpy.linspace does
             let i = self.index;
             self.index += 1;
-            Some(self.start + self.step * F::from(i).unwrap())
+            Some(self.value(i))
         }
     }
 
@@ -51,10 +67,9 @@ where
         if self.index >= self.len {
             None
         } else {
-            // Calculate the value just like numpy.linspace does
             self.len -= 1;
             let i = self.len;
-            Some(self.start + self.step * F::from(i).unwrap())
+            Some(self.value(i))
         }
     }
 }
@@ -74,17 +89,18 @@ pub fn linspace<F>(a: F, b: F, n: usize) -> Linspace<F>
 where
     F: Float,
 {
-    let step = if n > 1 {
+    let (step, end) = if n > 1 {
         let num_steps = F::from(n - 1).expect("Converting number of steps to `A` must not fail.");
-        (b - a) / num_steps
+        ((b - a) / num_steps, Some((n - 1, b)))
     } else {
-        F::zero()
+        (F::zero(), None)
     };
     Linspace {
         start: a,
         step,
         index: 0,
         len: n,
+        end,
     }
 }
 
@@ -113,5 +129,6 @@ where
              different from the sign of `step`.",
         ),
         index: 0,
+        end: None,
     }
 }
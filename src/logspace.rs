@@ -17,6 +17,24 @@ pub struct Logspace<F> {
     step: F,
     index: usize,
     len: usize,
+    /// The index and the exact exponent of the last element, if it is not
+    /// computed from `start` and `step`.
+    end: Option<(usize, F)>,
+}
+
+impl<F> Logspace<F>
+where
+    F: Float,
+{
+    #[inline]
+    fn value(&self, i: usize) -> F {
+        let exponent = match self.end {
+            Some((last, end)) if i == last => end,
+            // Calculate the value just like numpy.linspace does
+            _ => self.start + self.step * F::from(i).unwrap(),
+        };
+        self.sign * self.base.powf(exponent)
+    }
 }
 
 impl<F> Iterator for Logspace<F>
@@ -30,11 +48,9 @@ where
         if self.index >= self.len {
             None
         } else {
-            // Calculate the value just like numpy.linspace does
             let i = self.index;
             self.index += 1;
-            let exponent = self.start + self.step * F::from(i).unwrap();
-            Some(self.sign * self.base.powf(exponent))
+            Some(self.value(i))
         }
     }
 
@@ -54,11 +70,9 @@ where
         if self.index >= self.len {
             None
         } else {
-            // Calculate the value just like numpy.linspace does
             self.len -= 1;
             let i = self.len;
-            let exponent = self.start + self.step * F::from(i).unwrap();
-            Some(self.sign * self.base.powf(exponent))
+            Some(self.value(i))
         }
     }
 }
@@ -80,11 +94,11 @@ pub fn logspace<F>(base: F, a: F, b: F, n: usize) -> Logspace<F>
 where
     F: Float,
 {
-    let step = if n > 1 {
+    let (step, end) = if n > 1 {
         let num_steps = F::from(n - 1).expect("Converting number of steps to `A` must not fail.");
-        (b - a) / num_steps
+        ((b - a) / num_steps, Some((n - 1, b)))
     } else {
-        F::zero()
+        (F::zero(), None)
     };
     Logspace {
         sign: base.signum(),
@@ -93,6 +107,7 @@ where
         step,
         index: 0,
         len: n,
+        end,
     }
 }
 
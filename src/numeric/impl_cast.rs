@@ -0,0 +1,79 @@
+// Copyright 2014-2016 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use num_traits::{AsPrimitive, NumCast};
+
+use crate::imp_prelude::*;
+
+/// # Element Type Conversions
+impl<A, S, D> ArrayBase<S, D>
+where
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    /// Return a new array with each element converted to `B` like with the
+    /// `as` operator.
+    ///
+    /// The conversion never fails: conversions between integer types wrap
+    /// around, conversions to a float type round to the nearest value, and
+    /// conversions from a float to an integer type round towards zero.
+    ///
+    /// ```
+    /// use ndarray::arr1;
+    ///
+    /// let a = arr1(&[1.9, -1.9, 255.]);
+    /// assert_eq!(a.cast::<i32>(), arr1(&[1, -1, 255]));
+    /// assert_eq!(arr1(&[200u8, 255]).cast::<i8>(), arr1(&[-56, -1]));
+    /// ```
+    pub fn cast<B>(&self) -> Array<B, D>
+    where
+        A: AsPrimitive<B>,
+        B: Copy + 'static,
+    {
+        self.mapv(A::as_)
+    }
+
+    /// Return a new array with each element converted to `B`, if all
+    /// elements can be converted without loss.
+    ///
+    /// A conversion is lossless if converting the result back to `A` gives
+    /// the original value; NaN converts to NaN.
+    ///
+    /// Return `None` if an element is out of the range of `B`, or if
+    /// converting it loses precision or a fractional part.
+    ///
+    /// ```
+    /// use ndarray::arr1;
+    ///
+    /// let a = arr1(&[1., 2.5, 255.]);
+    /// assert_eq!(a.try_cast::<f32>(), Some(arr1(&[1., 2.5, 255.])));
+    /// assert_eq!(a.try_cast::<i32>(), None);
+    /// assert_eq!(a.mapv(f64::floor).try_cast::<u8>(), Some(arr1(&[1, 2, 255])));
+    /// assert_eq!(arr1(&[0.1_f64]).try_cast::<f32>(), None);
+    /// assert_eq!(arr1(&[-1]).try_cast::<u32>(), None);
+    /// ```
+    pub fn try_cast<B>(&self) -> Option<Array<B, D>>
+    where
+        A: NumCast + PartialOrd + Copy,
+        B: NumCast + PartialOrd + Copy,
+    {
+        let v = self
+            .iter()
+            .map(|&x| {
+                let y = B::from(x)?;
+                let nan = x.partial_cmp(&x).is_none() && y.partial_cmp(&y).is_none();
+                if nan || A::from(y) == Some(x) {
+                    Some(y)
+                } else {
+                    None
+                }
+            })
+            .collect::<Option<Vec<_>>>()?;
+        unsafe { Some(ArrayBase::from_shape_vec_unchecked(self.dim.clone(), v)) }
+    }
+}
@@ -0,0 +1,154 @@
+// Copyright 2014-2016 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use num_traits::Float;
+
+use crate::imp_prelude::*;
+use crate::Zip;
+
+macro_rules! unary_ops {
+    ($($name:ident, $name_inplace:ident, $doc:tt;)+) => {
+        $(
+            #[doc = $doc]
+            ///
+            /// Return a new array with the results.
+            pub fn $name(&self) -> Array<A, D>
+            where
+                S: Data,
+            {
+                self.mapv(A::$name)
+            }
+
+            #[doc = $doc]
+            ///
+            /// Modify the array in place.
+            pub fn $name_inplace(&mut self)
+            where
+                S: DataMut,
+            {
+                self.mapv_inplace(A::$name)
+            }
+        )+
+    };
+}
+
+macro_rules! binary_ops {
+    ($($name:ident, $doc:tt;)+) => {
+        $(
+            #[doc = $doc]
+            ///
+            /// `rhs` is broadcast to the shape of `self`; use
+            /// [`aview0`](fn.aview0.html) for a single value.
+            ///
+            /// **Panics** if broadcasting isn’t possible.
+            pub fn $name<S2, E>(&self, rhs: &ArrayBase<S2, E>) -> Array<A, D>
+            where
+                S: Data,
+                S2: Data<Elem = A>,
+                E: Dimension,
+            {
+                let rhs = rhs.broadcast_unwrap(self.raw_dim());
+                Zip::from(self).and(&rhs).apply_collect(|&x, &y| x.$name(y))
+            }
+        )+
+    };
+}
+
+/// # Element-wise Methods for Float Arrays
+///
+/// The methods apply the `Float` method of the same name to each element;
+/// each has an allocating version and an `_inplace` version. Arrays that
+/// are contiguous in memory are traversed as one slice, which lets the
+/// compiler vectorize the loop where the function allows it.
+///
+/// ```
+/// use ndarray::arr1;
+///
+/// let mut a = arr1(&[1., 4., 9.]);
+/// assert_eq!(a.sqrt(), arr1(&[1., 2., 3.]));
+/// a.ln_inplace();
+/// assert_eq!(a[0], 0.);
+/// ```
+impl<A, S, D> ArrayBase<S, D>
+where
+    A: Float,
+    S: RawData<Elem = A>,
+    D: Dimension,
+{
+    unary_ops! {
+        exp, exp_inplace, "The exponential `e^x` of each element.";
+        exp2, exp2_inplace, "The base 2 exponential `2^x` of each element.";
+        exp_m1, exp_m1_inplace, "`e^x - 1` of each element, accurate near zero.";
+        ln, ln_inplace, "The natural logarithm of each element.";
+        log2, log2_inplace, "The base 2 logarithm of each element.";
+        log10, log10_inplace, "The base 10 logarithm of each element.";
+        ln_1p, ln_1p_inplace, "`ln(1 + x)` of each element, accurate near zero.";
+        sqrt, sqrt_inplace, "The square root of each element.";
+        cbrt, cbrt_inplace, "The cube root of each element.";
+        sin, sin_inplace, "The sine of each element, in radians.";
+        cos, cos_inplace, "The cosine of each element, in radians.";
+        tan, tan_inplace, "The tangent of each element, in radians.";
+        asin, asin_inplace, "The arcsine of each element, in radians.";
+        acos, acos_inplace, "The arccosine of each element, in radians.";
+        atan, atan_inplace, "The arctangent of each element, in radians.";
+        sinh, sinh_inplace, "The hyperbolic sine of each element.";
+        cosh, cosh_inplace, "The hyperbolic cosine of each element.";
+        tanh, tanh_inplace, "The hyperbolic tangent of each element.";
+        asinh, asinh_inplace, "The inverse hyperbolic sine of each element.";
+        acosh, acosh_inplace, "The inverse hyperbolic cosine of each element.";
+        atanh, atanh_inplace, "The inverse hyperbolic tangent of each element.";
+        abs, abs_inplace, "The absolute value of each element.";
+        signum, signum_inplace, "The sign of each element: `1`, `-1` or NaN.";
+        recip, recip_inplace, "The reciprocal `1/x` of each element.";
+        floor, floor_inplace, "The largest integer less than or equal to each element.";
+        ceil, ceil_inplace, "The smallest integer greater than or equal to each element.";
+        round, round_inplace, "Each element rounded to the nearest integer, with halves away from zero.";
+        trunc, trunc_inplace, "The integer part of each element.";
+        fract, fract_inplace, "The fractional part of each element.";
+        to_degrees, to_degrees_inplace, "Each element converted from radians to degrees.";
+        to_radians, to_radians_inplace, "Each element converted from degrees to radians.";
+    }
+
+    binary_ops! {
+        powf, "Each element raised to the power of the element of `rhs`.";
+        atan2, "The four quadrant arctangent of each element of `self` (`y`) and `rhs` (`x`), in radians.";
+        hypot, "The length of the hypotenuse of a right triangle with each element of `self` and `rhs` as legs.";
+        log, "The logarithm of each element with the element of `rhs` as base.";
+        max, "The larger of each element and the element of `rhs`, ignoring NaN.";
+        min, "The smaller of each element and the element of `rhs`, ignoring NaN.";
+    }
+
+    /// Each element raised to the integer power `n`.
+    ///
+    /// Return a new array with the results.
+    ///
+    /// ```
+    /// use ndarray::{arr1, aview0};
+    ///
+    /// let a = arr1(&[1., 2., 3.]);
+    /// assert_eq!(a.powi(2), arr1(&[1., 4., 9.]));
+    /// assert_eq!(a.powf(&arr1(&[2., 0.5, 1.])), arr1(&[1., 2_f64.sqrt(), 3.]));
+    /// assert_eq!(a.hypot(&aview0(&4.))[2], 5.);
+    /// ```
+    pub fn powi(&self, n: i32) -> Array<A, D>
+    where
+        S: Data,
+    {
+        self.mapv(|x| x.powi(n))
+    }
+
+    /// Each element raised to the integer power `n`.
+    ///
+    /// Modify the array in place.
+    pub fn powi_inplace(&mut self, n: i32)
+    where
+        S: DataMut,
+    {
+        self.mapv_inplace(|x| x.powi(n))
+    }
+}
@@ -0,0 +1,122 @@
+// Copyright 2014-2016 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use num_traits::{CheckedAdd, CheckedDiv, CheckedMul, CheckedSub};
+use num_traits::{Saturating, WrappingAdd, WrappingMul, WrappingSub};
+
+use crate::imp_prelude::*;
+use crate::{FoldWhile, Zip};
+
+macro_rules! checked_ops {
+    ($($name:ident, $trait:ident, $doc:tt;)+) => {
+        $(
+            #[doc = $doc]
+            ///
+            /// `rhs` is broadcast to the shape of `self`.
+            ///
+            /// Return `None` if the operation overflows (or divides by zero)
+            /// for any element, like the `num_traits::Checked*` methods.
+            /// The computation stops at the first such element.
+            ///
+            /// **Panics** if broadcasting isn’t possible.
+            pub fn $name<S2, E>(&self, rhs: &ArrayBase<S2, E>) -> Option<Array<A, D>>
+            where
+                A: Clone + $trait,
+                S2: Data<Elem = A>,
+                E: Dimension,
+            {
+                let rhs = rhs.broadcast_unwrap(self.raw_dim());
+                let mut result = self.to_owned();
+                let failed = Zip::from(&mut result)
+                    .and(&rhs)
+                    .fold_while((), |(), x, y| match x.$name(y) {
+                        Some(z) => {
+                            *x = z;
+                            FoldWhile::Continue(())
+                        }
+                        None => FoldWhile::Done(()),
+                    })
+                    .is_done();
+                if failed {
+                    None
+                } else {
+                    Some(result)
+                }
+            }
+        )+
+    };
+}
+
+macro_rules! total_ops {
+    ($($name:ident, $trait:ident, $doc:tt;)+) => {
+        $(total_ops!(@ $name, $trait, $doc, |x, y| x.clone().$name(y.clone()));)+
+    };
+    (by_ref $($name:ident, $trait:ident, $doc:tt;)+) => {
+        $(total_ops!(@ $name, $trait, $doc, |x, y| x.$name(y));)+
+    };
+    (@ $name:ident, $trait:ident, $doc:tt, |$x:ident, $y:ident| $op:expr) => {
+        #[doc = $doc]
+        ///
+        /// `rhs` is broadcast to the shape of `self`.
+        ///
+        /// **Panics** if broadcasting isn’t possible.
+        pub fn $name<S2, E>(&self, rhs: &ArrayBase<S2, E>) -> Array<A, D>
+        where
+            A: Clone + $trait,
+            S2: Data<Elem = A>,
+            E: Dimension,
+        {
+            let mut result = self.to_owned();
+            result.zip_mut_with(rhs, |$x, $y| *$x = $op);
+            result
+        }
+    };
+}
+
+/// # Checked, Saturating and Wrapping Arithmetic
+///
+/// Element-wise arithmetic for integer arrays where overflow is explicit:
+/// the `checked_` methods return `None`, the `saturating_` methods clamp
+/// to the bounds of the element type and the `wrapping_` methods wrap
+/// around, regardless of whether overflow checks are enabled. The
+/// operators `+`, `-`, `*` and `/` use the element type’s operators, which
+/// panic on overflow in debug builds and wrap in release builds.
+///
+/// ```
+/// use ndarray::{arr1, aview0};
+///
+/// let a = arr1(&[100u8, 200, 250]);
+/// assert_eq!(a.checked_add(&aview0(&10)), None);
+/// assert_eq!(a.checked_add(&aview0(&5)).unwrap(), arr1(&[105, 205, 255]));
+/// assert_eq!(a.saturating_add(&aview0(&10)), arr1(&[110, 210, 255]));
+/// assert_eq!(a.wrapping_add(&aview0(&10)), arr1(&[110, 210, 4]));
+/// ```
+impl<A, S, D> ArrayBase<S, D>
+where
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    checked_ops! {
+        checked_add, CheckedAdd, "Return the element-wise sum of `self` and `rhs`, checking for overflow.";
+        checked_sub, CheckedSub, "Return the element-wise difference of `self` and `rhs`, checking for overflow.";
+        checked_mul, CheckedMul, "Return the element-wise product of `self` and `rhs`, checking for overflow.";
+        checked_div, CheckedDiv, "Return the element-wise quotient of `self` and `rhs`, checking for overflow and division by zero.";
+    }
+
+    total_ops! {
+        saturating_add, Saturating, "Return the element-wise sum of `self` and `rhs`, saturating at the bounds of the element type.";
+        saturating_sub, Saturating, "Return the element-wise difference of `self` and `rhs`, saturating at the bounds of the element type.";
+    }
+
+    total_ops! {
+        by_ref
+        wrapping_add, WrappingAdd, "Return the element-wise sum of `self` and `rhs`, wrapping around at the bounds of the element type.";
+        wrapping_sub, WrappingSub, "Return the element-wise difference of `self` and `rhs`, wrapping around at the bounds of the element type.";
+        wrapping_mul, WrappingMul, "Return the element-wise product of `self` and `rhs`, wrapping around at the bounds of the element type.";
+    }
+}
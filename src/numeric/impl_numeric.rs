@@ -6,14 +6,14 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-use num_traits::{self, Float, FromPrimitive, Zero};
-use std::ops::{Add, Div, Mul};
+use num_traits::{self, Float, FromPrimitive, One, Zero};
+use std::ops::{Add, Div, Mul, Sub};
 
 use crate::imp_prelude::*;
 use crate::itertools::enumerate;
 use crate::numeric_util;
 
-use crate::{FoldWhile, Zip};
+use crate::{FoldWhile, Slice, Zip};
 
 /// # Numerical Methods for Arrays
 impl<A, S, D> ArrayBase<S, D>
@@ -153,6 +153,184 @@ where
         res
     }
 
+    /// Return the sum over all of `axes` at once.
+    ///
+    /// The result has the remaining axes of `self`, in order; since their
+    /// number depends on `axes`, it has dynamic dimension. Each element is
+    /// computed in one traversal of its part of `self`, without
+    /// intermediate arrays.
+    ///
+    /// ```
+    /// use ndarray::{arr1, Array, Axis};
+    ///
+    /// let a = Array::from_shape_fn((2, 3, 4), |(i, j, k)| (i * 12 + j * 4 + k) as i32);
+    /// let s = a.sum_axes(&[Axis(0), Axis(2)]);
+    /// assert_eq!(s, a.sum_axis(Axis(2)).sum_axis(Axis(0)).into_dyn());
+    /// assert_eq!(s, arr1(&[60, 92, 124]).into_dyn());
+    /// ```
+    ///
+    /// **Panics** if an axis is out of bounds or occurs more than once.
+    pub fn sum_axes(&self, axes: &[Axis]) -> ArrayD<A>
+    where
+        A: Clone + Zero + Add<Output = A>,
+    {
+        self.reduce_axes(axes, |part| part.sum())
+    }
+
+    /// Return the product over all of `axes` at once.
+    ///
+    /// The result is shaped like for [`.sum_axes()`](#method.sum_axes).
+    ///
+    /// **Panics** if an axis is out of bounds or occurs more than once.
+    pub fn product_axes(&self, axes: &[Axis]) -> ArrayD<A>
+    where
+        A: Clone + One + Mul<Output = A>,
+    {
+        self.reduce_axes(axes, |part| part.product())
+    }
+
+    /// Return the mean over all of `axes` at once.
+    ///
+    /// The result is shaped like for [`.sum_axes()`](#method.sum_axes).
+    /// If the axes have no elements, that is if one of them has length
+    /// zero, `None` is returned.
+    ///
+    /// **Panics** if an axis is out of bounds or occurs more than once, or
+    /// if `A::from_usize()` fails for the number of elements in the axes.
+    pub fn mean_axes(&self, axes: &[Axis]) -> Option<ArrayD<A>>
+    where
+        A: Clone + Zero + FromPrimitive + Add<Output = A> + Div<Output = A>,
+    {
+        let n: usize = axes.iter().map(|&axis| self.len_of(axis)).product();
+        if n == 0 {
+            return None;
+        }
+        let n = A::from_usize(n).expect("Converting number of elements to `A` must not fail.");
+        Some(self.reduce_axes(axes, |part| part.sum() / n.clone()))
+    }
+
+    /// Return the result of `f` on the part of `self` for each index of the
+    /// axes that are not in `axes`.
+    fn reduce_axes<B, F>(&self, axes: &[Axis], mut f: F) -> ArrayD<B>
+    where
+        F: FnMut(ArrayViewD<'_, A>) -> B,
+    {
+        for (i, axis) in axes.iter().enumerate() {
+            assert!(
+                axis.index() < self.ndim() && !axes[..i].contains(axis),
+                "ndarray: axes to reduce must be in bounds and distinct"
+            );
+        }
+        let kept: Vec<_> = (0..self.ndim())
+            .filter(|&i| !axes.contains(&Axis(i)))
+            .collect();
+        let shape: Vec<_> = kept.iter().map(|&i| self.shape()[i]).collect();
+        let view = self.view().into_dyn();
+        Array::from_shape_fn(shape, |index| {
+            let mut part = view.view();
+            for (k, &i) in kept.iter().enumerate() {
+                part.collapse_axis(Axis(i), index[k]);
+            }
+            f(part)
+        })
+    }
+
+    /// Return product along `axis`.
+    ///
+    /// The product of an empty axis is one.
+    ///
+    /// ```
+    /// use ndarray::{aview1, arr2, Axis};
+    ///
+    /// let a = arr2(&[[1., 2., 3.],
+    ///                [4., 5., 6.]]);
+    /// assert!(
+    ///     a.product_axis(Axis(0)) == aview1(&[4., 10., 18.]) &&
+    ///     a.product_axis(Axis(1)) == aview1(&[6., 120.])
+    /// );
+    /// ```
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    pub fn product_axis(&self, axis: Axis) -> Array<A, D::Smaller>
+    where
+        A: Clone + One + Mul<Output = A>,
+        D: RemoveAxis,
+    {
+        let mut res = Array::ones(self.raw_dim().remove_axis(axis));
+        for view in self.axis_iter(axis) {
+            res = res * &view;
+        }
+        res
+    }
+
+    /// Return the smallest element of each lane along `axis`.
+    ///
+    /// If a lane contains elements that are not comparable to themselves,
+    /// like NaN, the first of them is its result instead, so NaN propagates.
+    ///
+    /// ```
+    /// use ndarray::{aview1, arr2, Axis};
+    ///
+    /// let a = arr2(&[[1, 5, 3],
+    ///                [4, 2, 6]]);
+    /// assert_eq!(a.min_axis(Axis(0)), aview1(&[1, 2, 3]));
+    /// assert_eq!(a.min_axis(Axis(1)), aview1(&[1, 2]));
+    /// ```
+    ///
+    /// **Panics** if `axis` is out of bounds or has length zero.
+    pub fn min_axis(&self, axis: Axis) -> Array<A, D::Smaller>
+    where
+        A: Clone + PartialOrd,
+        D: RemoveAxis,
+    {
+        self.extremum_axis(axis, |x, best| x < best)
+    }
+
+    /// Return the largest element of each lane along `axis`.
+    ///
+    /// If a lane contains elements that are not comparable to themselves,
+    /// like NaN, the first of them is its result instead, so NaN propagates.
+    ///
+    /// ```
+    /// use ndarray::{aview1, arr2, Axis};
+    ///
+    /// let a = arr2(&[[1, 5, 3],
+    ///                [4, 2, 6]]);
+    /// assert_eq!(a.max_axis(Axis(0)), aview1(&[4, 5, 6]));
+    /// assert_eq!(a.max_axis(Axis(1)), aview1(&[5, 6]));
+    /// ```
+    ///
+    /// **Panics** if `axis` is out of bounds or has length zero.
+    pub fn max_axis(&self, axis: Axis) -> Array<A, D::Smaller>
+    where
+        A: Clone + PartialOrd,
+        D: RemoveAxis,
+    {
+        self.extremum_axis(axis, |x, best| x > best)
+    }
+
+    fn extremum_axis<F>(&self, axis: Axis, better: F) -> Array<A, D::Smaller>
+    where
+        A: Clone + PartialOrd,
+        D: RemoveAxis,
+        F: Fn(&A, &A) -> bool,
+    {
+        assert_ne!(self.len_of(axis), 0, "ndarray: extremum of an empty axis");
+        let mut res = self.index_axis(axis, 0).to_owned();
+        for view in self.axis_iter(axis).skip(1) {
+            Zip::from(&mut res).and(&view).apply(|best, x| {
+                let b: &A = best;
+                // A best element that is NaN stays; a NaN element replaces it.
+                let replace =
+                    b.partial_cmp(b).is_some() && (x.partial_cmp(x).is_none() || better(x, b));
+                if replace {
+                    *best = x.clone();
+                }
+            });
+        }
+        res
+    }
+
     /// Return mean along `axis`.
     ///
     /// Return `None` if the length of the axis is zero.
@@ -306,6 +484,383 @@ where
         self.var_axis(axis, ddof).mapv_into(|x| x.sqrt())
     }
 
+    /// Return sum along `axis`, keeping `axis` with length one.
+    ///
+    /// This is [`.sum_axis()`](#method.sum_axis) with the same dimension as
+    /// `self`, so that the result broadcasts against `self`; the other
+    /// `_keepdims` methods are the same for their reductions.
+    ///
+    /// ```
+    /// use ndarray::{arr2, Axis};
+    ///
+    /// let a = arr2(&[[1., 3.],
+    ///                [2., 2.]]);
+    /// let s = a.sum_axis_keepdims(Axis(1));
+    /// assert_eq!(s, arr2(&[[4.],
+    ///                      [4.]]));
+    /// assert_eq!(&a / &s, arr2(&[[0.25, 0.75],
+    ///                            [0.5, 0.5]]));
+    /// ```
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    pub fn sum_axis_keepdims(&self, axis: Axis) -> Array<A, D>
+    where
+        A: Clone + Zero + Add<Output = A>,
+        D: RemoveAxis,
+    {
+        self.keep_axis(axis, self.sum_axis(axis))
+    }
+
+    /// Return product along `axis`, keeping `axis` with length one.
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    pub fn product_axis_keepdims(&self, axis: Axis) -> Array<A, D>
+    where
+        A: Clone + One + Mul<Output = A>,
+        D: RemoveAxis,
+    {
+        self.keep_axis(axis, self.product_axis(axis))
+    }
+
+    /// Return the smallest elements along `axis`, keeping `axis` with
+    /// length one.
+    ///
+    /// **Panics** if `axis` is out of bounds or has length zero.
+    pub fn min_axis_keepdims(&self, axis: Axis) -> Array<A, D>
+    where
+        A: Clone + PartialOrd,
+        D: RemoveAxis,
+    {
+        self.keep_axis(axis, self.min_axis(axis))
+    }
+
+    /// Return the largest elements along `axis`, keeping `axis` with length
+    /// one.
+    ///
+    /// **Panics** if `axis` is out of bounds or has length zero.
+    pub fn max_axis_keepdims(&self, axis: Axis) -> Array<A, D>
+    where
+        A: Clone + PartialOrd,
+        D: RemoveAxis,
+    {
+        self.keep_axis(axis, self.max_axis(axis))
+    }
+
+    /// Return mean along `axis`, keeping `axis` with length one, or `None`
+    /// if the length of `axis` is zero.
+    ///
+    /// **Panics** if `axis` is out of bounds or if `A::from_usize()` fails
+    /// for the length of `axis`.
+    pub fn mean_axis_keepdims(&self, axis: Axis) -> Option<Array<A, D>>
+    where
+        A: Clone + Zero + FromPrimitive + Add<Output = A> + Div<Output = A>,
+        D: RemoveAxis,
+    {
+        self.mean_axis(axis).map(|mean| self.keep_axis(axis, mean))
+    }
+
+    /// Return variance along `axis`, keeping `axis` with length one.
+    ///
+    /// See [`.var_axis()`](#method.var_axis) for the parameters.
+    ///
+    /// **Panics** if `ddof` is less than zero or greater than the length of
+    /// `axis`, if `axis` is out of bounds, or if `A::from_usize()` fails for
+    /// any of the numbers in the range `0..=n`.
+    pub fn var_axis_keepdims(&self, axis: Axis, ddof: A) -> Array<A, D>
+    where
+        A: Float + FromPrimitive,
+        D: RemoveAxis,
+    {
+        self.keep_axis(axis, self.var_axis(axis, ddof))
+    }
+
+    /// Return standard deviation along `axis`, keeping `axis` with length
+    /// one.
+    ///
+    /// See [`.std_axis()`](#method.std_axis) for the parameters.
+    ///
+    /// **Panics** if `ddof` is less than zero or greater than the length of
+    /// `axis`, if `axis` is out of bounds, or if `A::from_usize()` fails for
+    /// any of the numbers in the range `0..=n`.
+    pub fn std_axis_keepdims(&self, axis: Axis, ddof: A) -> Array<A, D>
+    where
+        A: Float + FromPrimitive,
+        D: RemoveAxis,
+    {
+        self.keep_axis(axis, self.std_axis(axis, ddof))
+    }
+
+    /// Insert `axis` with length one into `reduced`, the result of a
+    /// reduction of `axis`, to get an array of the dimension of `self`.
+    ///
+    /// The axis is inserted rather than reshaped to, since `reduced` need not
+    /// be in standard or Fortran layout.
+    fn keep_axis<B>(&self, axis: Axis, reduced: Array<B, D::Smaller>) -> Array<B, D>
+    where
+        D: RemoveAxis,
+    {
+        reduced.insert_axis(axis).into_dimensionality::<D>().unwrap()
+    }
+
+    /// Return the cumulative sum of the elements along `axis`.
+    ///
+    /// Element `i` along `axis` of the result is the sum of the elements
+    /// `0..=i` along `axis` of `self`.
+    ///
+    /// ```
+    /// use ndarray::{arr2, Axis};
+    ///
+    /// let a = arr2(&[[1, 2, 3],
+    ///                [4, 5, 6]]);
+    /// assert_eq!(a.cumsum(Axis(0)), arr2(&[[1, 2, 3],
+    ///                                      [5, 7, 9]]));
+    /// assert_eq!(a.cumsum(Axis(1)), arr2(&[[1, 3, 6],
+    ///                                      [4, 9, 15]]));
+    /// ```
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    pub fn cumsum(&self, axis: Axis) -> Array<A, D>
+    where
+        A: Clone + Add<Output = A>,
+    {
+        let mut res = self.to_owned();
+        res.accumulate_axis_inplace(axis, |prev, curr| *curr = prev.clone() + curr.clone());
+        res
+    }
+
+    /// Return the cumulative product of the elements along `axis`.
+    ///
+    /// Element `i` along `axis` of the result is the product of the elements
+    /// `0..=i` along `axis` of `self`.
+    ///
+    /// ```
+    /// use ndarray::{arr2, Axis};
+    ///
+    /// let a = arr2(&[[1, 2, 3],
+    ///                [4, 5, 6]]);
+    /// assert_eq!(a.cumprod(Axis(0)), arr2(&[[1, 2, 3],
+    ///                                       [4, 10, 18]]));
+    /// assert_eq!(a.cumprod(Axis(1)), arr2(&[[1, 2, 6],
+    ///                                       [4, 20, 120]]));
+    /// ```
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    pub fn cumprod(&self, axis: Axis) -> Array<A, D>
+    where
+        A: Clone + Mul<Output = A>,
+    {
+        let mut res = self.to_owned();
+        res.accumulate_axis_inplace(axis, |prev, curr| *curr = prev.clone() * curr.clone());
+        res
+    }
+
+    /// Return the `n`-th discrete difference along `axis`.
+    ///
+    /// The first difference is `a[i + 1] - a[i]` along `axis`, and higher
+    /// differences are computed by repeating it; each difference makes the
+    /// axis one element shorter, down to zero length. The zeroth difference
+    /// is a copy of `self`.
+    ///
+    /// ```
+    /// use ndarray::{arr1, arr2, Axis};
+    ///
+    /// let a = arr1(&[1, 2, 4, 7, 0]);
+    /// assert_eq!(a.diff(Axis(0), 1), arr1(&[1, 2, 3, -7]));
+    /// assert_eq!(a.diff(Axis(0), 2), arr1(&[1, 1, -10]));
+    ///
+    /// let b = arr2(&[[1, 3, 6],
+    ///                [0, 5, 6]]);
+    /// assert_eq!(b.diff(Axis(0), 1), arr2(&[[-1, 2, 0]]));
+    /// assert_eq!(b.diff(Axis(1), 1), arr2(&[[2, 3],
+    ///                                       [5, 1]]));
+    /// ```
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    pub fn diff(&self, axis: Axis, n: usize) -> Array<A, D>
+    where
+        A: Clone + Sub<Output = A>,
+    {
+        let mut res = self.to_owned();
+        for _ in 0..n {
+            if res.len_of(axis) == 0 {
+                break;
+            }
+            res =
+                &res.slice_axis(axis, Slice::from(1..)) - &res.slice_axis(axis, Slice::from(..-1));
+        }
+        res
+    }
+
+    /// Return the gradient along `axis`, for samples with the given
+    /// `spacing`.
+    ///
+    /// The gradient is computed with central differences
+    /// `(a[i + 1] - a[i - 1]) / (2 * spacing)` in the interior and with
+    /// one-sided differences at the first and last element, like
+    /// `numpy.gradient`. The result has the same shape as `self`.
+    ///
+    /// ```
+    /// use ndarray::{arr1, Axis};
+    ///
+    /// let a = arr1(&[1., 2., 4., 7., 11.]);
+    /// assert_eq!(a.gradient(Axis(0), 0.5), arr1(&[2., 3., 5., 7., 8.]));
+    /// ```
+    ///
+    /// **Panics** if `axis` is out of bounds or has less than two elements.
+    pub fn gradient(&self, axis: Axis, spacing: A) -> Array<A, D>
+    where
+        A: Float,
+    {
+        let n = self.len_of(axis);
+        assert!(
+            n >= 2,
+            "ndarray: gradient requires at least two elements along the axis"
+        );
+        let mut res = Array::zeros(self.raw_dim());
+        let two = A::one() + A::one();
+        Zip::from(res.slice_axis_mut(axis, Slice::new(1, Some(-1), 1)))
+            .and(self.slice_axis(axis, Slice::from(2..)))
+            .and(self.slice_axis(axis, Slice::from(..-2)))
+            .apply(|r, &next, &prev| *r = (next - prev) / (two * spacing));
+        for &(i, j, k) in &[(0, 0, 1), (n - 1, n - 2, n - 1)] {
+            Zip::from(res.slice_axis_mut(axis, Slice::from(i..i + 1)))
+                .and(self.slice_axis(axis, Slice::from(j..j + 1)))
+                .and(self.slice_axis(axis, Slice::from(k..k + 1)))
+                .apply(|r, &prev, &next| *r = (next - prev) / spacing);
+        }
+        res
+    }
+
+    /// Return the index of the first largest element, or `None` if the
+    /// array is empty.
+    ///
+    /// If the array contains elements that are not comparable to themselves,
+    /// like NaN, the index of the first of them is returned instead, like
+    /// `numpy.argmax`.
+    ///
+    /// ```
+    /// use ndarray::arr2;
+    /// use std::f64::NAN;
+    ///
+    /// let a = arr2(&[[1., 5., 2.],
+    ///                [5., 0., 3.]]);
+    /// assert_eq!(a.argmax(), Some((0, 1)));
+    /// assert_eq!(a.argmin(), Some((1, 1)));
+    /// assert_eq!(arr2(&[[1., NAN, 7.]]).argmax(), Some((0, 1)));
+    /// ```
+    pub fn argmax(&self) -> Option<D::Pattern>
+    where
+        A: PartialOrd,
+    {
+        arg_extremum(self.indexed_iter(), |x, best| x > best)
+    }
+
+    /// Return the index of the first smallest element, or `None` if the
+    /// array is empty.
+    ///
+    /// If the array contains elements that are not comparable to themselves,
+    /// like NaN, the index of the first of them is returned instead, like
+    /// `numpy.argmin`.
+    pub fn argmin(&self) -> Option<D::Pattern>
+    where
+        A: PartialOrd,
+    {
+        arg_extremum(self.indexed_iter(), |x, best| x < best)
+    }
+
+    /// Return the index of the first largest element of each lane along
+    /// `axis`.
+    ///
+    /// Elements that are not comparable to themselves, like NaN, are
+    /// handled like in [`.argmax()`](#method.argmax).
+    ///
+    /// ```
+    /// use ndarray::{arr1, arr2, Axis};
+    ///
+    /// let a = arr2(&[[1, 5, 2],
+    ///                [5, 0, 3]]);
+    /// assert_eq!(a.argmax_axis(Axis(0)), arr1(&[1, 0, 1]));
+    /// assert_eq!(a.argmax_axis(Axis(1)), arr1(&[1, 0]));
+    /// ```
+    ///
+    /// **Panics** if `axis` is out of bounds or has length zero.
+    pub fn argmax_axis(&self, axis: Axis) -> Array<usize, D::Smaller>
+    where
+        A: PartialOrd,
+        D: RemoveAxis,
+    {
+        assert_ne!(self.len_of(axis), 0, "ndarray: argmax of an empty axis");
+        self.map_axis(axis, |lane| {
+            arg_extremum(lane.iter().enumerate(), |x, best| x > best).unwrap()
+        })
+    }
+
+    /// Return the index of the first smallest element of each lane along
+    /// `axis`.
+    ///
+    /// Elements that are not comparable to themselves, like NaN, are
+    /// handled like in [`.argmin()`](#method.argmin).
+    ///
+    /// **Panics** if `axis` is out of bounds or has length zero.
+    pub fn argmin_axis(&self, axis: Axis) -> Array<usize, D::Smaller>
+    where
+        A: PartialOrd,
+        D: RemoveAxis,
+    {
+        assert_ne!(self.len_of(axis), 0, "ndarray: argmin of an empty axis");
+        self.map_axis(axis, |lane| {
+            arg_extremum(lane.iter().enumerate(), |x, best| x < best).unwrap()
+        })
+    }
+
+    /// Return an array with each element limited to the interval
+    /// [`min`, `max`].
+    ///
+    /// Elements less than `min` are replaced by `min`, and elements greater
+    /// than `max` by `max`. Elements that are not comparable to the bounds,
+    /// like NaN, are left unchanged, so NaN elements stay NaN.
+    ///
+    /// **Panics** if `min > max`, or if `min` or `max` is not comparable to
+    /// itself (like NaN).
+    ///
+    /// ```
+    /// use ndarray::{arr1, s};
+    /// use std::f64::NAN;
+    ///
+    /// let a = arr1(&[-2., 0.5, 3., NAN]);
+    /// let c = a.clamp(0., 1.);
+    /// assert_eq!(c.slice(s![..3]), arr1(&[0., 0.5, 1.]));
+    /// assert!(c[3].is_nan());
+    /// ```
+    pub fn clamp(&self, min: A, max: A) -> Array<A, D>
+    where
+        A: Clone + PartialOrd,
+    {
+        assert_clamp_bounds(&min, &max);
+        self.map(|x| clamp_elem(x, &min, &max).clone())
+    }
+
+    /// Limit each element to the interval [`min`, `max`], in place.
+    ///
+    /// See [`.clamp()`](#method.clamp) for details.
+    ///
+    /// **Panics** if `min > max`, or if `min` or `max` is not comparable to
+    /// itself (like NaN).
+    pub fn clamp_inplace(&mut self, min: A, max: A)
+    where
+        A: Clone + PartialOrd,
+        S: DataMut,
+    {
+        assert_clamp_bounds(&min, &max);
+        self.map_inplace(|x| {
+            if *x < min {
+                *x = min.clone();
+            } else if *x > max {
+                *x = max.clone();
+            }
+        });
+    }
+
     /// Return `true` if the arrays' elementwise differences are all within
     /// the given absolute tolerance, `false` otherwise.
     ///
@@ -334,3 +889,43 @@ where
             .is_done()
     }
 }
+
+/// Return the index of the first element for which `better` holds against
+/// all earlier elements, or of the first element not comparable to itself.
+fn arg_extremum<'a, I, T, A, F>(iter: I, better: F) -> Option<T>
+where
+    I: IntoIterator<Item = (T, &'a A)>,
+    A: PartialOrd + 'a,
+    F: Fn(&A, &A) -> bool,
+{
+    let mut best: Option<(T, &A)> = None;
+    for (index, x) in iter {
+        if x.partial_cmp(x).is_none() {
+            return Some(index);
+        }
+        if let Some((_, b)) = best {
+            if !better(x, b) {
+                continue;
+            }
+        }
+        best = Some((index, x));
+    }
+    best.map(|(index, _)| index)
+}
+
+fn clamp_elem<'a, A: PartialOrd>(x: &'a A, min: &'a A, max: &'a A) -> &'a A {
+    if x < min {
+        min
+    } else if x > max {
+        max
+    } else {
+        x
+    }
+}
+
+fn assert_clamp_bounds<A: PartialOrd>(min: &A, max: &A) {
+    assert!(
+        min <= max,
+        "ndarray: clamp requires min <= max and bounds that are not NaN"
+    );
+}
@@ -1 +1,4 @@
+mod impl_cast;
+mod impl_float_maths;
+mod impl_int_maths;
 mod impl_numeric;
@@ -0,0 +1,135 @@
+// Copyright 2014-2016 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::imp_prelude::*;
+use crate::IntoDimension;
+
+/// How [`.pad()`](struct.ArrayBase.html#method.pad) fills the padding.
+///
+/// The examples show how the one-dimensional array `[1, 2, 3]` is padded
+/// with four elements on each side.
+#[derive(Clone, Debug, PartialEq)]
+pub enum PadMode<A> {
+    /// Fill with the given value: `0 0 0 0 [1 2 3] 0 0 0 0`
+    Constant(A),
+    /// Repeat the edge element: `1 1 1 1 [1 2 3] 3 3 3 3`
+    Edge,
+    /// Reflect about the edge element, which is not repeated:
+    /// `1 2 3 2 [1 2 3] 2 1 2 3`
+    Reflect,
+    /// Wrap around to the other end: `3 1 2 3 [1 2 3] 1 2 3 1`
+    Wrap,
+}
+
+impl<A, S, D> ArrayBase<S, D>
+where
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    /// Return a padded copy of the array.
+    ///
+    /// `pad_width[k]` is the number of elements to add before and after the
+    /// array along axis `k`, and `mode` selects how they are filled; see
+    /// [`PadMode`](enum.PadMode.html).
+    ///
+    /// **Panics** if `pad_width` does not have one pair for each axis, if an
+    /// empty axis is padded with a mode other than `Constant`, or if the
+    /// number of elements in the result would overflow `isize`.
+    ///
+    /// ```
+    /// use ndarray::{arr1, arr2, PadMode};
+    ///
+    /// let a = arr2(&[[1, 2],
+    ///                [3, 4]]);
+    /// assert_eq!(a.pad(&[(1, 0), (0, 2)], PadMode::Constant(0)),
+    ///            arr2(&[[0, 0, 0, 0],
+    ///                   [1, 2, 0, 0],
+    ///                   [3, 4, 0, 0]]));
+    /// assert_eq!(a.pad(&[(0, 1), (1, 1)], PadMode::Edge),
+    ///            arr2(&[[1, 1, 2, 2],
+    ///                   [3, 3, 4, 4],
+    ///                   [3, 3, 4, 4]]));
+    ///
+    /// let v = arr1(&[1, 2, 3]);
+    /// assert_eq!(v.pad(&[(4, 4)], PadMode::Reflect), arr1(&[1, 2, 3, 2, 1, 2, 3, 2, 1, 2, 3]));
+    /// assert_eq!(v.pad(&[(4, 4)], PadMode::Wrap), arr1(&[3, 1, 2, 3, 1, 2, 3, 1, 2, 3, 1]));
+    /// ```
+    pub fn pad(&self, pad_width: &[(usize, usize)], mode: PadMode<A>) -> Array<A, D>
+    where
+        A: Clone,
+    {
+        assert_eq!(
+            pad_width.len(),
+            self.ndim(),
+            "ndarray: pad_width must have one pair for each axis"
+        );
+        // For each axis, the index into `self` of each index of the result,
+        // or `None` for the constant.
+        let maps: Vec<Vec<Option<usize>>> = pad_width
+            .iter()
+            .zip(self.shape())
+            .map(|(&(before, after), &len)| {
+                let total = before
+                    .checked_add(len)
+                    .and_then(|n| n.checked_add(after))
+                    .expect("ndarray: padded shape overflows usize");
+                (0..total)
+                    .map(|i| source_index(i as isize - before as isize, len, &mode))
+                    .collect()
+            })
+            .collect();
+        let mut dim = self.raw_dim();
+        for (k, map) in maps.iter().enumerate() {
+            dim[k] = map.len();
+        }
+
+        let mut source = self.raw_dim();
+        Array::from_shape_fn(dim, |index| {
+            let index = index.into_dimension();
+            for (k, map) in maps.iter().enumerate() {
+                match map[index[k]] {
+                    Some(i) => source[k] = i,
+                    None => match mode {
+                        PadMode::Constant(ref value) => return value.clone(),
+                        _ => unreachable!(),
+                    },
+                }
+            }
+            self[source.clone()].clone()
+        })
+    }
+}
+
+/// Return the index into an axis of length `len` that fills position `i`
+/// relative to the start of that axis, or `None` if it is filled with the
+/// constant.
+fn source_index<A>(i: isize, len: usize, mode: &PadMode<A>) -> Option<usize> {
+    let len_i = len as isize;
+    if 0 <= i && i < len_i {
+        return Some(i as usize);
+    }
+    match *mode {
+        PadMode::Constant(_) => None,
+        _ if len == 0 => panic!("ndarray: can only pad an empty axis with a constant"),
+        PadMode::Edge => Some(if i < 0 { 0 } else { len - 1 }),
+        PadMode::Wrap => Some(modulo(i, len_i) as usize),
+        PadMode::Reflect => {
+            if len == 1 {
+                return Some(0);
+            }
+            let period = 2 * (len_i - 1);
+            let j = modulo(i, period);
+            Some(if j < len_i { j } else { period - j } as usize)
+        }
+    }
+}
+
+/// Return `i` modulo `n`, in `0..n`.
+fn modulo(i: isize, n: isize) -> isize {
+    ((i % n) + n) % n
+}
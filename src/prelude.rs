@@ -48,6 +48,9 @@ pub use crate::{arr0, arr1, arr2, aview0, aview1, aview2, aview_mut1};
 
 pub use crate::{array, azip, s};
 
+#[doc(no_inline)]
+pub use crate::{Ellipsis, NewAxis};
+
 #[doc(no_inline)]
 pub use crate::ShapeBuilder;
 
@@ -7,8 +7,9 @@
 // except according to those terms.
 use crate::dimension::slices_intersect;
 use crate::error::{ErrorKind, ShapeError};
-use crate::{ArrayViewMut, Dimension};
+use crate::{ArrayViewMut, Dimension, Ix0, Ix1, Ix2, Ix3, Ix4, Ix5, Ix6, IxDyn};
 use std::fmt;
+use std::iter::{self, FromIterator};
 use std::marker::PhantomData;
 use std::ops::{Deref, Range, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive};
 
@@ -67,10 +68,20 @@ impl Slice {
     }
 }
 
-/// A slice (range with step) or an index.
+/// Token to insert a new axis of length 1 with the [`s![]`](macro.s!.html)
+/// macro, for example `s![.., NewAxis]`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct NewAxis;
+
+/// Token to elide any number of axes with the [`s![]`](macro.s!.html) macro,
+/// for example `s![Ellipsis, 0]`. The elided axes are sliced with `..`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Ellipsis;
+
+/// A slice (range with step), an index, a new axis or an ellipsis.
 ///
 /// See also the [`s![]`](macro.s!.html) macro for a convenient way to create a
-/// `&SliceInfo<[SliceOrIndex; n], D>`.
+/// `&SliceInfo<[SliceOrIndex; n], Din, Dout>`.
 ///
 /// ## Examples
 ///
@@ -91,6 +102,15 @@ impl Slice {
 /// from `a` until the end, in reverse order. It can also be created with
 /// `SliceOrIndex::from(a..).step_by(-1)`. The Python equivalent is `[a::-1]`.
 /// The macro equivalent is `s![a..;-1]`.
+///
+/// `SliceOrIndex::NewAxis` inserts a new axis of length 1. It can also be
+/// created with `SliceOrIndex::from(NewAxis)`. The Python equivalent is
+/// `[np.newaxis]`. The macro equivalent is `s![NewAxis]`.
+///
+/// `SliceOrIndex::Ellipsis` stands for as many full slices `..` as needed to
+/// match the number of axes of the array. It can also be created with
+/// `SliceOrIndex::from(Ellipsis)`. The Python equivalent is `[...]`. The
+/// macro equivalent is `s![Ellipsis]`.
 #[derive(Debug, PartialEq, Eq, Hash)]
 pub enum SliceOrIndex {
     /// A range with step size. `end` is an exclusive index. Negative `begin`
@@ -103,6 +123,11 @@ pub enum SliceOrIndex {
     },
     /// A single index.
     Index(isize),
+    /// A new axis of length 1.
+    NewAxis,
+    /// Full slices of all axes that are not otherwise sliced or indexed.
+    /// There can be at most one `Ellipsis`.
+    Ellipsis,
 }
 
 copy_and_clone! {SliceOrIndex}
@@ -124,6 +149,22 @@ impl SliceOrIndex {
         }
     }
 
+    /// Returns `true` if `self` is a `NewAxis` value.
+    pub fn is_new_axis(&self) -> bool {
+        match self {
+            SliceOrIndex::NewAxis => true,
+            _ => false,
+        }
+    }
+
+    /// Returns `true` if `self` is an `Ellipsis` value.
+    pub fn is_ellipsis(&self) -> bool {
+        match self {
+            SliceOrIndex::Ellipsis => true,
+            _ => false,
+        }
+    }
+
     /// Returns a new `SliceOrIndex` with the given step size (multiplied with
     /// the previous step size).
     ///
@@ -143,6 +184,8 @@ impl SliceOrIndex {
                 step: orig_step * step,
             },
             SliceOrIndex::Index(s) => SliceOrIndex::Index(s),
+            SliceOrIndex::NewAxis => SliceOrIndex::NewAxis,
+            SliceOrIndex::Ellipsis => SliceOrIndex::Ellipsis,
         }
     }
 }
@@ -163,6 +206,8 @@ impl fmt::Display for SliceOrIndex {
                     write!(f, ";{}", step)?;
                 }
             }
+            SliceOrIndex::NewAxis => write!(f, "NewAxis")?,
+            SliceOrIndex::Ellipsis => write!(f, "Ellipsis")?,
         }
         Ok(())
     }
@@ -282,23 +327,80 @@ impl_sliceorindex_from_index!(isize);
 impl_sliceorindex_from_index!(usize);
 impl_sliceorindex_from_index!(i32);
 
+impl From<NewAxis> for SliceOrIndex {
+    #[inline]
+    fn from(_: NewAxis) -> SliceOrIndex {
+        SliceOrIndex::NewAxis
+    }
+}
+
+impl From<Ellipsis> for SliceOrIndex {
+    #[inline]
+    fn from(_: Ellipsis) -> SliceOrIndex {
+        SliceOrIndex::Ellipsis
+    }
+}
+
+/// Return an iterator over `indices` for slicing an array with `ndim` axes,
+/// where an `Ellipsis` is replaced by full slices of the elided axes.
+///
+/// **Panics** if `indices` contains more than one `Ellipsis`, or if the
+/// number of slices and indices does not match `ndim`.
+pub(crate) fn expand_ellipsis(
+    indices: &[SliceOrIndex],
+    ndim: usize,
+) -> impl Iterator<Item = SliceOrIndex> + '_ {
+    let n_in = indices
+        .iter()
+        .filter(|s| s.is_slice() || s.is_index())
+        .count();
+    let n_elided = match indices.iter().filter(|s| s.is_ellipsis()).count() {
+        0 => {
+            assert_eq!(
+                n_in, ndim,
+                "ndarray: slice info does not match the number of array axes"
+            );
+            0
+        }
+        1 => {
+            assert!(
+                n_in <= ndim,
+                "ndarray: slice info has more axes than the array"
+            );
+            ndim - n_in
+        }
+        _ => panic!("ndarray: slice info can contain at most one Ellipsis"),
+    };
+    indices.iter().flat_map(move |&s| match s {
+        SliceOrIndex::Ellipsis => iter::repeat(SliceOrIndex::from(..)).take(n_elided),
+        _ => iter::repeat(s).take(1),
+    })
+}
+
 /// Represents all of the necessary information to perform a slice.
 ///
 /// The type `T` is typically `[SliceOrIndex; n]`, `[SliceOrIndex]`, or
-/// `Vec<SliceOrIndex>`. The type `D` is the output dimension after calling
+/// `Vec<SliceOrIndex>`. The type `Din` is the dimension of the arrays that
+/// can be sliced, and `Dout` is the output dimension after calling
 /// [`.slice()`].
 ///
+/// An `IxDyn` input dimension can slice arrays of any dimension, and a fixed
+/// input dimension can also slice `IxDyn` arrays; in those cases the number of
+/// axes is checked when slicing.
+///
 /// [`.slice()`]: struct.ArrayBase.html#method.slice
 #[derive(Debug)]
 #[repr(C)]
-pub struct SliceInfo<T: ?Sized, D: Dimension> {
-    out_dim: PhantomData<D>,
+pub struct SliceInfo<T: ?Sized, Din: Dimension, Dout: Dimension> {
+    in_dim: PhantomData<Din>,
+    out_dim: PhantomData<Dout>,
     indices: T,
 }
 
-impl<T: ?Sized, D> Deref for SliceInfo<T, D>
+impl<T: ?Sized, Din, Dout> Deref for SliceInfo<T, Din, Dout>
 where
-    D: Dimension,
+    Din: Dimension,
+    Dout: Dimension,
 {
     type Target = T;
     fn deref(&self) -> &Self::Target {
@@ -306,145 +408,266 @@ where
     }
 }
 
-impl<T, D> SliceInfo<T, D>
+impl<T, Din, Dout> SliceInfo<T, Din, Dout>
 where
-    D: Dimension,
+    Din: Dimension,
+    Dout: Dimension,
 {
     /// Returns a new `SliceInfo` instance.
     ///
-    /// If you call this method, you are guaranteeing that `out_dim` is
-    /// consistent with `indices`.
+    /// If you call this method, you are guaranteeing that `in_dim` and
+    /// `out_dim` are consistent with `indices`.
     #[doc(hidden)]
-    pub unsafe fn new_unchecked(indices: T, out_dim: PhantomData<D>) -> SliceInfo<T, D> {
-        SliceInfo { out_dim, indices }
+    pub unsafe fn new_unchecked(
+        indices: T,
+        in_dim: PhantomData<Din>,
+        out_dim: PhantomData<Dout>,
+    ) -> SliceInfo<T, Din, Dout> {
+        SliceInfo {
+            in_dim,
+            out_dim,
+            indices,
+        }
     }
 }
 
-impl<T, D> SliceInfo<T, D>
+impl<T, Din, Dout> SliceInfo<T, Din, Dout>
 where
     T: AsRef<[SliceOrIndex]>,
-    D: Dimension,
+    Din: Dimension,
+    Dout: Dimension,
 {
     /// Returns a new `SliceInfo` instance.
     ///
-    /// Errors if `D` is not consistent with `indices`.
-    pub fn new(indices: T) -> Result<SliceInfo<T, D>, ShapeError> {
-        if let Some(ndim) = D::NDIM {
-            if ndim != indices.as_ref().iter().filter(|s| s.is_slice()).count() {
-                return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape));
-            }
+    /// Errors if `Din` or `Dout` is not consistent with `indices`, or if
+    /// `indices` contains more than one `Ellipsis`. An `Ellipsis` requires
+    /// `Dout` to be `IxDyn` unless `Din` is a fixed dimension.
+    pub fn new(indices: T) -> Result<SliceInfo<T, Din, Dout>, ShapeError> {
+        let elems = indices.as_ref();
+        let n_in = elems
+            .iter()
+            .filter(|s| s.is_slice() || s.is_index())
+            .count();
+        let n_out = elems
+            .iter()
+            .filter(|s| s.is_slice() || s.is_new_axis())
+            .count();
+        let ellipsis = match elems.iter().filter(|s| s.is_ellipsis()).count() {
+            0 => false,
+            1 => true,
+            _ => return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape)),
+        };
+        let elided = match Din::NDIM {
+            Some(ndim) if n_in == ndim || (ellipsis && n_in < ndim) => Some(ndim - n_in),
+            Some(_) => return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape)),
+            None if ellipsis => None,
+            None => Some(0),
+        };
+        match (Dout::NDIM, elided) {
+            (None, _) => {}
+            (Some(ndim), Some(elided)) if ndim == n_out + elided => {}
+            _ => return Err(ShapeError::from_kind(ErrorKind::IncompatibleShape)),
         }
         Ok(SliceInfo {
+            in_dim: PhantomData,
             out_dim: PhantomData,
             indices,
         })
     }
 }
 
-impl<T: ?Sized, D> SliceInfo<T, D>
+/// Collect a `SliceInfo` at runtime, for example from user input or from a
+/// loop over the axes. The result slices `IxDyn` arrays and any array
+/// through a dynamic view.
+///
+/// ```
+/// use ndarray::{s, Array, IxDyn, NewAxis, SliceInfo, SliceOrIndex};
+///
+/// let a = Array::from_shape_fn((2, 3, 4), |(i, j, k)| 100 * i + 10 * j + k).into_dyn();
+///
+/// // Reverse the odd axes and take index 1 of the others.
+/// let info: SliceInfo<_, IxDyn, IxDyn> = (0..a.ndim())
+///     .map(|k| {
+///         if k % 2 == 1 {
+///             SliceOrIndex::from(..).step_by(-1)
+///         } else {
+///             SliceOrIndex::from(1)
+///         }
+///     })
+///     .collect();
+/// assert_eq!(a.slice(&info), a.slice(s![1, ..;-1, 1]).into_dyn());
+///
+/// let info: SliceInfo<_, IxDyn, IxDyn> = Some(SliceOrIndex::from(NewAxis))
+///     .into_iter()
+///     .chain(a.shape().iter().map(|_| SliceOrIndex::from(..)))
+///     .collect();
+/// assert_eq!(a.slice(&info).shape(), &[1, 2, 3, 4]);
+/// ```
+impl<E> FromIterator<E> for SliceInfo<Vec<SliceOrIndex>, IxDyn, IxDyn>
+where
+    E: Into<SliceOrIndex>,
+{
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = E>,
+    {
+        SliceInfo {
+            in_dim: PhantomData,
+            out_dim: PhantomData,
+            indices: iter.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl<T: ?Sized, Din, Dout> SliceInfo<T, Din, Dout>
 where
     T: AsRef<[SliceOrIndex]>,
-    D: Dimension,
+    Din: Dimension,
+    Dout: Dimension,
 {
+    /// Returns the number of dimensions of the arrays that can be sliced.
+    ///
+    /// If `Din` is a fixed-size dimension type, then this is equivalent to
+    /// `Din::NDIM.unwrap()`. Otherwise, the value is calculated by iterating
+    /// over the ranges/indices, not counting axes elided by an `Ellipsis`.
+    pub fn in_ndim(&self) -> usize {
+        Din::NDIM.unwrap_or_else(|| {
+            self.indices
+                .as_ref()
+                .iter()
+                .filter(|s| s.is_slice() || s.is_index())
+                .count()
+        })
+    }
+
     /// Returns the number of dimensions after calling
     /// [`.slice()`](struct.ArrayBase.html#method.slice) (including taking
     /// subviews).
     ///
-    /// If `D` is a fixed-size dimension type, then this is equivalent to
-    /// `D::NDIM.unwrap()`. Otherwise, the value is calculated by iterating
-    /// over the ranges/indices.
+    /// If `Dout` is a fixed-size dimension type, then this is equivalent to
+    /// `Dout::NDIM.unwrap()`. Otherwise, the value is calculated by iterating
+    /// over the ranges/indices/new axes, not counting axes elided by an
+    /// `Ellipsis`.
     pub fn out_ndim(&self) -> usize {
-        D::NDIM.unwrap_or_else(|| {
+        Dout::NDIM.unwrap_or_else(|| {
             self.indices
                 .as_ref()
                 .iter()
-                .filter(|s| s.is_slice())
+                .filter(|s| s.is_slice() || s.is_new_axis())
                 .count()
         })
     }
 }
 
-impl<T, D> AsRef<[SliceOrIndex]> for SliceInfo<T, D>
+impl<T: ?Sized, Din, Dout> AsRef<[SliceOrIndex]> for SliceInfo<T, Din, Dout>
 where
     T: AsRef<[SliceOrIndex]>,
-    D: Dimension,
+    Din: Dimension,
+    Dout: Dimension,
 {
     fn as_ref(&self) -> &[SliceOrIndex] {
         self.indices.as_ref()
     }
 }
 
-impl<T, D> AsRef<SliceInfo<[SliceOrIndex], D>> for SliceInfo<T, D>
+impl<T, Din, Dout> AsRef<SliceInfo<[SliceOrIndex], Din, Dout>> for SliceInfo<T, Din, Dout>
 where
     T: AsRef<[SliceOrIndex]>,
-    D: Dimension,
+    Din: Dimension,
+    Dout: Dimension,
 {
-    fn as_ref(&self) -> &SliceInfo<[SliceOrIndex], D> {
+    fn as_ref(&self) -> &SliceInfo<[SliceOrIndex], Din, Dout> {
         unsafe {
             // This is okay because the only non-zero-sized member of
-            // `SliceInfo` is `indices`, so `&SliceInfo<[SliceOrIndex], D>`
+            // `SliceInfo` is `indices`, so `&SliceInfo<[SliceOrIndex], Din, Dout>`
             // should have the same bitwise representation as
             // `&[SliceOrIndex]`.
             &*(self.indices.as_ref() as *const [SliceOrIndex]
-                as *const SliceInfo<[SliceOrIndex], D>)
+                as *const SliceInfo<[SliceOrIndex], Din, Dout>)
         }
     }
 }
 
-impl<T, D> Copy for SliceInfo<T, D>
+impl<T, Din, Dout> Copy for SliceInfo<T, Din, Dout>
 where
     T: Copy,
-    D: Dimension,
+    Din: Dimension,
+    Dout: Dimension,
 {
 }
 
-impl<T, D> Clone for SliceInfo<T, D>
+impl<T, Din, Dout> Clone for SliceInfo<T, Din, Dout>
 where
     T: Clone,
-    D: Dimension,
+    Din: Dimension,
+    Dout: Dimension,
 {
     fn clone(&self) -> Self {
         SliceInfo {
+            in_dim: PhantomData,
             out_dim: PhantomData,
             indices: self.indices.clone(),
         }
     }
 }
 
+/// The input dimensions `Din` of a [`SliceInfo`] that can slice arrays of
+/// dimension `D`: `D` itself, `IxDyn` for a fixed `D`, and any fixed
+/// dimension for `IxDyn` arrays.
+///
+/// [`SliceInfo`]: struct.SliceInfo.html
+#[doc(hidden)]
+pub trait SliceInDim<D: Dimension>: Dimension {}
+
+impl<D: Dimension> SliceInDim<D> for D {}
+
+macro_rules! impl_sliceindim_dyn {
+    ($($d:ty),*) => {
+        $(
+            impl SliceInDim<IxDyn> for $d {}
+            impl SliceInDim<$d> for IxDyn {}
+        )*
+    };
+}
+impl_sliceindim_dyn!(Ix0, Ix1, Ix2, Ix3, Ix4, Ix5, Ix6);
+
 #[doc(hidden)]
 pub trait SliceNextDim<D1, D2> {
     fn next_dim(&self, _: PhantomData<D1>) -> PhantomData<D2>;
 }
 
-macro_rules! impl_slicenextdim_equal {
-    ($self:ty) => {
-        impl<D1: Dimension> SliceNextDim<D1, D1> for $self {
-            fn next_dim(&self, _: PhantomData<D1>) -> PhantomData<D1> {
+#[doc(hidden)]
+pub trait SliceNextInDim<D1, D2> {
+    fn next_in_dim(&self, _: PhantomData<D1>) -> PhantomData<D2>;
+}
+
+macro_rules! impl_slicenextdim {
+    (($($generics:tt)*), $self:ty, $in_dim:ty, $out_dim:ty) => {
+        impl<D1: Dimension, $($generics)*> SliceNextInDim<D1, $in_dim> for $self {
+            fn next_in_dim(&self, _: PhantomData<D1>) -> PhantomData<$in_dim> {
                 PhantomData
             }
         }
-    };
-}
-impl_slicenextdim_equal!(isize);
-impl_slicenextdim_equal!(usize);
-impl_slicenextdim_equal!(i32);
 
-macro_rules! impl_slicenextdim_larger {
-    (($($generics:tt)*), $self:ty) => {
-        impl<D1: Dimension, $($generics)*> SliceNextDim<D1, D1::Larger> for $self {
-            fn next_dim(&self, _: PhantomData<D1>) -> PhantomData<D1::Larger> {
+        impl<D1: Dimension, $($generics)*> SliceNextDim<D1, $out_dim> for $self {
+            fn next_dim(&self, _: PhantomData<D1>) -> PhantomData<$out_dim> {
                 PhantomData
             }
         }
-    }
+    };
 }
-impl_slicenextdim_larger!((T), Range<T>);
-impl_slicenextdim_larger!((T), RangeInclusive<T>);
-impl_slicenextdim_larger!((T), RangeFrom<T>);
-impl_slicenextdim_larger!((T), RangeTo<T>);
-impl_slicenextdim_larger!((T), RangeToInclusive<T>);
-impl_slicenextdim_larger!((), RangeFull);
-impl_slicenextdim_larger!((), Slice);
+impl_slicenextdim!((), isize, D1::Larger, D1);
+impl_slicenextdim!((), usize, D1::Larger, D1);
+impl_slicenextdim!((), i32, D1::Larger, D1);
+impl_slicenextdim!((T), Range<T>, D1::Larger, D1::Larger);
+impl_slicenextdim!((T), RangeInclusive<T>, D1::Larger, D1::Larger);
+impl_slicenextdim!((T), RangeFrom<T>, D1::Larger, D1::Larger);
+impl_slicenextdim!((T), RangeTo<T>, D1::Larger, D1::Larger);
+impl_slicenextdim!((T), RangeToInclusive<T>, D1::Larger, D1::Larger);
+impl_slicenextdim!((), RangeFull, D1::Larger, D1::Larger);
+impl_slicenextdim!((), Slice, D1::Larger, D1::Larger);
+impl_slicenextdim!((), NewAxis, D1, D1::Larger);
+impl_slicenextdim!((), Ellipsis, IxDyn, IxDyn);
 
 /// Slice argument constructor.
 ///
@@ -469,11 +692,20 @@ impl_slicenextdim_larger!((), Slice);
 /// * *slice*: a [`Slice`] instance to use for slicing that axis.
 /// * *slice* `;` *step*: a range constructed from the start and end of a [`Slice`]
 ///   instance, with new step size *step*, to use for slicing that axis.
+/// * [`NewAxis`]: insert a new axis of length 1 at this position in the
+///   result. (Not supported by [`.slice_collapse()`].)
+/// * [`Ellipsis`]: as many full ranges `..` as needed to cover the axes that
+///   are not otherwise sliced or indexed. At most one `Ellipsis` may be used,
+///   and since the number of axes it covers is only known when slicing, the
+///   result has dimension `IxDyn`.
 ///
 /// [`Slice`]: struct.Slice.html
+/// [`NewAxis`]: struct.NewAxis.html
+/// [`Ellipsis`]: struct.Ellipsis.html
 ///
-/// The number of *axis-slice-or-index* must match the number of axes in the
-/// array. *index*, *range*, *slice*, and *step* can be expressions. *index*
+/// The number of *axis-slice-or-index* that are not `NewAxis` must match the
+/// number of axes in the array, unless there is an `Ellipsis`. *index*,
+/// *range*, *slice*, and *step* can be expressions. *index*
 /// must be of type `isize`, `usize`, or `i32`. *range* must be of type
 /// `Range<I>`, `RangeTo<I>`, `RangeFrom<I>`, or `RangeFull` where `I` is
 /// `isize`, `usize`, or `i32`. *step* must be a type that can be converted to
@@ -508,6 +740,20 @@ impl_slicenextdim_larger!((), Slice);
 /// # fn main() { }
 /// ```
 ///
+/// # `NewAxis` and `Ellipsis`
+///
+/// ```
+/// use ndarray::{s, Array, Ellipsis, NewAxis};
+///
+/// let a = Array::from_shape_fn((2, 3, 4), |(i, j, k)| 100 * i + 10 * j + k);
+/// assert_eq!(a.slice(s![.., NewAxis, 1.., ..]).shape(), &[2, 1, 2, 4]);
+/// assert_eq!(a.slice(s![NewAxis, .., 0, NewAxis, ..]).shape(), &[1, 2, 1, 4]);
+///
+/// // Index the last axis, whatever the number of axes before it.
+/// assert_eq!(a.slice(s![Ellipsis, 0]), a.slice(s![.., .., 0]).into_dyn());
+/// assert_eq!(a.slice(s![1, Ellipsis, 2]), a.slice(s![1, .., 2]).into_dyn());
+/// ```
+///
 /// # Negative *step*
 ///
 /// The behavior of negative *step* arguments is most easily understood with
@@ -540,14 +786,16 @@ impl_slicenextdim_larger!((), Slice);
 #[macro_export]
 macro_rules! s(
     // convert a..b;c into @convert(a..b, c), final item
-    (@parse $dim:expr, [$($stack:tt)*] $r:expr;$s:expr) => {
+    (@parse $in_dim:expr, $out_dim:expr, [$($stack:tt)*] $r:expr;$s:expr) => {
         match $r {
             r => {
-                let out_dim = $crate::SliceNextDim::next_dim(&r, $dim);
+                let in_dim = $crate::SliceNextInDim::next_in_dim(&r, $in_dim);
+                let out_dim = $crate::SliceNextDim::next_dim(&r, $out_dim);
                 #[allow(unsafe_code)]
                 unsafe {
                     $crate::SliceInfo::new_unchecked(
                         [$($stack)* $crate::s!(@convert r, $s)],
+                        in_dim,
                         out_dim,
                     )
                 }
@@ -555,14 +803,16 @@ macro_rules! s(
         }
     };
     // convert a..b into @convert(a..b), final item
-    (@parse $dim:expr, [$($stack:tt)*] $r:expr) => {
+    (@parse $in_dim:expr, $out_dim:expr, [$($stack:tt)*] $r:expr) => {
         match $r {
             r => {
-                let out_dim = $crate::SliceNextDim::next_dim(&r, $dim);
+                let in_dim = $crate::SliceNextInDim::next_in_dim(&r, $in_dim);
+                let out_dim = $crate::SliceNextDim::next_dim(&r, $out_dim);
                 #[allow(unsafe_code)]
                 unsafe {
                     $crate::SliceInfo::new_unchecked(
                         [$($stack)* $crate::s!(@convert r)],
+                        in_dim,
                         out_dim,
                     )
                 }
@@ -570,19 +820,20 @@ macro_rules! s(
         }
     };
     // convert a..b;c into @convert(a..b, c), final item, trailing comma
-    (@parse $dim:expr, [$($stack:tt)*] $r:expr;$s:expr ,) => {
-        $crate::s![@parse $dim, [$($stack)*] $r;$s]
+    (@parse $in_dim:expr, $out_dim:expr, [$($stack:tt)*] $r:expr;$s:expr ,) => {
+        $crate::s![@parse $in_dim, $out_dim, [$($stack)*] $r;$s]
     };
     // convert a..b into @convert(a..b), final item, trailing comma
-    (@parse $dim:expr, [$($stack:tt)*] $r:expr ,) => {
-        $crate::s![@parse $dim, [$($stack)*] $r]
+    (@parse $in_dim:expr, $out_dim:expr, [$($stack:tt)*] $r:expr ,) => {
+        $crate::s![@parse $in_dim, $out_dim, [$($stack)*] $r]
     };
     // convert a..b;c into @convert(a..b, c)
-    (@parse $dim:expr, [$($stack:tt)*] $r:expr;$s:expr, $($t:tt)*) => {
+    (@parse $in_dim:expr, $out_dim:expr, [$($stack:tt)*] $r:expr;$s:expr, $($t:tt)*) => {
         match $r {
             r => {
                 $crate::s![@parse
-                   $crate::SliceNextDim::next_dim(&r, $dim),
+                   $crate::SliceNextInDim::next_in_dim(&r, $in_dim),
+                   $crate::SliceNextDim::next_dim(&r, $out_dim),
                    [$($stack)* $crate::s!(@convert r, $s),]
                    $($t)*
                 ]
@@ -590,11 +841,12 @@ macro_rules! s(
         }
     };
     // convert a..b into @convert(a..b)
-    (@parse $dim:expr, [$($stack:tt)*] $r:expr, $($t:tt)*) => {
+    (@parse $in_dim:expr, $out_dim:expr, [$($stack:tt)*] $r:expr, $($t:tt)*) => {
         match $r {
             r => {
                 $crate::s![@parse
-                   $crate::SliceNextDim::next_dim(&r, $dim),
+                   $crate::SliceNextInDim::next_in_dim(&r, $in_dim),
+                   $crate::SliceNextDim::next_dim(&r, $out_dim),
                    [$($stack)* $crate::s!(@convert r),]
                    $($t)*
                 ]
@@ -602,11 +854,15 @@ macro_rules! s(
         }
     };
     // empty call, i.e. `s![]`
-    (@parse ::std::marker::PhantomData::<$crate::Ix0>, []) => {
+    (@parse ::std::marker::PhantomData::<$crate::Ix0>, ::std::marker::PhantomData::<$crate::Ix0>, []) => {
         {
             #[allow(unsafe_code)]
             unsafe {
-                $crate::SliceInfo::new_unchecked([], ::std::marker::PhantomData::<$crate::Ix0>)
+                $crate::SliceInfo::new_unchecked(
+                    [],
+                    ::std::marker::PhantomData::<$crate::Ix0>,
+                    ::std::marker::PhantomData::<$crate::Ix0>,
+                )
             }
         }
     };
@@ -623,7 +879,12 @@ macro_rules! s(
     ($($t:tt)*) => {
         // The extra `*&` is a workaround for this compiler bug:
         // https://github.com/rust-lang/rust/issues/23014
-        &*&$crate::s![@parse ::std::marker::PhantomData::<$crate::Ix0>, [] $($t)*]
+        &*&$crate::s![@parse
+            ::std::marker::PhantomData::<$crate::Ix0>,
+            ::std::marker::PhantomData::<$crate::Ix0>,
+            []
+            $($t)*
+        ]
     };
 );
 
@@ -656,10 +917,12 @@ where
     fn multi_slice_move(&self, _view: ArrayViewMut<'a, A, D>) -> Self::Output {}
 }
 
-impl<'a, A, D, Do0> MultiSlice<'a, A, D> for (&SliceInfo<D::SliceArg, Do0>,)
+impl<'a, A, D, T0, Di0, Do0> MultiSlice<'a, A, D> for (&SliceInfo<T0, Di0, Do0>,)
 where
     A: 'a,
     D: Dimension,
+    T0: AsRef<[SliceOrIndex]> + ?Sized,
+    Di0: SliceInDim<D>,
     Do0: Dimension,
 {
     type Output = (ArrayViewMut<'a, A, Do0>,);
@@ -670,15 +933,19 @@ where
 }
 
 macro_rules! impl_multislice_tuple {
-    ([$($but_last:ident)*] $last:ident) => {
-        impl_multislice_tuple!(@def_impl ($($but_last,)* $last,), [$($but_last)*] $last);
+    ([$($but_last:ident: $but_last_t:ident $but_last_in:ident)*] $last:ident: $last_t:ident $last_in:ident) => {
+        impl_multislice_tuple!(
+            @def_impl ($($but_last: $but_last_t $but_last_in,)* $last: $last_t $last_in,),
+            [$($but_last)*] $last
+        );
     };
-    (@def_impl ($($all:ident,)*), [$($but_last:ident)*] $last:ident) => {
-        impl<'a, A, D, $($all,)*> MultiSlice<'a, A, D> for ($(&SliceInfo<D::SliceArg, $all>,)*)
+    (@def_impl ($($all:ident: $all_t:ident $all_in:ident,)*), [$($but_last:ident)*] $last:ident) => {
+        impl<'a, A, D, $($all_t, $all_in, $all,)*> MultiSlice<'a, A, D>
+            for ($(&SliceInfo<$all_t, $all_in, $all>,)*)
         where
             A: 'a,
             D: Dimension,
-            $($all: Dimension,)*
+            $($all_t: AsRef<[SliceOrIndex]> + ?Sized, $all_in: SliceInDim<D>, $all: Dimension,)*
         {
             type Output = ($(ArrayViewMut<'a, A, $all>,)*);
 
@@ -708,11 +975,11 @@ macro_rules! impl_multislice_tuple {
     };
 }
 
-impl_multislice_tuple!([Do0] Do1);
-impl_multislice_tuple!([Do0 Do1] Do2);
-impl_multislice_tuple!([Do0 Do1 Do2] Do3);
-impl_multislice_tuple!([Do0 Do1 Do2 Do3] Do4);
-impl_multislice_tuple!([Do0 Do1 Do2 Do3 Do4] Do5);
+impl_multislice_tuple!([Do0: T0 Di0] Do1: T1 Di1);
+impl_multislice_tuple!([Do0: T0 Di0 Do1: T1 Di1] Do2: T2 Di2);
+impl_multislice_tuple!([Do0: T0 Di0 Do1: T1 Di1 Do2: T2 Di2] Do3: T3 Di3);
+impl_multislice_tuple!([Do0: T0 Di0 Do1: T1 Di1 Do2: T2 Di2 Do3: T3 Di3] Do4: T4 Di4);
+impl_multislice_tuple!([Do0: T0 Di0 Do1: T1 Di1 Do2: T2 Di2 Do3: T3 Di3 Do4: T4 Di4] Do5: T5 Di5);
 
 impl<'a, A, D, T> MultiSlice<'a, A, D> for &T
 where
@@ -0,0 +1,372 @@
+// Copyright 2014-2016 bluss and ndarray developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use std::cmp::Ordering;
+
+use crate::imp_prelude::*;
+use crate::{Slice, Zip};
+
+/// Which insertion index [`.searchsorted()`](struct.ArrayBase.html#method.searchsorted)
+/// returns for a value that is equal to elements of the array.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SearchSide {
+    /// The index of the first equal element.
+    Left,
+    /// The index after the last equal element.
+    Right,
+}
+
+/// # Sorting Methods
+impl<A, S, D> ArrayBase<S, D>
+where
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    /// Return the indices that sort each lane along `axis`.
+    ///
+    /// Each lane of the result is the permutation of `0..n` that puts the
+    /// corresponding lane of `self` in ascending order. The sort is stable,
+    /// so equal elements keep their order. Elements that are not comparable
+    /// to themselves, like NaN, are sorted to the end, like `numpy.argsort`.
+    ///
+    /// ```
+    /// use ndarray::{arr2, Axis};
+    ///
+    /// let a = arr2(&[[3, 1, 2],
+    ///                [0, 5, 0]]);
+    /// assert_eq!(a.argsort_axis(Axis(1)), arr2(&[[1, 2, 0],
+    ///                                            [0, 2, 1]]));
+    /// assert_eq!(a.argsort_axis(Axis(0)), arr2(&[[1, 0, 1],
+    ///                                            [0, 1, 0]]));
+    /// ```
+    ///
+    /// **Panics** if `axis` is out of bounds.
+    pub fn argsort_axis(&self, axis: Axis) -> Array<usize, D>
+    where
+        A: PartialOrd,
+    {
+        let n = self.len_of(axis);
+        let mut res = Array::zeros(self.raw_dim());
+        let mut perm = Vec::with_capacity(n);
+        Zip::from(self.lanes(axis))
+            .and(res.lanes_mut(axis))
+            .apply(|lane, mut out| {
+                perm.clear();
+                perm.extend(0..n);
+                perm.sort_by(|&i, &j| nan_last_cmp(&lane[i], &lane[j]));
+                for (out, &i) in out.iter_mut().zip(&perm) {
+                    *out = i;
+                }
+            });
+        res
+    }
+
+    /// Return a copy of the array with the subviews along `axis` reordered
+    /// by `perm`: subview `i` of the result is subview `perm[i]` of `self`.
+    ///
+    /// Together with [`.argsort_axis()`](#method.argsort_axis) of a
+    /// one-dimensional key, this sorts the array by that key.
+    ///
+    /// ```
+    /// use ndarray::{arr1, arr2, Axis};
+    ///
+    /// let a = arr2(&[[1, 2],
+    ///                [3, 4],
+    ///                [5, 6]]);
+    /// let key = arr1(&[2.5, -1., 0.]);
+    /// let perm = key.argsort_axis(Axis(0));
+    /// assert_eq!(a.permute_axis(Axis(0), perm.as_slice().unwrap()),
+    ///            arr2(&[[3, 4],
+    ///                   [5, 6],
+    ///                   [1, 2]]));
+    /// ```
+    ///
+    /// **Panics** if `axis` is out of bounds, or if `perm` is not a
+    /// permutation of `0..n`, where `n` is the length of `axis`.
+    pub fn permute_axis(&self, axis: Axis, perm: &[usize]) -> Array<A, D>
+    where
+        A: Clone,
+    {
+        let n = self.len_of(axis);
+        let mut seen = vec![false; n];
+        let is_permutation = perm.len() == n
+            && perm
+                .iter()
+                .all(|&i| i < n && !std::mem::replace(&mut seen[i], true));
+        assert!(
+            is_permutation,
+            "ndarray: permute_axis requires a permutation of the axis indices"
+        );
+        let mut res = self.to_owned();
+        for (i, &j) in perm.iter().enumerate() {
+            res.slice_axis_mut(axis, Slice::from(i..i + 1))
+                .assign(&self.slice_axis(axis, Slice::from(j..j + 1)));
+        }
+        res
+    }
+
+    /// Partially sort each lane along `axis` so that its element at index
+    /// `n` is the one that would be there if the lane were sorted.
+    ///
+    /// Afterwards, the elements before index `n` in each lane are less than
+    /// or equal to it, and the elements after it are greater than or equal
+    /// to it, in unspecified order. Elements that are not comparable to
+    /// themselves, like NaN, are ordered after all others, like in
+    /// [`.argsort_axis()`](#method.argsort_axis).
+    ///
+    /// This takes linear time on average for each lane, which makes it
+    /// cheaper than sorting for finding the `k` smallest or largest
+    /// elements.
+    ///
+    /// ```
+    /// use ndarray::{arr2, s, Axis};
+    ///
+    /// let mut scores = arr2(&[[1, 7, 3, 9],
+    ///                         [8, 2, 6, 4]]);
+    /// // the two largest scores of each row, in some order
+    /// scores.select_nth_unstable_axis(Axis(1), 2);
+    /// let mut top = scores.slice(s![.., 2..]).to_owned();
+    /// for mut row in top.genrows_mut() {
+    ///     row.as_slice_mut().unwrap().sort();
+    /// }
+    /// assert_eq!(top, arr2(&[[7, 9],
+    ///                        [6, 8]]));
+    /// ```
+    ///
+    /// **Panics** if `axis` is out of bounds, or if `n` is not less than the
+    /// length of `axis`.
+    pub fn select_nth_unstable_axis(&mut self, axis: Axis, n: usize)
+    where
+        A: PartialOrd,
+        S: DataMut,
+    {
+        assert!(
+            n < self.len_of(axis),
+            "ndarray: select_nth_unstable_axis index out of bounds"
+        );
+        Zip::from(self.lanes_mut(axis)).apply(|mut lane| select_nth(&mut lane, n));
+    }
+}
+
+impl<A, S> ArrayBase<S, Ix1>
+where
+    S: Data<Elem = A>,
+{
+    /// Return the distinct elements of the array, in ascending order.
+    ///
+    /// Elements that are not comparable to themselves, like NaN, are placed
+    /// at the end and count as one distinct value.
+    ///
+    /// ```
+    /// use ndarray::arr1;
+    ///
+    /// let a = arr1(&[3, 1, 3, 2, 1, 3]);
+    /// assert_eq!(a.unique(), arr1(&[1, 2, 3]));
+    /// ```
+    pub fn unique(&self) -> Array1<A>
+    where
+        A: Clone + PartialOrd,
+    {
+        self.unique_counts().0
+    }
+
+    /// Return the distinct elements of the array, in ascending order, and
+    /// the number of times each of them occurs.
+    ///
+    /// Elements that are not comparable to themselves are handled like in
+    /// [`.unique()`](#method.unique).
+    ///
+    /// ```
+    /// use ndarray::arr1;
+    ///
+    /// let a = arr1(&["b", "a", "b", "c", "b"]);
+    /// let (values, counts) = a.unique_counts();
+    /// assert_eq!(values, arr1(&["a", "b", "c"]));
+    /// assert_eq!(counts, arr1(&[1, 3, 1]));
+    /// ```
+    pub fn unique_counts(&self) -> (Array1<A>, Array1<usize>)
+    where
+        A: Clone + PartialOrd,
+    {
+        let mut sorted = self.to_vec();
+        sorted.sort_by(nan_last_cmp);
+        let mut values: Vec<A> = Vec::new();
+        let mut counts = Vec::new();
+        for x in sorted {
+            match values.last() {
+                Some(last) if nan_last_cmp(last, &x) == Ordering::Equal => {
+                    *counts.last_mut().unwrap() += 1;
+                    continue;
+                }
+                _ => {}
+            }
+            values.push(x);
+            counts.push(1);
+        }
+        (Array::from(values), Array::from(counts))
+    }
+
+    /// Find the indices at which `values` would be inserted into the sorted
+    /// array to keep it sorted.
+    ///
+    /// For each value `v`, the result is the index `i` such that all
+    /// elements before `i` are less than `v` and all elements from `i` on
+    /// are greater than or equal to `v` (for `SearchSide::Left`), or such
+    /// that elements before `i` are less than or equal to `v` and elements
+    /// from `i` on are greater than `v` (for `SearchSide::Right`), like
+    /// `numpy.searchsorted`. Each index is found with a binary search.
+    ///
+    /// `self` must be sorted in ascending order, with elements that are not
+    /// comparable to themselves, like NaN, at the end, as sorted by
+    /// [`.argsort_axis()`](#method.argsort_axis); otherwise the result is
+    /// unspecified.
+    ///
+    /// ```
+    /// use ndarray::{arr1, arr2, SearchSide};
+    ///
+    /// let a = arr1(&[1, 2, 2, 3, 5]);
+    /// let values = arr2(&[[2, 4],
+    ///                     [0, 9]]);
+    /// assert_eq!(a.searchsorted(&values, SearchSide::Left), arr2(&[[1, 4],
+    ///                                                              [0, 5]]));
+    /// assert_eq!(a.searchsorted(&values, SearchSide::Right), arr2(&[[3, 4],
+    ///                                                               [0, 5]]));
+    /// ```
+    pub fn searchsorted<S2, E>(
+        &self,
+        values: &ArrayBase<S2, E>,
+        side: SearchSide,
+    ) -> Array<usize, E>
+    where
+        A: PartialOrd,
+        S2: Data<Elem = A>,
+        E: Dimension,
+    {
+        values.map(|v| {
+            // Find the first index whose element is after `v`.
+            let is_after = |x: &A| match side {
+                SearchSide::Left => nan_last_cmp(x, v) != Ordering::Less,
+                SearchSide::Right => nan_last_cmp(x, v) == Ordering::Greater,
+            };
+            let (mut lo, mut hi) = (0, self.len());
+            while lo < hi {
+                let mid = lo + (hi - lo) / 2;
+                if is_after(&self[mid]) {
+                    hi = mid;
+                } else {
+                    lo = mid + 1;
+                }
+            }
+            lo
+        })
+    }
+}
+
+impl<A, S> ArrayBase<S, Ix2>
+where
+    S: Data<Elem = A>,
+{
+    /// Return the distinct rows of the matrix, in ascending lexicographic
+    /// order.
+    ///
+    /// Elements are compared like in [`.unique()`](#method.unique).
+    ///
+    /// ```
+    /// use ndarray::arr2;
+    ///
+    /// let a = arr2(&[[1, 2],
+    ///                [0, 5],
+    ///                [1, 2],
+    ///                [1, 0]]);
+    /// assert_eq!(a.unique_rows(), arr2(&[[0, 5],
+    ///                                    [1, 0],
+    ///                                    [1, 2]]));
+    /// ```
+    pub fn unique_rows(&self) -> Array2<A>
+    where
+        A: Clone + PartialOrd,
+    {
+        let row_cmp = |&i: &usize, &j: &usize| {
+            let (a, b) = (self.row(i), self.row(j));
+            a.iter()
+                .zip(&b)
+                .map(|(x, y)| nan_last_cmp(x, y))
+                .find(|&ord| ord != Ordering::Equal)
+                .unwrap_or(Ordering::Equal)
+        };
+        let mut rows: Vec<_> = (0..self.nrows()).collect();
+        rows.sort_by(&row_cmp);
+        rows.dedup_by(|i, j| row_cmp(i, j) == Ordering::Equal);
+
+        let mut elements = Vec::with_capacity(rows.len() * self.ncols());
+        for &i in &rows {
+            elements.extend(self.row(i).iter().cloned());
+        }
+        Array::from_shape_vec((rows.len(), self.ncols()), elements).unwrap()
+    }
+}
+
+/// Reorder `lane` so that its element at index `n` is where it would be if
+/// the lane were sorted by `nan_last_cmp`, with smaller or equal elements
+/// before it and greater or equal elements after it.
+fn select_nth<A: PartialOrd>(lane: &mut ArrayViewMut1<'_, A>, n: usize) {
+    let (mut lo, mut hi) = (0, lane.len());
+    while hi - lo > 1 {
+        // Move the median of the first, middle and last element to `lo`.
+        let (mid, last) = (lo + (hi - lo) / 2, hi - 1);
+        if nan_last_cmp(&lane[mid], &lane[lo]) == Ordering::Less {
+            lane.swap(mid, lo);
+        }
+        if nan_last_cmp(&lane[last], &lane[mid]) == Ordering::Less {
+            lane.swap(last, mid);
+            if nan_last_cmp(&lane[mid], &lane[lo]) == Ordering::Less {
+                lane.swap(mid, lo);
+            }
+        }
+        lane.swap(lo, mid);
+
+        // Three-way partition into `lo..lt` less than the pivot, `lt..gt`
+        // equal to it and `gt..hi` greater than it. The pivot is always at
+        // `lt`, the start of the equal part.
+        let (mut lt, mut i, mut gt) = (lo, lo + 1, hi);
+        while i < gt {
+            match nan_last_cmp(&lane[i], &lane[lt]) {
+                Ordering::Less => {
+                    lane.swap(lt, i);
+                    lt += 1;
+                    i += 1;
+                }
+                Ordering::Greater => {
+                    gt -= 1;
+                    lane.swap(i, gt);
+                }
+                Ordering::Equal => i += 1,
+            }
+        }
+        if n < lt {
+            hi = lt;
+        } else if n >= gt {
+            lo = gt;
+        } else {
+            return;
+        }
+    }
+}
+
+/// Compare `a` and `b`, ordering elements that are not comparable to
+/// themselves after all others.
+fn nan_last_cmp<A: PartialOrd>(a: &A, b: &A) -> Ordering {
+    match a.partial_cmp(b) {
+        Some(ord) => ord,
+        None => match (a.partial_cmp(a).is_none(), b.partial_cmp(b).is_none()) {
+            (true, false) => Ordering::Greater,
+            (false, true) => Ordering::Less,
+            _ => Ordering::Equal,
+        },
+    }
+}
@@ -30,6 +30,14 @@ where
     }
 }
 
+impl<'a, A, D> SplitAt for ArrayView<'a, A, D>
+    where D: Dimension
+{
+    fn split_at(self, axis: Axis, index: usize) -> (Self, Self) {
+        self.split_at(axis, index)
+    }
+}
+
 impl<'a, A, D> SplitAt for ArrayViewMut<'a, A, D>
     where D: Dimension
 {
@@ -50,6 +50,30 @@ fn test_arcarray_thread_safe() {
     is_sync(&a);
 }
 
+#[test]
+fn test_linspace_exact_endpoints() {
+    for &(start, end) in &[(0.1f64, 0.7), (1.3, -9.1), (-1e-3, 1e5)] {
+        for n in 2..40 {
+            let a = Array::linspace(start, end, n);
+            assert_eq!(a.len(), n);
+            assert_eq!(a[0], start);
+            assert_eq!(a[n - 1], end);
+        }
+    }
+    assert_eq!(Array::linspace(2., 3., 1), arr1(&[2.]));
+    assert_eq!(Array::<f64, _>::linspace(2., 3., 0).len(), 0);
+
+    for &(base, start, end) in &[(10f64, 0.3, 7.1), (2., 1.3, -9.1), (-10., -0.7, 2.9)] {
+        for n in 2..40 {
+            let a = Array::logspace(base, start, end, n);
+            assert_eq!(a.len(), n);
+            assert_eq!(a[0], base.signum() * base.abs().powf(start));
+            assert_eq!(a[n - 1], base.signum() * base.abs().powf(end));
+        }
+    }
+    assert_eq!(Array::logspace(10., 7.1, 0.3, 6)[5], 10f64.powf(0.3));
+}
+
 #[test]
 fn test_uninit() {
     unsafe {
@@ -238,3 +262,21 @@ fn maybe_uninit_1() {
 
     }
 }
+
+#[test]
+fn uninit() {
+    use std::mem::MaybeUninit;
+
+    let mut a = Array::<String, _>::uninit((3, 2).f());
+    assert_eq!(a.strides(), &[1, 3]);
+    for ((i, j), elt) in a.indexed_iter_mut() {
+        *elt = MaybeUninit::new(format!("{}{}", i, j));
+    }
+    let a = unsafe { a.assume_init() };
+    assert_eq!(a, Array::from_shape_fn((3, 2), |(i, j)| format!("{}{}", i, j)));
+
+    let mut b = ArcArray::<f64, _>::uninit(4);
+    b.fill(MaybeUninit::new(2.));
+    let b = unsafe { b.assume_init() };
+    assert_eq!(b, ArcArray::from_elem(4, 2.));
+}
@@ -11,7 +11,7 @@ use defmac::defmac;
 use itertools::{enumerate, zip, Itertools};
 use ndarray::indices;
 use ndarray::prelude::*;
-use ndarray::{arr3, rcarr2};
+use ndarray::{arr3, rcarr2, ErrorKind};
 use ndarray::{Slice, SliceInfo, SliceOrIndex};
 use std::iter::FromIterator;
 
@@ -216,7 +216,7 @@ fn test_slice_dyninput_array_fixed() {
 #[test]
 fn test_slice_array_dyn() {
     let mut arr = Array3::<f64>::zeros((5, 2, 5));
-    let info = &SliceInfo::<_, IxDyn>::new([
+    let info = &SliceInfo::<_, Ix3, IxDyn>::new([
         SliceOrIndex::from(1..),
         SliceOrIndex::from(1),
         SliceOrIndex::from(..).step_by(2),
@@ -231,7 +231,7 @@ fn test_slice_array_dyn() {
 #[test]
 fn test_slice_dyninput_array_dyn() {
     let mut arr = Array3::<f64>::zeros((5, 2, 5)).into_dyn();
-    let info = &SliceInfo::<_, IxDyn>::new([
+    let info = &SliceInfo::<_, Ix3, IxDyn>::new([
         SliceOrIndex::from(1..),
         SliceOrIndex::from(1),
         SliceOrIndex::from(..).step_by(2),
@@ -246,7 +246,7 @@ fn test_slice_dyninput_array_dyn() {
 #[test]
 fn test_slice_dyninput_vec_fixed() {
     let mut arr = Array3::<f64>::zeros((5, 2, 5)).into_dyn();
-    let info = &SliceInfo::<_, Ix2>::new(vec![
+    let info = &SliceInfo::<_, IxDyn, Ix2>::new(vec![
         SliceOrIndex::from(1..),
         SliceOrIndex::from(1),
         SliceOrIndex::from(..).step_by(2),
@@ -261,7 +261,7 @@ fn test_slice_dyninput_vec_fixed() {
 #[test]
 fn test_slice_dyninput_vec_dyn() {
     let mut arr = Array3::<f64>::zeros((5, 2, 5)).into_dyn();
-    let info = &SliceInfo::<_, IxDyn>::new(vec![
+    let info = &SliceInfo::<_, IxDyn, IxDyn>::new(vec![
         SliceOrIndex::from(1..),
         SliceOrIndex::from(1),
         SliceOrIndex::from(..).step_by(2),
@@ -342,6 +342,143 @@ fn test_slice_collapse_with_indices() {
     assert_eq!(vi, Array3::from_elem((1, 1, 1), elem));
 }
 
+#[test]
+fn test_slice_new_axis() {
+    let arr = Array::from_shape_fn((2, 3), |(i, j)| 10 * i + j);
+
+    let v = arr.slice(s![NewAxis, .., NewAxis, 1..]);
+    assert_eq!(v.shape(), &[1, 2, 1, 2]);
+    assert_eq!(
+        v.index_axis(Axis(0), 0).index_axis(Axis(1), 0),
+        arr.slice(s![.., 1..])
+    );
+
+    let v: ArrayView3<_> = arr.slice(s![1, NewAxis, ..;-1, NewAxis]);
+    assert_eq!(v, arr3(&[[[12], [11], [10]]]));
+
+    let v = aview0(&5).slice_move(s![NewAxis]);
+    assert_eq!(v, aview1(&[5]));
+
+    let mut vi = arr.view();
+    assert_panics!(vi
+        .clone()
+        .into_dyn()
+        .slice_collapse(s![.., NewAxis, ..].as_ref()));
+    vi.slice_collapse(s![.., 0]);
+    assert_eq!(vi.shape(), &[2, 1]);
+}
+
+#[test]
+fn test_slice_ellipsis() {
+    let arr = Array::from_shape_fn((2, 3, 4), |(i, j, k)| 100 * i + 10 * j + k);
+
+    let v: ArrayViewD<_> = arr.slice(s![Ellipsis, 1]);
+    assert_eq!(v, arr.slice(s![.., .., 1]).into_dyn());
+    assert_eq!(
+        arr.slice(s![0, Ellipsis]),
+        arr.slice(s![0, .., ..]).into_dyn()
+    );
+    assert_eq!(
+        arr.slice(s![1, Ellipsis, 3]),
+        arr.slice(s![1, .., 3]).into_dyn()
+    );
+    assert_eq!(
+        arr.slice(s![0, 1, Ellipsis, 2]),
+        arr.slice(s![0, 1, 2]).into_dyn()
+    );
+    assert_eq!(arr.slice(s![Ellipsis]), arr.view().into_dyn());
+    assert_eq!(arr.slice(s![Ellipsis, NewAxis]).shape(), &[2, 3, 4, 1]);
+
+    let mut dyn_arr = arr.clone().into_dyn();
+    dyn_arr.slice_mut(s![Ellipsis, ..;2]).fill(0);
+    assert_eq!(dyn_arr.slice(s![.., .., 0]), Array::zeros((2, 3)));
+    assert_eq!(dyn_arr.slice(s![.., .., 1]), arr.slice(s![.., .., 1]));
+
+    let mut vi = arr.view().into_dyn();
+    vi.slice_collapse(s![Ellipsis, 2].as_ref());
+    assert_eq!(vi.shape(), &[2, 3, 1]);
+
+    assert_panics!(arr.slice(s![Ellipsis, 0, 0, 0, 0]));
+    assert_panics!(arr.slice(s![Ellipsis, 0, Ellipsis]));
+}
+
+#[test]
+fn test_slice_info_new_axis_ellipsis() {
+    let elems = [
+        SliceOrIndex::from(Ellipsis),
+        SliceOrIndex::from(NewAxis),
+        SliceOrIndex::from(1),
+    ];
+    assert!(SliceInfo::<_, IxDyn, IxDyn>::new(elems).is_ok());
+    assert!(SliceInfo::<_, Ix3, Ix3>::new(elems).is_ok());
+    assert!(SliceInfo::<_, Ix3, Ix2>::new(elems).is_err());
+    assert!(SliceInfo::<_, IxDyn, Ix3>::new(elems).is_err());
+    assert!(SliceInfo::<_, Ix0, Ix0>::new(elems).is_err());
+    assert!(SliceInfo::<_, IxDyn, IxDyn>::new([SliceOrIndex::Ellipsis; 2]).is_err());
+
+    let arr = Array::from_shape_fn((2, 3, 4), |(i, j, k)| 100 * i + 10 * j + k);
+    let info = SliceInfo::<_, Ix3, Ix3>::new(elems).unwrap();
+    assert_eq!(arr.slice(&info), arr.slice(s![.., .., NewAxis, 1]));
+    assert_eq!(info.in_ndim(), 3);
+    assert_eq!(info.out_ndim(), 3);
+}
+
+#[test]
+fn test_slice_info_from_iter() {
+    let arr = Array::from_shape_fn((2, 3, 4), |(i, j, k)| 100 * i + 10 * j + k);
+    let info: SliceInfo<_, IxDyn, IxDyn> =
+        arr.shape().iter().map(|&len| len as isize - 1).collect();
+    assert_eq!(arr.slice(&info), aview0(&123).into_dyn());
+
+    let info: SliceInfo<_, IxDyn, IxDyn> = vec![SliceOrIndex::from(1..), SliceOrIndex::Ellipsis]
+        .into_iter()
+        .collect();
+    assert_eq!(arr.slice(&info), arr.slice(s![1.., .., ..]).into_dyn());
+
+    let twice: SliceInfo<_, IxDyn, IxDyn> = vec![Ellipsis, Ellipsis].into_iter().collect();
+    assert_panics!(arr.slice(&twice));
+}
+
+#[test]
+fn test_slice_assign() {
+    let mut a = Array::from_shape_fn((3, 4), |(i, j)| i * 4 + j);
+    let mut b = a.clone();
+    a.slice_assign(s![..;-1, 1..3], &arr2(&[[20], [10], [0]]));
+    b.slice_mut(s![..;-1, 1..3])
+        .assign(&arr2(&[[20], [10], [0]]));
+    assert_eq!(a, b);
+
+    a.slice_assign(s![1, NewAxis, ..], &aview0(&7));
+    assert_eq!(a.row(1), arr1(&[7, 7, 7, 7]));
+
+    let mut d = a.clone().into_dyn();
+    d.slice_assign(s![Ellipsis, 0], &arr1(&[1, 2, 3]));
+    assert_eq!(d.slice(s![.., 0]), arr1(&[1, 2, 3]));
+}
+
+#[test]
+#[should_panic(expected = "to the slice [1.., 0] of shape [2]")]
+fn test_slice_assign_incompatible() {
+    let mut a = Array2::<i32>::zeros((3, 4));
+    a.slice_assign(s![1.., 0], &arr1(&[1, 2, 3]));
+}
+
+#[test]
+fn test_multislice_ellipsis() {
+    let mut arr = Array3::<i32>::zeros((2, 3, 4)).into_dyn();
+    {
+        let (mut a, mut b) = arr.multi_slice_mut((s![Ellipsis, 0], s![.., NewAxis, .., 1..]));
+        a.fill(1);
+        b.fill(2);
+    }
+    assert_eq!(arr.slice(s![.., .., 0]), Array::from_elem((2, 3), 1));
+    assert_eq!(arr.slice(s![.., .., 1..]), Array::from_elem((2, 3, 3), 2));
+    assert_panics!({
+        let mut arr = arr.clone();
+        arr.multi_slice_mut((s![Ellipsis, 0], s![NewAxis, 1, Ellipsis]));
+    });
+}
+
 #[test]
 fn test_multislice() {
     macro_rules! do_test {
@@ -608,6 +745,27 @@ fn test_cow_shrink() {
     assert_eq!(before[1], 5);
 }
 
+#[test]
+fn test_get_uget() {
+    let mut a = arr2(&[[1, 2, 3], [4, 5, 6]]);
+    assert_eq!(a.get((1, 2)), Some(&6));
+    assert_eq!(a.get([0, 1]), Some(&2));
+    assert_eq!(a.get((2, 0)), None);
+    assert_eq!(a.get((0, 3)), None);
+    *a.get_mut((1, 0)).unwrap() = 7;
+    assert!(a.get_mut((1, 3)).is_none());
+
+    let v = a.slice(s![..;-1, ..;2]);
+    assert_eq!(v.get((0, 1)), Some(&6));
+    assert_eq!(v.get((1, 2)), None);
+    unsafe {
+        assert_eq!(*v.uget((0, 0)), 7);
+        *a.uget_mut((0, 2)) = 8;
+        assert_eq!(*a.uget([0, 2]), 8);
+    }
+    assert_eq!(a, arr2(&[[1, 2, 8], [7, 5, 6]]));
+}
+
 #[test]
 fn test_sub() {
     let mat = ArcArray::linspace(0., 15., 16).reshape((2, 4, 2));
@@ -1394,6 +1552,54 @@ fn reshape_f() {
     assert_eq!(s, aview2(&[[0, 4, 8], [1, 5, 9], [2, 6, 10], [3, 7, 11]]));
 }
 
+#[test]
+fn to_shape() {
+    let a = Array::from_shape_vec((2, 2, 2), (0..8).collect()).unwrap();
+    assert_eq!(
+        a.to_shape((2, 5)).unwrap_err().kind(),
+        ErrorKind::IncompatibleShape
+    );
+
+    // contiguous in the requested order: a view
+    let b = a.to_shape((4, 2)).unwrap();
+    assert!(b.is_view());
+    assert_eq!(b, aview2(&[[0, 1], [2, 3], [4, 5], [6, 7]]));
+    let f = a.view().reversed_axes();
+    let b = f.to_shape((4, 2).f()).unwrap();
+    assert!(b.is_view());
+    assert_eq!(b, aview2(&[[0, 4], [1, 5], [2, 6], [3, 7]]));
+
+    // otherwise a copy in the requested order
+    let mut p = a.view();
+    p.swap_axes(0, 1);
+    let b = p.to_shape(8).unwrap();
+    assert!(b.is_owned());
+    assert_eq!(b, aview1(&[0, 1, 4, 5, 2, 3, 6, 7]));
+    let b = p.to_shape((2, 4).f()).unwrap();
+    assert!(b.is_owned());
+    assert!(b.t().is_standard_layout());
+    assert_eq!(b, aview2(&[[0, 4, 1, 5], [2, 6, 3, 7]]));
+    let s = a.slice(s![.., ..;-1, 1]);
+    assert_eq!(s.to_shape((1, 4)).unwrap(), aview2(&[[3, 1, 7, 5]]));
+}
+
+#[test]
+fn flatten() {
+    let a = Array::from_shape_vec((2, 1, 3), (0..6).collect()).unwrap();
+    assert_eq!(a.flatten(), arr1(&[0, 1, 2, 3, 4, 5]));
+    assert_eq!(a.flatten_view().unwrap(), aview1(&[0, 1, 2, 3, 4, 5]));
+    let s = a.slice(s![.., .., ..;2]);
+    assert_eq!(s.flatten(), arr1(&[0, 2, 3, 5]));
+    assert_eq!(s.flatten_view(), None);
+    let t = a.view().reversed_axes();
+    assert_eq!(t.flatten(), arr1(&[0, 3, 1, 4, 2, 5]));
+    assert_eq!(t.flatten_view(), None);
+
+    assert_eq!(arr0(7).flatten(), arr1(&[7]));
+    assert_eq!(arr0(7).flatten_view().unwrap(), aview1(&[7]));
+    assert_eq!(Array::<i32, _>::zeros((3, 0)).flatten().len(), 0);
+}
+
 #[test]
 #[allow(clippy::cognitive_complexity)]
 fn insert_axis() {
@@ -1555,6 +1761,23 @@ fn insert_axis_view() {
     );
 }
 
+#[test]
+fn squeeze() {
+    let a = array![[[1, 2, 3]], [[4, 5, 6]]];
+    assert_eq!(a.view().squeeze(), array![[1, 2, 3], [4, 5, 6]].into_dyn());
+    let b = a.slice(s![.., .., 1..2]).insert_axis(Axis(0));
+    assert_eq!(b.shape(), &[1, 2, 1, 1]);
+    assert_eq!(b.squeeze(), array![2, 5].into_dyn());
+    assert_eq!(a.clone().squeeze().strides(), &[3, 1]);
+
+    // axes of other lengths, including zero, are kept
+    assert_eq!(Array::<i32, _>::zeros((1, 0, 2)).squeeze().shape(), &[0, 2]);
+    assert_eq!(arr0(1).squeeze(), arr0(1).into_dyn());
+    let mut c = Array::from_elem((1, 1, 1), 3).squeeze();
+    c[[]] = 4;
+    assert_eq!(c, arr0(4).into_dyn());
+}
+
 #[test]
 fn arithmetic_broadcast() {
     let mut a = arr2(&[[1., 2.], [3., 4.]]);
@@ -1646,6 +1869,44 @@ fn split_at() {
     assert_eq!(right.shape(), [3, 0, 5]);
 }
 
+#[test]
+fn split_into_parts() {
+    let mut a = Array::from_shape_fn((7, 3), |(i, j)| i * 3 + j);
+    for n in 1..10 {
+        let parts = a.view().split(Axis(0), n);
+        assert_eq!(parts.len(), n);
+        let lengths: Vec<_> = parts.iter().map(|p| p.nrows()).collect();
+        assert_eq!(lengths.iter().sum::<usize>(), 7);
+        assert!(lengths.windows(2).all(|w| w[0] >= w[1] && w[0] - w[1] <= 1));
+        let views: Vec<_> = parts.iter().map(|p| p.view()).collect();
+        assert_eq!(ndarray::concatenate(Axis(0), &views).unwrap(), a);
+    }
+    assert_eq!(a.view().split(Axis(1), 2)[1], a.slice(s![.., 2..]));
+    assert_eq!(
+        a.slice(s![..;-1, ..]).split(Axis(0), 2)[1],
+        a.slice(s![..3;-1, ..])
+    );
+
+    for (k, mut part) in a.view_mut().split(Axis(0), 3).into_iter().enumerate() {
+        part.fill(k);
+    }
+    assert_eq!(a.column(0), aview1(&[0, 0, 0, 1, 1, 2, 2]));
+
+    let b = Array::from_shape_fn((2, 5), |(i, j)| i * 5 + j).into_shared();
+    assert_eq!(b.split_view(Axis(1), 2), b.view().split(Axis(1), 2));
+    for (k, mut part) in a.split_view_mut(Axis(1), 3).into_iter().enumerate() {
+        part.fill(k);
+    }
+    assert_eq!(a.row(6), aview1(&[0, 1, 2]));
+}
+
+#[test]
+#[should_panic]
+fn deny_split_into_zero_parts() {
+    let a = arr2(&[[1., 2.], [3., 4.]]);
+    a.view().split(Axis(0), 0);
+}
+
 #[test]
 #[should_panic]
 fn deny_split_at_axis_out_of_bounds() {
@@ -1752,6 +2013,32 @@ fn map_memory_order() {
     assert_eq!(amap.strides(), v.strides());
 }
 
+#[test]
+fn mapv_into_any() {
+    // same size: the allocation is reused
+    let a = arr2(&[[1u32, 2, 3], [4, 5, 6]]).reversed_axes();
+    let ptr = a.as_ptr() as usize;
+    let strides = a.strides().to_vec();
+    let expected = a.mapv(|x| x as f32 * 0.5);
+    let b = a.mapv_into_any(|x| x as f32 * 0.5);
+    assert_eq!(b, expected);
+    assert_eq!(b.as_ptr() as usize, ptr);
+    assert_eq!(b.strides(), &strides[..]);
+
+    // elements with destructors
+    let a = Array::from_shape_fn((2, 2), |(i, j)| vec![b'a' + i as u8; j + 1]);
+    let b = a.mapv_into_any(|v| String::from_utf8(v).unwrap());
+    assert_eq!(b, arr2(&[["a", "aa"], ["b", "bb"]]).mapv(String::from));
+
+    // different sizes, or elements outside the array: a new allocation
+    let a = arr1(&[1u8, 2, 3]);
+    assert_eq!(a.mapv_into_any(f64::from), arr1(&[1., 2., 3.]));
+    let a = arr1(&[1, 2, 3, 4]).slice_move(s![..;2]);
+    assert_eq!(a.mapv_into_any(|x| x as f32), arr1(&[1., 3.]));
+    let a = arr1(&[1, 2, 3]).slice_move(s![..;-1]);
+    assert_eq!(a.mapv_into_any(|x| x as f32), arr1(&[3., 2., 1.]));
+}
+
 #[test]
 fn test_contiguous() {
     let c = arr3(&[[[1, 2, 3], [4, 5, 6]], [[4, 5, 6], [7, 7, 7]]]);
@@ -1823,6 +2110,30 @@ fn test_uswap() {
     assert_eq!(a, b.t());
 }
 
+#[test]
+fn test_swap_axes_content() {
+    let mut a = Array::from_shape_fn((3, 2, 4), |(i, j, k)| 100 * i + 10 * j + k);
+    let b = a.clone();
+    a.swap_axes_content(Axis(0), 2, 0);
+    assert_eq!(a.index_axis(Axis(0), 0), b.index_axis(Axis(0), 2));
+    assert_eq!(a.index_axis(Axis(0), 1), b.index_axis(Axis(0), 1));
+    assert_eq!(a.index_axis(Axis(0), 2), b.index_axis(Axis(0), 0));
+    a.swap_axes_content(Axis(0), 0, 2);
+    assert_eq!(a, b);
+
+    let mut v = a.slice_mut(s![.., .., ..;-2]);
+    v.swap_axes_content(Axis(2), 1, 0);
+    v.swap_axes_content(Axis(1), 1, 1);
+    assert_eq!(a.slice(s![0, 0, ..]), aview1(&[0, 3, 2, 1]));
+}
+
+#[test]
+#[should_panic]
+fn test_swap_axes_content_out_of_bounds() {
+    let mut a = Array::<i32, _>::zeros((3, 2));
+    a.swap_axes_content(Axis(1), 0, 2);
+}
+
 #[test]
 fn test_shape() {
     let data = [0, 1, 2, 3, 4, 5];
@@ -2208,3 +2519,602 @@ mod array_cow_tests {
         });
     }
 }
+
+#[test]
+fn pad() {
+    use ndarray::PadMode;
+
+    let v = arr1(&[1, 2, 3]);
+    assert_eq!(
+        v.pad(&[(2, 1)], PadMode::Constant(0)),
+        arr1(&[0, 0, 1, 2, 3, 0])
+    );
+    assert_eq!(v.pad(&[(2, 1)], PadMode::Edge), arr1(&[1, 1, 1, 2, 3, 3]));
+    assert_eq!(
+        v.pad(&[(5, 5)], PadMode::Reflect),
+        arr1(&[2, 1, 2, 3, 2, 1, 2, 3, 2, 1, 2, 3, 2])
+    );
+    assert_eq!(
+        v.pad(&[(5, 5)], PadMode::Wrap),
+        arr1(&[2, 3, 1, 2, 3, 1, 2, 3, 1, 2, 3, 1, 2])
+    );
+    assert_eq!(v.pad(&[(0, 0)], PadMode::Wrap), v);
+    assert_eq!(arr1(&[7]).pad(&[(2, 2)], PadMode::Reflect), arr1(&[7; 5]));
+
+    // padding a three-dimensional array is padding each axis in turn
+    let a = Array::from_shape_fn((2, 3, 4), |(i, j, k)| i * 12 + j * 4 + k);
+    let widths = [(1, 2), (0, 3), (2, 0)];
+    for mode in &[
+        PadMode::Constant(99),
+        PadMode::Edge,
+        PadMode::Reflect,
+        PadMode::Wrap,
+    ] {
+        let padded = a.pad(&widths, mode.clone());
+        assert_eq!(padded.shape(), &[5, 6, 6]);
+        let mut stepwise = a.clone();
+        for (k, &width) in widths.iter().enumerate() {
+            let mut w = [(0, 0); 3];
+            w[k] = width;
+            stepwise = stepwise.pad(&w, mode.clone());
+        }
+        assert_eq!(padded, stepwise);
+        assert_eq!(padded.slice(s![1..3, ..3, 2..]), a);
+        // a non-contiguous view is padded alike
+        assert_eq!(
+            a.slice(s![.., ..;-1, ..]).pad(&widths, mode.clone()),
+            a.pad(&[(1, 2), (3, 0), (2, 0)], mode.clone())
+                .slice(s![.., ..;-1, ..])
+        );
+    }
+
+    let empty = Array2::<i32>::zeros((0, 2));
+    assert_eq!(
+        empty.pad(&[(1, 0), (0, 1)], PadMode::Constant(5)),
+        arr2(&[[5, 5, 5]])
+    );
+    assert_eq!(empty.pad(&[(0, 0), (1, 1)], PadMode::Edge).shape(), &[0, 4]);
+}
+
+#[test]
+#[should_panic]
+fn pad_empty_axis_edge() {
+    let empty = Array2::<i32>::zeros((0, 2));
+    empty.pad(&[(1, 0), (0, 0)], ndarray::PadMode::Edge);
+}
+
+#[test]
+fn roll() {
+    let a = Array::from_shape_fn((3, 4, 5), |(i, j, k)| i * 20 + j * 5 + k);
+    for axis in 0..3 {
+        let len = a.len_of(Axis(axis)) as isize;
+        for &shift in &[0, 1, 2, -1, -7, len, 3 * len + 1] {
+            let rolled = a.roll(Axis(axis), shift);
+            for (index, &x) in rolled.indexed_iter() {
+                let mut source = [index.0, index.1, index.2];
+                source[axis] =
+                    ((source[axis] as isize - shift) % len + len) as usize % len as usize;
+                assert_eq!(x, a[source]);
+            }
+            // all memory layouts agree
+            let mut f = Array::zeros(a.raw_dim().f());
+            f.assign(&a);
+            f.roll_inplace(Axis(axis), shift);
+            assert_eq!(f, rolled);
+            let mut c = a.clone();
+            let mut strided = c.slice_mut(s![..;-1, .., ..;-2]);
+            strided.roll_inplace(Axis(axis), shift);
+            let expected = a
+                .slice(s![..;-1, .., ..;-2])
+                .to_owned()
+                .roll(Axis(axis), shift);
+            assert_eq!(strided, expected);
+            assert_eq!(rolled.roll(Axis(axis), -shift), a);
+        }
+    }
+    let mut empty = Array2::<i32>::zeros((0, 3));
+    empty.roll_inplace(Axis(0), 2);
+    assert_eq!(empty.roll(Axis(1), 1).shape(), &[0, 3]);
+}
+
+#[test]
+fn flipud_fliplr() {
+    let a = arr2(&[[1, 2, 3], [4, 5, 6]]);
+    assert_eq!(a.flipud(), a.slice(s![..;-1, ..]));
+    assert_eq!(a.fliplr(), a.slice(s![.., ..;-1]));
+    assert_eq!(a.flipud().fliplr(), arr2(&[[6, 5, 4], [3, 2, 1]]));
+    assert_eq!(a.flipud().flipud(), a);
+    // the views share the array's memory
+    assert_eq!(a.flipud().as_ptr(), &a[[1, 0]] as *const _);
+    assert_eq!(a.fliplr().as_ptr(), &a[[0, 2]] as *const _);
+
+    let empty = Array2::<i32>::zeros((0, 3));
+    assert_eq!(empty.flipud().shape(), &[0, 3]);
+    assert_eq!(empty.fliplr().shape(), &[0, 3]);
+}
+
+#[test]
+fn triu_tril() {
+    let a = Array::from_shape_fn((4, 6), |(i, j)| (i * 6 + j + 1) as i32);
+    for &k in &[-5, -1, 0, 1, 3, 7, isize::min_value(), isize::max_value()] {
+        let triu = a.triu(k);
+        let tril = a.tril(k);
+        for ((i, j), &x) in a.indexed_iter() {
+            let d = j as isize - i as isize;
+            assert_eq!(triu[[i, j]], if d >= k { x } else { 0 });
+            assert_eq!(tril[[i, j]], if d <= k { x } else { 0 });
+        }
+        // non-standard layouts agree
+        let neg_k = k.checked_neg().unwrap_or(isize::max_value());
+        assert_eq!(a.t().triu(k), a.tril(neg_k).reversed_axes());
+        let mut b = a.clone();
+        b.slice_mut(s![..;-1, ..]).tril_inplace(k);
+        assert_eq!(b.slice(s![..;-1, ..]), a.slice(s![..;-1, ..]).tril(k));
+    }
+    assert_eq!(&a.triu(1) + &a.tril(0), a);
+    assert_eq!(Array2::<i32>::zeros((0, 3)).triu(0).shape(), &[0, 3]);
+}
+
+#[test]
+fn diag_offset() {
+    let mut a = Array::from_shape_fn((3, 5), |(i, j)| i * 5 + j);
+    for k in -4..7 {
+        let expected: Vec<_> = a
+            .indexed_iter()
+            .filter(|&((i, j), _)| j as isize - i as isize == k)
+            .map(|(_, &x)| x)
+            .collect();
+        assert_eq!(a.diag_offset(k).to_vec(), expected);
+        assert_eq!(a.t().diag_offset(-k).to_vec(), expected);
+    }
+    assert_eq!(a.diag_offset(0), a.diag());
+    assert!(a.diag_offset(isize::min_value()).is_empty());
+    assert!(a.diag_offset(isize::max_value()).is_empty());
+
+    a.diag_offset_mut(-1).fill(0);
+    a.diag_offset_mut(2).fill(0);
+    assert_eq!(
+        a,
+        arr2(&[[0, 1, 0, 3, 4], [0, 6, 7, 0, 9], [10, 0, 12, 13, 0]])
+    );
+}
+
+#[test]
+fn eye_offset() {
+    for &(m, n) in &[(0, 3), (3, 3), (2, 5), (5, 2)] {
+        for k in -6..7 {
+            let a = Array2::<u8>::eye_offset((m, n), k);
+            let expected =
+                Array::from_shape_fn((m, n), |(i, j)| (j as isize - i as isize == k) as u8);
+            assert_eq!(a, expected);
+            let f = Array2::<u8>::eye_offset((m, n).f(), k);
+            assert_eq!(f, expected);
+        }
+    }
+    assert_eq!(Array2::<f64>::eye_offset((4, 4), 0), Array2::eye(4));
+    assert_eq!(Array2::<f64>::identity(3), Array2::eye(3));
+}
+
+#[test]
+fn argsort_axis() {
+    let a = Array::from_shape_fn((4, 3, 5), |(i, j, k)| ((i * 7 + j * 11 + k * 5) % 6) as i32);
+    for axis in 0..3 {
+        let axis = Axis(axis);
+        let argsort = a.argsort_axis(axis);
+        for (lane, perm) in a.lanes(axis).into_iter().zip(argsort.lanes(axis)) {
+            let sorted: Vec<_> = perm.iter().map(|&i| lane[i]).collect();
+            let mut expected = lane.to_vec();
+            expected.sort();
+            assert_eq!(sorted, expected);
+            // stable
+            for w in perm.to_vec().windows(2) {
+                assert!(lane[w[0]] < lane[w[1]] || w[0] < w[1]);
+            }
+        }
+    }
+
+    let x = arr1(&[2., std::f64::NAN, -1., 7., std::f64::NAN, 0.]);
+    assert_eq!(x.argsort_axis(Axis(0)), arr1(&[2, 5, 0, 3, 1, 4]));
+    assert_eq!(
+        Array2::<f64>::zeros((0, 3)).argsort_axis(Axis(0)).shape(),
+        &[0, 3]
+    );
+}
+
+#[test]
+fn permute_axis() {
+    let a = Array::from_shape_fn((3, 4), |(i, j)| i * 10 + j);
+    assert_eq!(a.permute_axis(Axis(0), &[0, 1, 2]), a);
+    assert_eq!(
+        a.permute_axis(Axis(1), &[3, 0, 2, 1]),
+        arr2(&[[3, 0, 2, 1], [13, 10, 12, 11], [23, 20, 22, 21]])
+    );
+    assert_eq!(
+        a.t().permute_axis(Axis(0), &[1, 0, 3, 2]),
+        a.permute_axis(Axis(1), &[1, 0, 3, 2]).t()
+    );
+}
+
+#[test]
+#[should_panic]
+fn permute_axis_not_a_permutation() {
+    let a = Array::from_shape_fn((3, 4), |(i, j)| i * 10 + j);
+    a.permute_axis(Axis(0), &[0, 1, 1]);
+}
+
+#[test]
+fn select_nth_unstable_axis() {
+    let a = Array::from_shape_fn((5, 9, 4), |(i, j, k)| ((i * 7 + j * 13 + k * 5) % 8) as i32);
+    for axis in 0..3 {
+        let axis = Axis(axis);
+        for n in 0..a.len_of(axis) {
+            let mut b = a.clone();
+            b.select_nth_unstable_axis(axis, n);
+            for (lane, selected) in a.lanes(axis).into_iter().zip(b.lanes(axis)) {
+                let mut sorted = lane.to_vec();
+                sorted.sort();
+                let mut reordered = selected.to_vec();
+                assert_eq!(reordered[n], sorted[n]);
+                assert!(reordered[..n].iter().all(|&x| x <= sorted[n]));
+                assert!(reordered[n + 1..].iter().all(|&x| x >= sorted[n]));
+                reordered.sort();
+                assert_eq!(reordered, sorted);
+            }
+        }
+    }
+
+    let nan = std::f64::NAN;
+    let mut x = arr1(&[nan, 3., nan, -1., 2., 0.]);
+    x.select_nth_unstable_axis(Axis(0), 3);
+    assert_eq!(x[3], 3.);
+    x.select_nth_unstable_axis(Axis(0), 4);
+    assert!(x[4].is_nan() && x[5].is_nan());
+}
+
+#[test]
+#[should_panic]
+fn select_nth_unstable_axis_out_of_bounds() {
+    Array2::<f64>::zeros((3, 2)).select_nth_unstable_axis(Axis(1), 2);
+}
+
+#[test]
+fn unique() {
+    let a = Array::from_shape_fn(40, |i| (i * 7 % 11) as i32 - 5).slice_move(s![..;-3]);
+    let (values, counts) = a.unique_counts();
+    let mut expected = a.to_vec();
+    expected.sort();
+    expected.dedup();
+    assert_eq!(values.to_vec(), expected);
+    assert_eq!(a.unique(), values);
+    for (v, &c) in values.iter().zip(&counts) {
+        assert_eq!(a.iter().filter(|&x| x == v).count(), c);
+    }
+
+    let nan = std::f64::NAN;
+    let (values, counts) = arr1(&[1., nan, 0., 1., nan]).unique_counts();
+    assert_eq!(values.slice(s![..2]), aview1(&[0., 1.]));
+    assert!(values[2].is_nan());
+    assert_eq!(counts, arr1(&[1, 2, 2]));
+
+    let empty = Array1::<f64>::zeros(0);
+    assert_eq!(empty.unique().len(), 0);
+}
+
+#[test]
+fn unique_rows() {
+    let a = arr2(&[[2, 1, 0], [1, 1, 1], [2, 1, 0], [1, 0, 2], [1, 1, 1]]);
+    assert_eq!(a.unique_rows(), arr2(&[[1, 0, 2], [1, 1, 1], [2, 1, 0]]));
+    assert_eq!(
+        a.t().unique_rows(),
+        arr2(&[[0, 1, 0, 2, 1], [1, 1, 1, 0, 1], [2, 1, 2, 1, 1]])
+    );
+    assert_eq!(Array2::<i32>::zeros((3, 0)).unique_rows().shape(), &[1, 0]);
+    assert_eq!(Array2::<i32>::zeros((0, 3)).unique_rows().shape(), &[0, 3]);
+}
+
+#[test]
+fn searchsorted() {
+    use ndarray::SearchSide;
+
+    let a = arr1(&[0, 2, 2, 2, 5, 7, 7]);
+    let values = Array::from_shape_fn((3, 3), |(i, j)| (i * 3 + j) as i32 - 1);
+    for &side in &[SearchSide::Left, SearchSide::Right] {
+        let found = a.searchsorted(&values, side);
+        assert_eq!(found.dim(), (3, 3));
+        for (v, &i) in values.iter().zip(&found) {
+            let expected = match side {
+                SearchSide::Left => a.iter().filter(|&x| x < v).count(),
+                SearchSide::Right => a.iter().filter(|&x| x <= v).count(),
+            };
+            assert_eq!(i, expected);
+        }
+    }
+    assert_eq!(
+        a.slice(s![..;-1])
+            .slice(s![..;-1])
+            .searchsorted(&arr0(2), SearchSide::Right),
+        arr0(4)
+    );
+    assert_eq!(
+        arr1(&[]).searchsorted(&arr1(&[1]), SearchSide::Left),
+        arr1(&[0])
+    );
+
+    let nan = std::f64::NAN;
+    let x = arr1(&[-1., 0.5, 3., nan, nan]);
+    let found = x.searchsorted(&arr1(&[0., nan, 10.]), SearchSide::Left);
+    assert_eq!(found, arr1(&[1, 3, 3]));
+    let found = x.searchsorted(&arr1(&[0., nan, 10.]), SearchSide::Right);
+    assert_eq!(found, arr1(&[1, 5, 3]));
+}
+
+#[test]
+fn take() {
+    let a = Array::from_shape_fn((3, 4, 2), |(i, j, k)| format!("{}{}{}", i, j, k));
+    let indices = [3, 0, 0, 2, 3];
+    let t = a.take(Axis(1), &indices);
+    assert_eq!(t.shape(), &[3, 5, 2]);
+    for ((i, j, k), x) in t.indexed_iter() {
+        assert_eq!(*x, a[[i, indices[j], k]]);
+    }
+    assert_eq!(a.t().take(Axis(1), &indices), t.t());
+    assert_eq!(a.take(Axis(0), &[]).shape(), &[0, 4, 2]);
+    assert!(t.is_standard_layout());
+    assert_eq!(
+        Array2::<i32>::zeros((3, 0)).take(Axis(0), &[2, 0]).shape(),
+        &[2, 0]
+    );
+
+    let b = Array::from_shape_fn((3, 4), |(i, j)| i * 4 + j);
+    let mut out = Array::zeros((2, 4).f());
+    b.take_into(Axis(0), &[2, 1], &mut out);
+    assert_eq!(out, b.select(Axis(0), &[2, 1]));
+    let mut out = Array::zeros((3, 3));
+    b.take_into(Axis(1), &[1, 1, 0], &mut out.view_mut());
+    assert_eq!(out, b.select(Axis(1), &[1, 1, 0]));
+}
+
+#[test]
+#[should_panic]
+fn take_out_of_bounds() {
+    Array2::<f64>::zeros((3, 2)).take(Axis(0), &[0, 3]);
+}
+
+#[test]
+#[should_panic(expected = "out of bounds")]
+fn take_from_empty_axis() {
+    Array2::<i32>::zeros((0, 3)).take(Axis(0), &[0]);
+}
+
+#[test]
+#[should_panic]
+fn take_into_wrong_shape() {
+    let mut out = Array2::zeros((2, 3));
+    Array2::<f64>::zeros((3, 2)).take_into(Axis(0), &[0, 2], &mut out);
+}
+
+#[test]
+fn gather() {
+    let a = Array::from_shape_fn((3, 4, 2), |(i, j, k)| format!("{}{}{}", i, j, k));
+    let i0 = arr2(&[[2], [0]]);
+    let i1 = arr2(&[[3, 1, 1]]);
+    let i2 = arr2(&[[1, 0, 1]]);
+    let g = a.gather(&[i0.view(), i1.view(), i2.view()]);
+    assert_eq!(g.shape(), &[2, 3]);
+    for ((r, c), x) in g.indexed_iter() {
+        assert_eq!(*x, a[[i0[[r, 0]], i1[[0, c]], i2[[0, c]]]]);
+    }
+
+    let g = a.gather(&[arr1(&[1]), arr1(&[2]), arr1(&[1])]);
+    assert_eq!(g, arr1(&["121".to_string()]));
+    let no_indices: [ArrayViewD<'_, usize>; 0] = [];
+    assert_eq!(aview0(&5).gather(&no_indices), aview0(&5).into_dyn());
+}
+
+#[test]
+#[should_panic]
+fn gather_out_of_bounds() {
+    Array2::<f64>::zeros((3, 2)).gather(&[arr1(&[0, 1]), arr1(&[0, 2])]);
+}
+
+#[test]
+#[should_panic]
+fn gather_incompatible_indices() {
+    Array2::<f64>::zeros((3, 2)).gather(&[arr1(&[0, 1]), arr1(&[0, 1, 0])]);
+}
+
+#[test]
+fn put() {
+    let b = Array::from_shape_fn((3, 4), |(i, j)| i * 4 + j + 1);
+    let mut a = Array2::zeros((3, 4));
+    a.put(Axis(1), &[3, 1, 0, 2], &b.take(Axis(1), &[3, 1, 0, 2]));
+    assert_eq!(a, b);
+
+    let mut a = Array2::zeros((3, 4));
+    a.put(
+        Axis(0),
+        &[1, 2, 1],
+        &arr2(&[[1, 1, 1, 1], [2, 2, 2, 2], [3, 3, 3, 3]]),
+    );
+    assert_eq!(a, arr2(&[[0, 0, 0, 0], [3, 3, 3, 3], [2, 2, 2, 2]]));
+
+    // broadcast along the other axes
+    let mut a = Array2::zeros((3, 4)).reversed_axes();
+    a.put(Axis(1), &[0, 2], &arr2(&[[7, 8]]));
+    assert_eq!(a, Array::from_shape_fn((4, 3), |(_, j)| [7, 0, 8][j]));
+
+    let mut c = Array::zeros((2, 3, 2));
+    c.index_axis_assign(Axis(2), 1, &b.slice(s![..2, ..3]));
+    assert_eq!(c.index_axis(Axis(2), 1), b.slice(s![..2, ..3]));
+    assert_eq!(c.index_axis(Axis(2), 0), Array2::zeros((2, 3)));
+}
+
+#[test]
+#[should_panic]
+fn put_wrong_number_of_values() {
+    let mut a = Array2::<f64>::zeros((3, 2));
+    a.put(Axis(0), &[0, 2], &Array2::zeros((3, 2)));
+}
+
+#[test]
+fn boolean_mask() {
+    let a = Array::from_shape_fn((3, 4), |(i, j)| (i * 4 + j) as i32);
+    let mask = a.mapv(|x| x % 3 == 0);
+    assert_eq!(a.select_where(&mask), arr1(&[0, 3, 6, 9]));
+    assert_eq!(a.t().select_where(&mask.t()), arr1(&[0, 9, 6, 3]));
+    assert_eq!(a.select_where(&Array::from_elem((3, 4), false)).len(), 0);
+
+    let mut b = a.clone();
+    b.fill_where(&mask, -1);
+    assert_eq!(b, a.mapv(|x| if x % 3 == 0 { -1 } else { x }));
+
+    let mut c = a.clone();
+    c.assign_where(&mask, &a.mapv(|x| x * 100));
+    assert_eq!(c, a.mapv(|x| if x % 3 == 0 { x * 100 } else { x }));
+
+    let mut d = a.clone();
+    d.view_mut()
+        .reversed_axes()
+        .assign_where(&mask.t(), &arr1(&[70, 80, 90]));
+    assert_eq!(d, arr2(&[[70, 1, 2, 70], [4, 5, 80, 7], [8, 90, 10, 11]]));
+}
+
+#[test]
+#[should_panic]
+fn select_where_wrong_shape() {
+    let a = Array2::<f64>::zeros((3, 2));
+    a.select_where(&Array::from_elem((2, 3), true));
+}
+
+#[test]
+fn where_() {
+    let a = Array::from_shape_fn((3, 4), |(i, j)| (i * 4 + j) as i32);
+    let b = a.mapv(|x| -x);
+    let cond = a.mapv(|x| x % 2 == 0);
+    let c = ndarray::where_(&cond, &a, &b);
+    assert_eq!(c, a.mapv(|x| if x % 2 == 0 { x } else { -x }));
+    assert_eq!(ndarray::where_(&cond.t(), &a.t(), &b.t()), c.t());
+    assert_eq!(
+        ndarray::where_(&cond, &arr2(&[[1], [2], [3]]), &arr0(0)),
+        arr2(&[[1, 0, 1, 0], [2, 0, 2, 0], [3, 0, 3, 0]])
+    );
+    // the condition is the smaller operand
+    let rows = arr2(&[[true], [false], [true]]);
+    assert_eq!(
+        ndarray::where_(&rows, &a, &b),
+        arr2(&[[0, 1, 2, 3], [-4, -5, -6, -7], [8, 9, 10, 11]])
+    );
+    let c = ndarray::where_(&arr1(&[true, false, false, true]), &a, &arr0(0).into_dyn());
+    assert_eq!(c.shape(), &[3, 4]);
+    assert_eq!(
+        c,
+        a.mapv(|x| if x % 4 == 0 || x % 4 == 3 { x } else { 0 })
+            .into_dyn()
+    );
+}
+
+#[test]
+#[should_panic]
+fn where_wrong_shape() {
+    let a = Array2::<i32>::zeros((3, 4));
+    ndarray::where_(&Array::from_elem((2, 1), true), &a, &a);
+}
+
+#[test]
+fn apply_along_axis() {
+    let a = Array::from_shape_fn((3, 4, 2), |(i, j, k)| ((i * 5 + j * 3 + k * 7) % 11) as i32);
+    for axis in 0..3 {
+        let axis = Axis(axis);
+        let mut b = a.clone();
+        b.apply_along_axis(axis, |mut lane| {
+            let mut v = lane.to_vec();
+            v.sort();
+            lane.assign(&Array::from(v));
+        });
+        for (lane, sorted) in a.lanes(axis).into_iter().zip(b.lanes(axis)) {
+            let mut v = lane.to_vec();
+            v.sort();
+            assert_eq!(sorted.to_vec(), v);
+        }
+    }
+}
+
+#[test]
+fn all_any() {
+    let a = Array::from_shape_fn((3, 4), |(i, j)| (i * 4 + j) as i32);
+    assert!(a.all(|&x| x < 12));
+    assert!(!a.all(|&x| x < 11));
+    assert!(a.any(|&x| x == 11));
+    assert!(!a.any(|&x| x > 11));
+    assert!(a.t().slice(s![..;-1, 1..]).all(|&x| x >= 4));
+
+    let mut visited = 0;
+    assert!(a.any(|&x| {
+        visited += 1;
+        x >= 0
+    }));
+    assert_eq!(visited, 1);
+
+    let empty = Array2::<i32>::zeros((0, 3));
+    assert!(empty.all(|_| false));
+    assert!(!empty.any(|_| true));
+    assert_eq!(
+        empty.all_axis(Axis(0), |_| false),
+        arr1(&[true, true, true])
+    );
+    assert_eq!(
+        empty.any_axis(Axis(0), |_| true),
+        arr1(&[false, false, false])
+    );
+
+    assert_eq!(
+        a.all_axis(Axis(1), |&x| x % 4 != 3),
+        arr1(&[false, false, false])
+    );
+    assert_eq!(
+        a.any_axis(Axis(0), |&x| x > 8),
+        arr1(&[false, true, true, true])
+    );
+    assert_eq!(a.any_axis(Axis(1), |&x| x > 8), arr1(&[false, false, true]));
+}
+
+#[test]
+fn elementwise_comparison() {
+    use ndarray::Zip;
+
+    let a = Array::from_shape_fn((3, 4), |(i, j)| (i * 4 + j) as i32 % 5);
+    let b = Array::from_shape_fn((3, 4), |(i, j)| (j * 3 + i) as i32 % 4);
+    assert_eq!(a.gt(&b), Zip::from(&a).and(&b).apply_collect(|x, y| x > y));
+    assert_eq!(a.ge(&b), Zip::from(&a).and(&b).apply_collect(|x, y| x >= y));
+    assert_eq!(a.lt(&b), Zip::from(&a).and(&b).apply_collect(|x, y| x < y));
+    assert_eq!(a.le(&b), Zip::from(&a).and(&b).apply_collect(|x, y| x <= y));
+    assert_eq!(
+        a.eq_elem(&b),
+        Zip::from(&a).and(&b).apply_collect(|x, y| x == y)
+    );
+    assert_eq!(
+        a.ne_elem(&b),
+        Zip::from(&a).and(&b).apply_collect(|x, y| x != y)
+    );
+
+    assert_eq!(a.t().lt(&b.t()), a.lt(&b).t());
+    assert_eq!(a.eq_elem(&aview0(&0)), a.mapv(|x| x == 0));
+    assert_eq!(
+        a.ge(&arr2(&[[1], [2], [3]])),
+        Array::from_shape_fn((3, 4), |(i, j)| a[[i, j]] >= i as i32 + 1)
+    );
+
+    let nan = std::f64::NAN;
+    let x = arr1(&[1., nan]);
+    assert_eq!(x.ge(&aview0(&1.)), arr1(&[true, false]));
+    assert_eq!(x.ne_elem(&x), arr1(&[false, true]));
+
+    assert_eq!(a.gt_scalar(&2), a.gt(&aview0(&2)));
+    assert_eq!(a.ge_scalar(&2), a.mapv(|x| x >= 2));
+    assert_eq!(a.lt_scalar(&2), a.mapv(|x| x < 2));
+    assert_eq!(a.le_scalar(&2), a.mapv(|x| x <= 2));
+    assert_eq!(a.eq_elem_scalar(&0), a.eq_elem(&aview0(&0)));
+    assert_eq!(a.t().ne_elem_scalar(&0), a.mapv(|x| x != 0).t());
+    assert_eq!(x.ge_scalar(&1.), arr1(&[true, false]));
+    assert_eq!(x.ne_elem_scalar(&nan), arr1(&[true, true]));
+}
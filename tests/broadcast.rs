@@ -1,4 +1,5 @@
 use ndarray::prelude::*;
+use ndarray::ErrorKind;
 
 #[test]
 fn broadcast_1() {
@@ -78,3 +79,89 @@ fn test_broadcast_1d() {
     println!("b2=\n{:?}", b2);
     assert_eq!(b0, b2);
 }
+
+#[test]
+fn test_co_broadcast_ops() {
+    let row = arr2(&[[1, 2, 3]]);
+    let col = arr2(&[[10], [20]]);
+    let sum = arr2(&[[11, 12, 13], [21, 22, 23]]);
+    assert_eq!(&row + &col, sum);
+    assert_eq!(&col + &row, sum);
+    assert_eq!(row.clone() + &col, sum);
+    assert_eq!(col.clone() + row.clone(), sum);
+    assert_eq!(&col - &arr1(&[1, 2, 3]), arr2(&[[9, 8, 7], [19, 18, 17]]));
+
+    // the result has the larger dimensionality
+    let v = arr1(&[1., 2.]);
+    let m = Array::from_elem((3, 1, 2), 1.);
+    let p: Array3<f64> = &v * &m;
+    assert_eq!(p, Array::from_shape_fn((3, 1, 2), |(_, _, k)| v[k]));
+    let q: Array3<f64> = v.clone() * &m;
+    assert_eq!(q, p);
+    let d: ArrayD<f64> = &v + &m.clone().into_dyn();
+    assert_eq!(d.shape(), &[3, 1, 2]);
+
+    // the owned lhs is reused when it has the result's shape
+    let a = Array::from_elem((2, 3), 1);
+    let ptr = a.as_ptr();
+    let a = a + &arr1(&[1, 2, 3]);
+    assert_eq!(a.as_ptr(), ptr);
+    assert_eq!(a, arr2(&[[2, 3, 4], [2, 3, 4]]));
+}
+
+#[test]
+#[should_panic]
+fn test_co_broadcast_incompat() {
+    let _ = &arr2(&[[1, 2, 3]]) + &arr2(&[[1, 2], [3, 4]]);
+}
+
+#[test]
+fn test_broadcast_with() {
+    let a = Array::from_elem((3, 1), 1);
+    let b = Array::from_elem((2, 1, 4), 2);
+    let (a2, b2) = a.broadcast_with(&b).unwrap();
+    assert_eq!(a2, Array::from_elem((2, 3, 4), 1));
+    assert_eq!(b2, Array::from_elem((2, 3, 4), 2));
+    let c = Array::from_elem(4, 0);
+    let (a2, c2) = a.broadcast_with(&c).unwrap();
+    assert_eq!(a2.shape(), &[3, 4]);
+    assert_eq!(c2.shape(), &[3, 4]);
+
+    let err = Array::from_elem((2, 3), 0).broadcast_with(&c).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::IncompatibleShape);
+}
+
+#[test]
+fn test_assign_op_broadcast() {
+    let mut a = arr2(&[[1., 2., 3.], [3., 6., 9.]]);
+    let mean = a.mean_axis(Axis(0)).unwrap();
+    a -= &mean;
+    assert_eq!(a, arr2(&[[-1., -2., -3.], [1., 2., 3.]]));
+
+    let scale = arr2(&[[2.], [10.]]);
+    a *= scale;
+    assert_eq!(a, arr2(&[[-2., -4., -6.], [10., 20., 30.]]));
+
+    let mut v = a.slice_mut(s![.., 1..]);
+    v += aview0(&1.);
+    v /= arr1(&[3., 7.]).view();
+    assert_eq!(a, arr2(&[[-2., -1., -5. / 7.], [10., 7., 31. / 7.]]));
+}
+
+#[test]
+fn test_zip_mut_with_broadcast() {
+    let mut a = Array::from_elem((2, 3), 1);
+    a.zip_mut_with(&arr1(&[1, 2, 3]), |x, &y| *x += y);
+    assert_eq!(a, arr2(&[[2, 3, 4], [2, 3, 4]]));
+    a.zip_mut_with(&arr2(&[[0], [10]]), |x, &y| *x *= y);
+    assert_eq!(a, arr2(&[[0, 0, 0], [20, 30, 40]]));
+    a.zip_mut_with(&aview0(&5), |x, &y| *x -= y);
+    assert_eq!(a, arr2(&[[-5, -5, -5], [15, 25, 35]]));
+}
+
+#[test]
+#[should_panic]
+fn test_zip_mut_with_incompat() {
+    let mut a = Array::from_elem((2, 3), 1);
+    a.zip_mut_with(&arr1(&[1, 2]), |x, &y| *x += y);
+}
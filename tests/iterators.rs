@@ -168,6 +168,25 @@ fn inner_iter() {
     );
 }
 
+#[test]
+fn lanes() {
+    let a = Array::from_iter(0..24).into_shape((2, 3, 4)).unwrap();
+    assert_equal(a.lanes(Axis(2)), a.genrows());
+    Zip::indexed(a.lanes(Axis(1))).apply(|(i, k), lane| {
+        assert_eq!(lane, a.slice(s![i, .., k]));
+    });
+
+    let mut b = a.clone();
+    for mut lane in b.lanes_mut(Axis(0)) {
+        lane[1] -= lane[0];
+    }
+    assert_eq!(b.index_axis(Axis(0), 1), Array::from_elem((3, 4), 12));
+    Zip::from(b.lanes_mut(Axis(1)))
+        .and(a.lanes(Axis(1)))
+        .apply(|mut x, y| x.assign(&y));
+    assert_eq!(b, a);
+}
+
 #[test]
 fn inner_iter_corner_cases() {
     let a0 = ArcArray::<i32, _>::zeros(());
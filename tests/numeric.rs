@@ -7,7 +7,9 @@
 )]
 
 use approx::assert_abs_diff_eq;
-use ndarray::{arr0, arr1, arr2, array, aview1, Array, Array1, Array2, Array3, Axis};
+use ndarray::{
+    arr0, arr1, arr2, array, aview0, aview1, s, Array, Array1, Array2, Array3, Axis, Zip,
+};
 use std::f64;
 
 #[test]
@@ -225,3 +227,401 @@ fn std_axis_empty_axis() {
     assert_eq!(v.shape(), &[2]);
     v.mapv(|x| assert!(x.is_nan()));
 }
+
+#[test]
+fn clamp() {
+    let a = arr2(&[[-3, 0, 7], [2, 9, 5]]);
+    let c = a.clamp(0, 5);
+    assert_eq!(c, arr2(&[[0, 0, 5], [2, 5, 5]]));
+    let mut b = a.clone();
+    b.clamp_inplace(0, 5);
+    assert_eq!(b, c);
+
+    let mut t = a.t().to_owned();
+    t.slice_mut(s![.., 1]).clamp_inplace(4, 4);
+    assert_eq!(t, arr2(&[[-3, 4], [0, 4], [7, 4]]));
+
+    let x = array![f64::NEG_INFINITY, -0.5, 0.25, f64::NAN, 2.];
+    let y = x.clamp(0., 1.);
+    assert_eq!(y.slice(s![..3]), aview1(&[0., 0., 0.25]));
+    assert!(y[3].is_nan());
+    assert_eq!(y[4], 1.);
+}
+
+#[test]
+#[should_panic]
+fn clamp_reversed_bounds() {
+    arr1(&[1, 2]).clamp(2, 1);
+}
+
+#[test]
+#[should_panic]
+fn clamp_nan_bound() {
+    arr1(&[1., 2.]).clamp(f64::NAN, 1.);
+}
+
+#[test]
+fn cumsum_cumprod() {
+    let a = Array::from_shape_fn((3, 4, 2), |(i, j, k)| (i + 2 * j + 3 * k + 1) as i64);
+    for axis in 0..3 {
+        let axis = Axis(axis);
+        let cumsum = a.cumsum(axis);
+        let cumprod = a.cumprod(axis);
+        for i in 0..a.len_of(axis) {
+            let head = a.slice_axis(axis, (..=i).into());
+            assert_eq!(cumsum.index_axis(axis, i), head.sum_axis(axis));
+            assert_eq!(
+                cumprod.index_axis(axis, i),
+                head.fold_axis(axis, 1, |&acc, &x| acc * x)
+            );
+        }
+        assert_eq!(a.t().cumsum(Axis(2 - axis.index())), cumsum.t());
+    }
+
+    let empty = Array2::<f64>::zeros((0, 3));
+    assert_eq!(empty.cumsum(Axis(0)), empty);
+}
+
+#[test]
+fn diff() {
+    let a = Array::from_shape_fn((4, 3), |(i, j)| (i * i * (j + 1)) as i32);
+    assert_eq!(a.diff(Axis(0), 0), a);
+    assert_eq!(
+        a.diff(Axis(0), 1),
+        arr2(&[[1, 2, 3], [3, 6, 9], [5, 10, 15]])
+    );
+    assert_eq!(a.diff(Axis(0), 2), arr2(&[[2, 4, 6], [2, 4, 6]]));
+    assert_eq!(a.diff(Axis(0), 3), Array2::zeros((1, 3)));
+    assert_eq!(a.diff(Axis(0), 4).shape(), &[0, 3]);
+    assert_eq!(a.diff(Axis(0), 10).shape(), &[0, 3]);
+    assert_eq!(a.t().diff(Axis(1), 2), a.diff(Axis(0), 2).t());
+    assert_eq!(a.diff(Axis(1), 1), arr2(&[[0, 0], [1, 1], [4, 4], [9, 9]]));
+}
+
+#[test]
+#[cfg(feature = "approx")]
+fn gradient() {
+    let x = Array::linspace(0_f64, 2., 9);
+    let a = x.mapv(|x| x * x);
+    let g = a.gradient(Axis(0), 0.25);
+    // central differences are exact for quadratics
+    assert_abs_diff_eq!(
+        g.slice(s![1..-1]),
+        x.slice(s![1..-1]).mapv(|x| 2. * x),
+        epsilon = 1e-12
+    );
+    assert_abs_diff_eq!(g[0], 0.25, epsilon = 1e-12);
+    assert_abs_diff_eq!(g[8], 3.75, epsilon = 1e-12);
+
+    let b = Array::from_shape_fn((2, 3, 2), |(i, j, k)| (i * 6 + j * j + k) as f64);
+    assert_eq!(
+        b.gradient(Axis(1), 1.),
+        Array::from_shape_fn((2, 3, 2), |(_, j, _)| [1., 2., 3.][j])
+    );
+    assert_eq!(b.gradient(Axis(2), 2.), Array::from_elem((2, 3, 2), 0.5));
+    assert_eq!(b.gradient(Axis(0), 3.), Array::from_elem((2, 3, 2), 2.));
+}
+
+#[test]
+#[should_panic]
+fn gradient_too_short() {
+    arr1(&[1.]).gradient(Axis(0), 1.);
+}
+
+#[test]
+fn argmax_argmin() {
+    let a = arr2(&[[3, 7, 7], [1, 9, 1], [9, 0, 2]]);
+    assert_eq!(a.argmax(), Some((1, 1)));
+    assert_eq!(a.argmin(), Some((2, 1)));
+    assert_eq!(a.t().argmax(), Some((0, 2)));
+    assert_eq!(a.t().argmin(), Some((1, 2)));
+    assert_eq!(a.argmax_axis(Axis(0)), arr1(&[2, 1, 0]));
+    assert_eq!(a.argmin_axis(Axis(0)), arr1(&[1, 2, 1]));
+    assert_eq!(a.argmax_axis(Axis(1)), arr1(&[1, 1, 0]));
+    assert_eq!(a.argmin_axis(Axis(1)), arr1(&[0, 0, 1]));
+    assert_eq!(a.into_dyn().argmax(), Some(ndarray::IxDyn(&[1, 1])));
+
+    assert_eq!(Array2::<f64>::zeros((0, 3)).argmax(), None);
+    assert_eq!(
+        Array2::<f64>::zeros((3, 0)).argmax_axis(Axis(0)).shape(),
+        &[0]
+    );
+    assert_eq!(arr0(1).argmin(), Some(()));
+
+    let x = array![[1., f64::NAN, 3.], [f64::NAN, 2., f64::NAN]];
+    assert_eq!(x.argmax(), Some((0, 1)));
+    assert_eq!(x.argmin(), Some((0, 1)));
+    assert_eq!(x.argmax_axis(Axis(1)), arr1(&[1, 0]));
+    assert_eq!(x.argmin_axis(Axis(0)), arr1(&[1, 0, 1]));
+}
+
+#[test]
+#[should_panic]
+fn argmax_axis_empty() {
+    Array2::<f64>::zeros((0, 3)).argmax_axis(Axis(0));
+}
+
+#[test]
+fn bincount() {
+    let a = Array::from_shape_fn(30, |i| (i * i % 7) as u8).slice_move(s![..;-2]);
+    let counts = a.bincount(0);
+    assert_eq!(counts.len(), *a.iter().max().unwrap() as usize + 1);
+    for (v, &c) in counts.iter().enumerate() {
+        assert_eq!(a.iter().filter(|&&x| x as usize == v).count(), c);
+    }
+    assert_eq!(counts.sum(), a.len());
+    assert_eq!(arr1(&[0i64; 0]).bincount(3), arr1(&[0, 0, 0]));
+    assert_eq!(arr1(&[2i32, 0, 2, 4]).bincount(0), arr1(&[1, 0, 2, 0, 1]));
+    assert_eq!(
+        arr1(&[1i16, 0, 1]).bincount_weighted(&arr1(&[0.5, 1., 2.]), 0),
+        arr1(&[1., 2.5])
+    );
+
+    let weights = Array::from_shape_fn(a.len(), |i| i as f64);
+    let sums = a.bincount_weighted(&weights, 10);
+    assert_eq!(sums.len(), 10);
+    for (v, &s) in sums.iter().enumerate() {
+        let expected: f64 = a
+            .iter()
+            .zip(&weights)
+            .filter(|&(&x, _)| x as usize == v)
+            .map(|(_, &w)| w)
+            .sum();
+        assert_eq!(s, expected);
+    }
+}
+
+#[test]
+#[should_panic]
+fn bincount_negative() {
+    arr1(&[1, -1]).bincount(0);
+}
+
+#[test]
+#[should_panic]
+fn bincount_weighted_negative() {
+    arr1(&[1i8, -1]).bincount_weighted(&arr1(&[1., 1.]), 0);
+}
+
+#[test]
+fn product_min_max_axis() {
+    let a = Array::from_shape_fn((3, 4, 2), |(i, j, k)| {
+        ((i * 5 + j * 3 + k * 7) % 11) as i64 - 4
+    });
+    for axis in 0..3 {
+        let axis = Axis(axis);
+        assert_eq!(
+            a.product_axis(axis),
+            a.fold_axis(axis, 1, |&acc, &x| acc * x)
+        );
+        assert_eq!(
+            a.min_axis(axis),
+            a.fold_axis(axis, i64::max_value(), |&acc, &x| acc.min(x))
+        );
+        assert_eq!(
+            a.max_axis(axis),
+            a.fold_axis(axis, i64::min_value(), |&acc, &x| acc.max(x))
+        );
+    }
+    assert_eq!(
+        Array2::<f64>::zeros((0, 3)).product_axis(Axis(0)),
+        arr1(&[1., 1., 1.])
+    );
+
+    let nan = f64::NAN;
+    let x = arr2(&[[1., nan, 3.], [nan, 2., 0.]]);
+    let max = x.max_axis(Axis(0));
+    assert!(max[0].is_nan() && max[1].is_nan());
+    assert_eq!(max[2], 3.);
+    let min = x.min_axis(Axis(1));
+    assert!(min[0].is_nan() && min[1].is_nan());
+}
+
+#[test]
+#[should_panic]
+fn max_axis_empty() {
+    Array2::<f64>::zeros((0, 3)).max_axis(Axis(0));
+}
+
+#[test]
+fn reduce_axes() {
+    let a = Array::from_shape_fn((3, 4, 2, 5), |(i, j, k, l)| {
+        ((i * 5 + j * 3 + k * 7 + l) % 11) as f64
+    });
+    let s = a.sum_axes(&[Axis(3), Axis(1)]);
+    assert_eq!(s, a.sum_axis(Axis(3)).sum_axis(Axis(1)).into_dyn());
+    assert_eq!(a.t().sum_axes(&[Axis(0), Axis(2)]), s.t());
+    assert_eq!(
+        a.product_axes(&[Axis(2)]),
+        a.product_axis(Axis(2)).into_dyn()
+    );
+    assert_eq!(a.sum_axes(&[]), a.clone().into_dyn());
+    assert_eq!(
+        a.sum_axes(&[Axis(0), Axis(1), Axis(2), Axis(3)]),
+        arr0(a.sum()).into_dyn()
+    );
+    let mean = a.mean_axes(&[Axis(0), Axis(2)]).unwrap();
+    let expected = a.mean_axis(Axis(2)).unwrap().mean_axis(Axis(0)).unwrap();
+    assert_eq!(mean.shape(), expected.shape());
+    assert!(mean
+        .iter()
+        .zip(&expected)
+        .all(|(x, y)| (x - y).abs() < 1e-12));
+    assert_eq!(Array3::<f64>::zeros((2, 0, 3)).mean_axes(&[Axis(1)]), None);
+    assert_eq!(
+        Array3::<f64>::zeros((2, 0, 3)).sum_axes(&[Axis(1)]),
+        Array::zeros((2, 3)).into_dyn()
+    );
+}
+
+#[test]
+#[should_panic]
+fn reduce_axes_repeated_axis() {
+    Array3::<f64>::zeros((2, 3, 4)).sum_axes(&[Axis(1), Axis(1)]);
+}
+
+#[test]
+fn keepdims() {
+    let a = Array::from_shape_fn((3, 4, 2), |(i, j, k)| ((i * 5 + j * 3 + k * 7) % 11) as f64);
+    for axis in 0..3 {
+        let axis = Axis(axis);
+        let mut shape = a.raw_dim();
+        shape[axis.index()] = 1;
+        let kept = |reduced: ndarray::Array2<f64>| reduced.insert_axis(axis);
+        assert_eq!(a.sum_axis_keepdims(axis), kept(a.sum_axis(axis)));
+        assert_eq!(a.product_axis_keepdims(axis), kept(a.product_axis(axis)));
+        assert_eq!(a.min_axis_keepdims(axis), kept(a.min_axis(axis)));
+        assert_eq!(a.max_axis_keepdims(axis), kept(a.max_axis(axis)));
+        assert_eq!(
+            a.mean_axis_keepdims(axis).unwrap(),
+            kept(a.mean_axis(axis).unwrap())
+        );
+        assert_eq!(a.var_axis_keepdims(axis, 1.), kept(a.var_axis(axis, 1.)));
+        assert_eq!(a.std_axis_keepdims(axis, 0.), kept(a.std_axis(axis, 0.)));
+        assert_eq!(a.sum_axis_keepdims(axis).raw_dim(), shape);
+        assert_eq!(
+            (&a - &a.mean_axis_keepdims(axis).unwrap()).shape(),
+            a.shape()
+        );
+    }
+    assert_eq!(
+        Array2::<f64>::zeros((2, 0)).mean_axis_keepdims(Axis(1)),
+        None
+    );
+}
+
+#[test]
+fn keepdims_permuted() {
+    // min_axis and max_axis keep the strides of the permuted input, so their
+    // result is contiguous but in neither standard nor Fortran layout
+    let a = Array::from_shape_fn((2, 3, 4, 5), |(i, j, k, l)| {
+        ((i * 7 + j * 5 + k * 3 + l) % 13) as f64
+    })
+    .permuted_axes([0, 2, 1, 3]);
+    for axis in 0..4 {
+        let axis = Axis(axis);
+        let kept = |reduced: ndarray::Array3<f64>| reduced.insert_axis(axis);
+        assert_eq!(a.min_axis_keepdims(axis), kept(a.min_axis(axis)));
+        assert_eq!(a.max_axis_keepdims(axis), kept(a.max_axis(axis)));
+        assert_eq!(a.sum_axis_keepdims(axis), kept(a.sum_axis(axis)));
+    }
+}
+
+#[test]
+fn float_maths() {
+    let a = Array::linspace(0.1f64, 0.9, 12).into_shape((3, 4)).unwrap();
+    let check = |x: Array2<f64>, f: fn(f64) -> f64| assert_eq!(x, a.mapv(f));
+    check(a.exp(), f64::exp);
+    check(a.ln(), f64::ln);
+    check(a.log2(), f64::log2);
+    check(a.ln_1p(), f64::ln_1p);
+    check(a.sqrt(), f64::sqrt);
+    check(a.sin(), f64::sin);
+    check(a.acos(), f64::acos);
+    check(a.tanh(), f64::tanh);
+    check(a.atanh(), f64::atanh);
+    check(a.round(), f64::round);
+    check(a.to_degrees(), f64::to_degrees);
+
+    let mut b = a.t().to_owned();
+    b.cos_inplace();
+    assert_eq!(b, a.t().cos());
+    let mut c = a.mapv(|x| -x);
+    c.slice_mut(s![.., ..;2]).abs_inplace();
+    assert_eq!(c.abs(), a);
+    assert_eq!(c.signum().sum(), 0.);
+}
+
+#[test]
+fn float_maths_binary() {
+    let a = Array::linspace(0.25f64, 3., 12).into_shape((3, 4)).unwrap();
+    let b = Array::linspace(0.5f64, 4., 12)
+        .into_shape((4, 3))
+        .unwrap()
+        .reversed_axes();
+    let check = |x: Array2<f64>, f: fn(f64, f64) -> f64| {
+        assert_eq!(x, Zip::from(&a).and(&b).apply_collect(|&x, &y| f(x, y)))
+    };
+    check(a.powf(&b), f64::powf);
+    check(a.atan2(&b), f64::atan2);
+    check(a.hypot(&b), f64::hypot);
+    check(a.log(&b), f64::log);
+    check(a.max(&b), f64::max);
+    check(a.min(&b), f64::min);
+
+    assert_eq!(a.atan2(&arr1(&[1., 2., 3., 4.])).shape(), &[3, 4]);
+    assert_eq!(a.max(&aview0(&0.)), a.mapv(|x| x.max(0.)));
+    assert_eq!(a.powi(3), a.mapv(|x| x.powi(3)));
+    let mut c = a.clone();
+    c.powi_inplace(-2);
+    assert_eq!(c, a.mapv(|x| x.powi(-2)));
+}
+
+#[test]
+fn integer_overflow_arithmetic() {
+    let a = arr2(&[[120i8, -100], [-128, 7]]);
+    let b = arr1(&[10i8, 30]);
+
+    assert_eq!(a.checked_add(&b), None);
+    assert_eq!(a.checked_sub(&aview0(&1)), None);
+    assert_eq!(a.checked_mul(&aview0(&2)), None);
+    assert_eq!(a.checked_div(&aview0(&-1)), None);
+    assert_eq!(a.checked_div(&arr1(&[1, 0])), None);
+    assert_eq!(
+        a.checked_add(&arr1(&[7, 27])).unwrap(),
+        arr2(&[[127, -73], [-121, 34]])
+    );
+    assert_eq!(
+        a.checked_div(&aview0(&2)).unwrap(),
+        arr2(&[[60, -50], [-64, 3]])
+    );
+
+    assert_eq!(a.saturating_add(&b), arr2(&[[127, -70], [-118, 37]]));
+    assert_eq!(a.saturating_sub(&b), arr2(&[[110, -128], [-128, -23]]));
+    assert_eq!(a.wrapping_add(&b), arr2(&[[-126, -70], [-118, 37]]));
+    assert_eq!(a.wrapping_sub(&b), arr2(&[[110, 126], [118, -23]]));
+    assert_eq!(a.wrapping_mul(&aview0(&2)), arr2(&[[-16, 56], [0, 14]]));
+}
+
+#[test]
+fn cast() {
+    let a = array![[0.5f64, -3.7], [1e10, 255.]];
+    assert_eq!(a.cast::<f32>(), a.mapv(|x| x as f32));
+    assert_eq!(a.cast::<i64>(), array![[0, -3], [10_000_000_000, 255]]);
+    assert_eq!(array![-1i32, 256].cast::<u8>(), array![255, 0]);
+    assert_eq!(a.t().cast::<i64>(), a.cast::<i64>().t());
+
+    assert_eq!(a.try_cast::<i64>(), None);
+    assert_eq!(a.try_cast::<f32>(), None);
+    let b = a.mapv(f64::trunc);
+    assert_eq!(b.try_cast::<i64>(), Some(b.cast::<i64>()));
+    assert_eq!(b.try_cast::<i32>(), None);
+    assert_eq!(
+        array![1u64 << 53, 3].try_cast::<f64>(),
+        Some(array![9007199254740992., 3.])
+    );
+    assert_eq!(array![(1u64 << 53) + 1].try_cast::<f64>(), None);
+    let nan = array![std::f64::NAN, 1.].try_cast::<f32>().unwrap();
+    assert!(nan[0].is_nan());
+    assert_eq!(array![std::f64::NAN].try_cast::<i32>(), None);
+}
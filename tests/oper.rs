@@ -6,10 +6,16 @@
 )]
 
 use ndarray::linalg::general_mat_mul;
+use ndarray::linalg::Givens;
+use ndarray::linalg::{banded_mat_vec_mul, BandedMatrix};
+use ndarray::linalg::{hermitian_mat_mul, hermitian_rank_k_update, Hermitian};
+use ndarray::linalg::{solve_triangular_mat, solve_triangular_vec, Triangle};
+use ndarray::linalg::{symmetric_mat_mul, symmetric_rank_k_update, Symmetric};
 use ndarray::prelude::*;
 use ndarray::{rcarr1, rcarr2};
 use ndarray::{Data, LinalgScalar};
 use ndarray::{Ix, Ixs};
+use num_complex::Complex64;
 use std::iter::FromIterator;
 
 use approx::assert_abs_diff_eq;
@@ -147,6 +153,37 @@ fn scalar_operations() {
     }
 }
 
+#[test]
+fn bitwise_operations() {
+    let a = arr2(&[[0b1100u8, 0b1010], [0xff, 0]]);
+    let b = arr2(&[[0b1010u8, 0b0110], [0x0f, 1]]);
+    assert_eq!(&a & &b, arr2(&[[0b1000, 0b0010], [0x0f, 0]]));
+    assert_eq!(&a | &b, arr2(&[[0b1110, 0b1110], [0xff, 1]]));
+    assert_eq!(&a ^ &b, arr2(&[[0b0110, 0b1100], [0xf0, 1]]));
+    assert_eq!(!&a, arr2(&[[0xf3, 0xf5], [0, 0xff]]));
+
+    assert_eq!(&a & 0b1000, arr2(&[[0b1000, 0b1000], [0b1000, 0]]));
+    assert_eq!(0x0f ^ &a, arr2(&[[0b0011, 0b0101], [0xf0, 0x0f]]));
+    assert_eq!(&a >> 2, arr2(&[[0b11, 0b10], [0x3f, 0]]));
+    assert_eq!(1 << arr1(&[0u32, 3, 5]), arr1(&[1, 8, 32]));
+    assert_eq!(
+        &a << &arr1(&[1, 4]),
+        arr2(&[[0b11000, 0b1010_0000], [0xfe, 0]])
+    );
+
+    let mut x = a.clone();
+    x |= &arr1(&[1, 0]);
+    x >>= 1;
+    assert_eq!(x, arr2(&[[0b110, 0b101], [0x7f, 0]]));
+
+    let mask = arr1(&[true, false, true]);
+    assert_eq!(
+        &mask & &arr1(&[true, true, false]),
+        arr1(&[true, false, false])
+    );
+    assert_eq!(!mask, arr1(&[false, true, false]));
+}
+
 fn reference_dot<'a, A, V1, V2>(a: V1, b: V2) -> A
 where
     A: NdFloat,
@@ -509,6 +546,38 @@ fn mat_mut_zero_len() {
     mat_mul_zero_len!(range_i32);
 }
 
+#[test]
+fn batch_mat_mul() {
+    defmac!(batch_mat_mul range_mat_fn => {
+        for &(batch, m, k, n) in &[(3, 4, 5, 6), (2, 17, 3, 16), (1, 8, 8, 8), (0, 3, 4, 5), (4, 0, 2, 3)] {
+            let a = range_mat_fn(batch * m, k).into_shape((batch, m, k)).unwrap();
+            let b = range_mat_fn(batch * k, n).into_shape((batch, k, n)).unwrap();
+            let c = a.dot(&b);
+            assert_eq!(c.dim(), (batch, m, n));
+            for i in 0..batch {
+                let c_i = reference_mat_mul(&a.index_axis(Axis(0), i), &b.index_axis(Axis(0), i));
+                assert_eq!(c.index_axis(Axis(0), i), c_i);
+            }
+            // non-contiguous operands
+            let bt = b.to_owned().permuted_axes([0, 2, 1]);
+            let mut bt_f = Array::zeros((batch, n, k).f());
+            bt_f.assign(&bt);
+            assert_eq!(a.dot(&bt_f.permuted_axes([0, 2, 1])), c);
+        }
+    });
+    batch_mat_mul!(range_mat);
+    batch_mat_mul!(range_mat64);
+    batch_mat_mul!(range_i32);
+}
+
+#[test]
+#[should_panic]
+fn batch_mat_mul_shape_mismatch() {
+    let a = range_mat(6, 4).into_shape((2, 3, 4)).unwrap();
+    let b = range_mat(12, 5).into_shape((3, 4, 5)).unwrap();
+    a.dot(&b);
+}
+
 #[test]
 fn scaled_add() {
     let a = range_mat(16, 15);
@@ -598,7 +667,7 @@ fn scaled_add_3() {
 
                 {
                     let mut av = a.slice_mut(s![..;s1, ..;s2]);
-                    let c = c.slice(SliceInfo::<_, IxDyn>::new(cslice).unwrap().as_ref());
+                    let c = c.slice(SliceInfo::<_, IxDyn, IxDyn>::new(cslice).unwrap().as_ref());
 
                     let mut answerv = answer.slice_mut(s![..;s1, ..;s2]);
                     answerv += &(beta * &c);
@@ -818,3 +887,1119 @@ fn vec_mat_mul() {
         }
     }
 }
+
+// triangular matrix with a dominant diagonal, zeros in the other triangle
+fn triangular_mat64(n: Ix, uplo: Triangle) -> Array2<f64> {
+    Array::from_shape_fn((n, n), |(i, j)| match uplo {
+        _ if i == j => n as f64 + i as f64,
+        Triangle::Upper if i < j => (i * n + j) as f64 / (n * n) as f64 - 0.5,
+        Triangle::Lower if i > j => (i * n + j) as f64 / (n * n) as f64 - 0.5,
+        _ => 0.,
+    })
+}
+
+#[cfg(feature = "approx")]
+#[test]
+fn solve_triangular() {
+    for &n in &[0, 1, 4, 17, 40] {
+        for &uplo in &[Triangle::Upper, Triangle::Lower] {
+            let a = triangular_mat64(n, uplo);
+            let mut af = Array::zeros((n, n).f());
+            af.assign(&a);
+            // fill the other triangle to check that it's never read
+            let a_dirty = Array::from_shape_fn((n, n), |(i, j)| match uplo {
+                Triangle::Upper if i > j => 99.,
+                Triangle::Lower if i < j => 99.,
+                _ => a[[i, j]],
+            });
+            let x = range_mat64(n, 3);
+            let b = reference_mat_mul(&a, &x);
+
+            for a in &[a.view(), af.view(), a_dirty.view()] {
+                let mut b_mat = b.clone();
+                solve_triangular_mat(uplo, a, &mut b_mat);
+                approx::assert_relative_eq!(b_mat, x, epsilon = 1e-9, max_relative = 1e-9);
+
+                let mut b_f = Array::zeros((n, 3).f());
+                b_f.assign(&b);
+                solve_triangular_mat(uplo, a, &mut b_f);
+                approx::assert_relative_eq!(b_f, x, epsilon = 1e-9, max_relative = 1e-9);
+
+                for &stride in &[1, 2, -1] {
+                    let mut b_vec = Array::zeros(2 * n);
+                    let mut b_vec = b_vec.slice_mut(s![..;stride]);
+                    let mut b_vec = b_vec.slice_mut(s![..n]);
+                    b_vec.assign(&b.column(1));
+                    solve_triangular_vec(uplo, a, &mut b_vec);
+                    approx::assert_relative_eq!(
+                        b_vec,
+                        x.column(1),
+                        epsilon = 1e-9,
+                        max_relative = 1e-9
+                    );
+                }
+            }
+        }
+    }
+}
+
+#[test]
+#[should_panic]
+fn solve_triangular_shape_mismatch() {
+    let a = triangular_mat64(4, Triangle::Upper);
+    let mut b = range_mat64(5, 2);
+    solve_triangular_mat(Triangle::Upper, &a, &mut b);
+}
+
+fn in_triangle(uplo: Triangle, i: Ix, j: Ix) -> bool {
+    match uplo {
+        Triangle::Upper => i <= j,
+        Triangle::Lower => i >= j,
+    }
+}
+
+#[test]
+fn symmetric_rank_k() {
+    for &(n, k) in &[(0, 3), (1, 1), (4, 4), (5, 2), (3, 17), (17, 15)] {
+        for &uplo in &[Triangle::Upper, Triangle::Lower] {
+            let a = range_i32(n, k);
+            let c = range_i32(n, n);
+            let full = reference_mat_mul(&a, &a.t()) * 2 + &c * 3;
+            let answer = Array::from_shape_fn((n, n), |(i, j)| {
+                if in_triangle(uplo, i, j) {
+                    full[[i, j]]
+                } else {
+                    c[[i, j]]
+                }
+            });
+            let mut c_i32 = c.clone();
+            symmetric_rank_k_update(2, &a, 3, &mut c_i32, uplo);
+            assert_eq!(c_i32, answer);
+
+            let a = a.mapv(f64::from);
+            let mut af = Array::zeros((n, k).f());
+            af.assign(&a);
+            let answer = answer.mapv(f64::from);
+            for a in &[a.view(), af.view()] {
+                let mut c_f64 = c.mapv(f64::from);
+                symmetric_rank_k_update(2., a, 3., &mut c_f64, uplo);
+                assert_eq!(c_f64, answer);
+
+                let mut c_f = Array::zeros((n, n).f());
+                c_f.assign(&c.mapv(f64::from));
+                symmetric_rank_k_update(2., a, 3., &mut c_f, uplo);
+                assert_eq!(c_f, answer);
+            }
+        }
+    }
+}
+
+#[test]
+fn symmetric_mat_mul_triangles() {
+    for &(m, n) in &[(0, 3), (1, 1), (4, 4), (5, 2), (3, 17), (17, 15)] {
+        for &uplo in &[Triangle::Upper, Triangle::Lower] {
+            let a_full = Array::from_shape_fn((m, m), |(i, j)| (i * j + i + j) as f64);
+            // fill the other triangle to check that it's never read
+            let a = Array::from_shape_fn((m, m), |(i, j)| {
+                if in_triangle(uplo, i, j) {
+                    a_full[[i, j]]
+                } else {
+                    -99.
+                }
+            });
+            let mut af = Array::zeros((m, m).f());
+            af.assign(&a);
+            let b = range_mat64(m, n);
+            let c = range_mat64(m, n);
+            let answer = reference_mat_mul(&a_full, &b) * 2. - &c;
+
+            let sym = Symmetric::new(a.view(), uplo);
+            assert_eq!(sym.to_full(), a_full);
+            for a in &[a.view(), af.view()] {
+                let mut c1 = c.clone();
+                symmetric_mat_mul(2., Symmetric::new(a.view(), uplo), &b, -1., &mut c1);
+                assert_eq!(c1, answer);
+                let mut c2 = c.clone();
+                symmetric_mat_mul(2., Symmetric::new(a.view(), uplo), &b.t().t(), -1., &mut c2);
+                assert_eq!(c2, answer);
+            }
+        }
+    }
+}
+
+#[test]
+#[should_panic]
+fn symmetric_rank_k_shape_mismatch() {
+    let a = range_mat64(4, 3);
+    let mut c = range_mat64(3, 3);
+    symmetric_rank_k_update(1., &a, 0., &mut c, Triangle::Upper);
+}
+
+#[test]
+fn rank1_update() {
+    for &(m, n) in &[(0, 3), (1, 1), (4, 4), (5, 2), (3, 17), (17, 15)] {
+        let x = Array::from_iter(0..m as i32);
+        let y = Array::from_iter((0..n as i32).map(|i| 2 * i - 3));
+        let a = range_i32(m, n);
+        let answer = &a
+            + &(reference_mat_mul(
+                &x.view().insert_axis(Axis(1)),
+                &y.view().insert_axis(Axis(0)),
+            ) * 3);
+
+        let mut a1 = a.clone();
+        a1.general_rank1_update(3, &x, &y);
+        assert_eq!(a1, answer);
+
+        let mut af = Array::zeros((m, n).f());
+        af.assign(&a);
+        af.general_rank1_update(3, &x.slice(s![..;-1]).slice(s![..;-1]), &y);
+        assert_eq!(af, answer);
+
+        let a = a.mapv(f64::from);
+        let x = x.mapv(f64::from);
+        let y = y.mapv(f64::from);
+        let answer = answer.mapv(f64::from);
+        let mut a2 = Array::zeros((m, 2 * n));
+        a2.slice_mut(s![.., ..;2]).assign(&a);
+        let mut y2 = Array::zeros(2 * n);
+        y2.slice_mut(s![..;-2]).assign(&y);
+        a2.slice_mut(s![.., ..;2])
+            .general_rank1_update(3., &x, &y2.slice(s![..;-2]));
+        assert_eq!(a2.slice(s![.., ..;2]), answer);
+    }
+}
+
+#[test]
+#[should_panic]
+fn rank1_update_shape_mismatch() {
+    let mut a = range_mat64(4, 3);
+    a.general_rank1_update(1., &Array::zeros(4), &Array::zeros(4));
+}
+
+#[test]
+fn outer_product() {
+    use ndarray::linalg::outer;
+
+    for &(m, n) in &[(0, 3), (1, 1), (4, 4), (5, 2), (3, 17)] {
+        let x = Array::from_iter(0..m as i32);
+        let y = Array::from_iter((0..n as i32).map(|i| 2 * i - 3));
+        let answer = Array::from_shape_fn((m, n), |(i, j)| x[i] * y[j]);
+        assert_eq!(outer(&x, &y), answer);
+        assert_eq!(outer(&x.slice(s![..;-1]), &y).slice(s![..;-1, ..]), answer);
+        let x = x.mapv(f64::from);
+        let y = y.mapv(f64::from);
+        assert_eq!(
+            outer(&x, &y.slice(s![..;-1])),
+            answer.mapv(f64::from).slice(s![.., ..;-1])
+        );
+        let two = arr0(2.);
+        let answer = Array::from_shape_fn((m, n), |(i, _)| 2. * x[i]);
+        assert_eq!(outer(&x, &two.broadcast(n).unwrap()), answer);
+    }
+}
+
+#[test]
+fn vector_norms() {
+    let a = arr1(&[3., -4., 0., 12.]);
+    assert_eq!(a.norm_l2(), 13.);
+    assert_eq!(a.norm_l1(), 19.);
+    assert_eq!(a.argmax_abs(), Some(3));
+    assert_eq!(a.slice(s![..;-1]).argmax_abs(), Some(0));
+    assert_eq!(a.slice(s![..;2]).norm_l1(), 3.);
+
+    let e = Array1::<f32>::zeros(0);
+    assert_eq!(e.norm_l2(), 0.);
+    assert_eq!(e.norm_l1(), 0.);
+    assert_eq!(e.argmax_abs(), None);
+
+    // ties are resolved to the first index
+    assert_eq!(arr1(&[1., -2., 2., -2.]).argmax_abs(), Some(1));
+    // the first NaN wins
+    let nan = std::f64::NAN;
+    assert_eq!(arr1(&[nan, -2., nan]).argmax_abs(), Some(0));
+    assert_eq!(arr1(&[1., -2., nan, 5., nan]).argmax_abs(), Some(2));
+
+    // no overflow or underflow in the intermediate sum of squares
+    assert!((arr1(&[3e200_f64, 4e200]).norm_l2() / 5e200 - 1.).abs() < 1e-15);
+    assert!((arr1(&[3e-200_f64, -4e-200]).norm_l2() / 5e-200 - 1.).abs() < 1e-15);
+    assert!(arr1(&[1., std::f64::NAN]).norm_l2().is_nan());
+
+    // infinite elements give an infinite norm, unless there is a NaN
+    let inf = std::f64::INFINITY;
+    assert_eq!(arr1(&[inf, inf]).norm_l2(), inf);
+    assert_eq!(arr1(&[inf, 1.]).norm_l2(), inf);
+    assert_eq!(arr1(&[1., -inf, 0.]).norm_l2(), inf);
+    assert!(arr1(&[inf, std::f64::NAN]).norm_l2().is_nan());
+    assert!(arr1(&[std::f64::NAN, inf]).norm_l2().is_nan());
+}
+
+#[test]
+fn scale() {
+    let mut a = range_i32(5, 4);
+    let answer = &a * -3;
+    a.scale(-3);
+    assert_eq!(a, answer);
+
+    let mut b = range_mat64(7, 40);
+    let answer = b.mapv(|x| if x as usize % 2 == 1 { x * 0.5 } else { x });
+    b.slice_mut(s![.., 1..;2]).scale(0.5);
+    assert_eq!(b, answer);
+    b.slice_mut(s![.., 1..;2]).reversed_axes().scale(2.);
+    b.row_mut(3).slice_mut(s![..;-1]).scale(1.);
+    assert_eq!(b, range_mat64(7, 40));
+}
+
+#[test]
+fn banded_mat_vec() {
+    for &(m, n) in &[(0, 0), (1, 1), (4, 4), (7, 3), (3, 7), (17, 15)] {
+        for &(kl, ku) in &[(0, 0), (1, 1), (2, 0), (0, 3), (5, 9)] {
+            let a = Array::from_shape_fn((m, n), |(i, j)| {
+                if i <= j + kl && j <= i + ku {
+                    (i * n + j) as f64 - 10.
+                } else {
+                    0.
+                }
+            });
+            // elements outside the band are ignored
+            let a_dirty =
+                &a + &Array::from_shape_fn((m, n), |(i, j)| {
+                    if i <= j + kl && j <= i + ku {
+                        0.
+                    } else {
+                        99.
+                    }
+                });
+            let banded = BandedMatrix::from_dense(&a_dirty, kl, ku);
+            assert_eq!(banded.dim(), (m, n));
+            assert_eq!(banded.to_dense(), a);
+
+            let x = Array::from_iter((0..2 * n).map(|i| i as f64));
+            let x = x.slice(s![..;-2]);
+            assert_eq!(banded.dot(&x), a.dot(&x));
+
+            let mut y = Array::from_iter((0..m).map(|i| i as f64));
+            let answer = a.dot(&x) * 2. + &y * -1.;
+            banded_mat_vec_mul(2., &banded, &x, -1., &mut y);
+            assert_eq!(y, answer);
+        }
+    }
+}
+
+#[test]
+fn hermitian() {
+    let in_triangle = |uplo, i, j| match uplo {
+        Triangle::Upper => i <= j,
+        Triangle::Lower => i >= j,
+    };
+    let range_complex = |m: usize, n: usize| {
+        Array::from_shape_fn((m, n), |(i, j)| {
+            Complex64::new((i * n + j) as f64 - 3., (i + 2 * j) as f64 % 5. - 2.)
+        })
+    };
+    for &(m, n) in &[(0, 2), (1, 1), (4, 4), (5, 2), (3, 17), (17, 15)] {
+        for &uplo in &[Triangle::Upper, Triangle::Lower] {
+            // rank-k update
+            let a = range_complex(m, n);
+            let mut af = Array::zeros((m, n).f());
+            af.assign(&a);
+            let c = range_complex(m, m);
+            let a_h = a.t().mapv(|x| x.conj());
+            let full = a.dot(&a_h) * 2. + &c * -1.;
+            for a in &[a.view(), af.view()] {
+                let mut c1 = c.clone();
+                hermitian_rank_k_update(2., a, -1., &mut c1, uplo);
+                for ((i, j), &x) in c1.indexed_iter() {
+                    if i == j {
+                        assert_eq!(x, Complex64::new(full[[i, j]].re, 0.));
+                    } else if in_triangle(uplo, i, j) {
+                        assert_eq!(x, full[[i, j]]);
+                    } else {
+                        assert_eq!(x, c[[i, j]]);
+                    }
+                }
+            }
+
+            // multiplication; the other triangle and the imaginary part of
+            // the diagonal are never read
+            let h = a.dot(&a_h);
+            let h_dirty = Array::from_shape_fn((m, m), |(i, j)| {
+                if i == j {
+                    h[[i, j]] + Complex64::new(0., 7.)
+                } else if in_triangle(uplo, i, j) {
+                    h[[i, j]]
+                } else {
+                    Complex64::new(99., 99.)
+                }
+            });
+            let mut h_dirty_f = Array::zeros((m, m).f());
+            h_dirty_f.assign(&h_dirty);
+            assert_eq!(Hermitian::new(h_dirty.view(), uplo).to_full(), h);
+            let b = range_complex(m, n);
+            let mut bf = Array::zeros((m, n).f());
+            bf.assign(&b);
+            let alpha = Complex64::new(1., 2.);
+            let beta = Complex64::new(0., -1.);
+            let answer = h.dot(&b) * alpha + &b * beta;
+            for h in &[h_dirty.view(), h_dirty_f.view()] {
+                for b in &[b.view(), bf.view()] {
+                    let mut c = b.to_owned();
+                    hermitian_mat_mul(alpha, Hermitian::new(h.view(), uplo), b, beta, &mut c);
+                    assert_eq!(c, answer);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(feature = "half")]
+#[test]
+fn mat_mul_f16() {
+    use half::f16;
+    use ndarray::linalg::general_mat_mul_f16;
+
+    for &(m, k, n) in &[(0, 3, 2), (3, 0, 2), (4, 4, 4), (5, 70, 3), (130, 9, 65)] {
+        // small integers are exact in half precision
+        let a = Array::from_shape_fn((m, k), |(i, j)| ((i + 2 * j) % 7) as f32 - 3.);
+        let b = Array::from_shape_fn((k, n), |(i, j)| ((3 * i + j) % 5) as f32 - 2.);
+        let c = range_mat(m, n);
+        let answer = reference_mat_mul(&a, &b) * 2. + &c * 0.5;
+
+        let a16 = a.mapv(f16::from_f32);
+        let b16 = b.mapv(f16::from_f32);
+        let mut c1 = c.clone();
+        general_mat_mul_f16(2., &a16, &b16, 0.5, &mut c1);
+        assert_eq!(c1, answer);
+
+        let mut c2 = Array::zeros((m, n).f());
+        c2.assign(&c);
+        general_mat_mul_f16(2., &a16.t().t(), &b16.reversed_axes().t(), 0.5, &mut c2);
+        assert_eq!(c2, answer);
+    }
+}
+
+#[cfg(feature = "approx")]
+#[test]
+fn givens_rotation() {
+    let (g, r) = Givens::zeroing(-3., 4.);
+    assert_eq!(r, -5.);
+    assert_eq!((g.cos(), g.sin()), (0.6, -0.8));
+    assert_eq!(Givens::zeroing(0., -2.), (Givens::new(0., 1.), -2.));
+    assert_eq!(Givens::zeroing(-2., 0.), (Givens::new(1., 0.), -2.));
+
+    // reduce a matrix to upper triangular form, as in a QR decomposition
+    for &(m, n) in &[(2, 2), (5, 3), (40, 40), (3, 6)] {
+        let a = Array::from_shape_fn((m, n), |(i, j)| {
+            ((i * 7 + j * 3) % 11) as f64 - 5. + (i == j) as u8 as f64
+        });
+        let mut r = a.clone();
+        let mut q = Array::eye(m);
+        for j in 0..n {
+            for i in (j + 1..m).rev() {
+                let (g, rij) = Givens::zeroing(r[[i - 1, j]], r[[i, j]]);
+                g.rotate_rows(&mut r, i - 1, i);
+                g.rotate_columns(&mut q, i - 1, i);
+                approx::assert_abs_diff_eq!(r[[i - 1, j]], rij, epsilon = 1e-12);
+                approx::assert_abs_diff_eq!(r[[i, j]], 0., epsilon = 1e-12);
+            }
+        }
+        approx::assert_abs_diff_eq!(q.dot(&r), a, epsilon = 1e-10);
+        approx::assert_abs_diff_eq!(q.t().dot(&q), Array::eye(m), epsilon = 1e-12);
+    }
+
+    // reversed and strided vectors
+    let x0 = Array::linspace(0., 9., 10);
+    let y0 = Array::linspace(-5., 13., 10);
+    let g = Givens::new(0.6, 0.8);
+    let mut x = x0.clone();
+    let mut y = Array::zeros(20);
+    y.slice_mut(s![..;-2]).assign(&y0);
+    g.rotate(&mut x, &mut y.slice_mut(s![..;-2]));
+    approx::assert_abs_diff_eq!(x, &x0 * 0.6 + &y0 * 0.8, epsilon = 1e-12);
+    approx::assert_abs_diff_eq!(y.slice(s![..;-2]), &y0 * 0.6 - &x0 * 0.8, epsilon = 1e-12);
+}
+
+#[test]
+fn small_mat_mul() {
+    for n in 1..6 {
+        let a = range_i32(n, n);
+        let b = range_i32(n, n).reversed_axes();
+        let answer = reference_mat_mul(&a, &b);
+        assert_eq!(a.dot(&b), answer);
+        let mut bf = Array::zeros((n, n).f());
+        bf.assign(&b);
+        assert_eq!(a.dot(&bf), answer);
+
+        let a = range_mat64(n, n);
+        let b = a.slice(s![..;-1, ..]);
+        let answer = reference_mat_mul(&a, &b);
+        assert_eq!(a.dot(&b), answer);
+        assert_eq!(b.t().dot(&a.t()), answer.t());
+
+        let x = Array::linspace(1., n as f64, n);
+        let answer = reference_mat_mul(&b, &x.view().insert_axis(Axis(1)));
+        assert_eq!(b.dot(&x), answer.column(0));
+        assert_eq!(b.dot(&x.slice(s![..;-1])), b.slice(s![.., ..;-1]).dot(&x));
+    }
+}
+
+#[cfg(feature = "approx")]
+#[test]
+fn lu_solve() {
+    use ndarray::linalg::LinalgErrorKind;
+
+    for &n in &[0, 1, 2, 5, 40] {
+        let a = Array::from_shape_fn((n, n), |(i, j)| {
+            ((i * 7 + j * 3) % 11) as f64 - 5. + (i == j) as u8 as f64 * 20.
+        });
+        let lu = a.factorize_lu().unwrap();
+        let mut pa = a.clone();
+        for (k, &p) in lu.pivots().iter().enumerate() {
+            for j in 0..n {
+                pa.swap([k, j], [p, j]);
+            }
+        }
+        approx::assert_abs_diff_eq!(lu.l().dot(&lu.u()), pa, epsilon = 1e-10);
+
+        let x = Array::linspace(-1., 2., n);
+        let x1 = lu.solve(&a.dot(&x)).unwrap();
+        approx::assert_abs_diff_eq!(x1, x, epsilon = 1e-10);
+        let xs = range_mat64(n, 3);
+        let mut b = Array::zeros((n, 3).f());
+        b.assign(&a.dot(&xs));
+        let xs1 = a.t().t().solve_into(b).unwrap();
+        approx::assert_abs_diff_eq!(xs1, xs, epsilon = 1e-10);
+    }
+
+    let singular = arr2(&[[1., 2.], [2., 4.]]);
+    let err = singular.solve(&arr1(&[1., 1.])).unwrap_err();
+    assert_eq!(err.kind(), LinalgErrorKind::Singular);
+    let err = arr2(&[[1., 2.]]).factorize_lu().unwrap_err();
+    assert_eq!(err.kind(), LinalgErrorKind::NotSquare);
+    let err = Array2::<f64>::eye(2)
+        .solve(&arr1(&[1., 1., 1.]))
+        .unwrap_err();
+    assert_eq!(err.kind(), LinalgErrorKind::IncompatibleShape);
+}
+
+#[cfg(feature = "approx")]
+#[test]
+fn qr() {
+    for &(m, n) in &[
+        (0, 0),
+        (0, 3),
+        (3, 0),
+        (1, 1),
+        (4, 4),
+        (7, 3),
+        (3, 7),
+        (17, 15),
+    ] {
+        let a = Array::from_shape_fn((m, n), |(i, j)| ((i * 7 + j * 3) % 11) as f64 - 5.);
+        let k = std::cmp::min(m, n);
+        let (q, r) = a.qr();
+        let (q_thin, r_thin) = a.t().t().qr_thin();
+        assert_eq!((q.dim(), r.dim()), ((m, m), (m, n)));
+        assert_eq!((q_thin.dim(), r_thin.dim()), ((m, k), (k, n)));
+        approx::assert_abs_diff_eq!(q.dot(&r), a, epsilon = 1e-10);
+        approx::assert_abs_diff_eq!(q_thin.dot(&r_thin), a, epsilon = 1e-10);
+        approx::assert_abs_diff_eq!(q.t().dot(&q), Array::eye(m), epsilon = 1e-12);
+        approx::assert_abs_diff_eq!(q_thin.t().dot(&q_thin), Array::eye(k), epsilon = 1e-12);
+        for ((i, j), &x) in r.indexed_iter() {
+            if i > j {
+                assert_eq!(x, 0.);
+            }
+        }
+    }
+
+    // rank deficient: a zero column
+    let a = arr2(&[[0., 1.], [0., 2.]]);
+    let (q, r) = a.qr();
+    approx::assert_abs_diff_eq!(q.dot(&r), a, epsilon = 1e-12);
+}
+
+#[cfg(feature = "approx")]
+#[test]
+fn svd() {
+    for &(m, n) in &[
+        (0, 0),
+        (0, 3),
+        (3, 0),
+        (1, 1),
+        (4, 4),
+        (7, 3),
+        (3, 7),
+        (17, 15),
+    ] {
+        let a = Array::from_shape_fn((m, n), |(i, j)| ((i * 7 + j * 3) % 11) as f64 - 5.);
+        // rank deficient: a repeated column
+        let mut b = a.clone();
+        if n > 1 {
+            let c0 = b.column(0).to_owned();
+            b.column_mut(n - 1).assign(&c0);
+        }
+        for a in &[a, b] {
+            let (u, s, vt) = a.svd(true, true).unwrap();
+            let (u, vt) = (u.unwrap(), vt.unwrap());
+            let k = std::cmp::min(m, n);
+            assert_eq!((u.dim(), s.dim(), vt.dim()), ((m, m), k, (n, n)));
+            assert!(s.windows(2).into_iter().all(|w| w[0] >= w[1]));
+            approx::assert_abs_diff_eq!(u.t().dot(&u), Array::eye(m), epsilon = 1e-10);
+            approx::assert_abs_diff_eq!(vt.dot(&vt.t()), Array::eye(n), epsilon = 1e-10);
+            let mut sigma = Array::zeros((m, n));
+            sigma.diag_mut().assign(&s);
+            approx::assert_abs_diff_eq!(u.dot(&sigma).dot(&vt), a, epsilon = 1e-10);
+
+            approx::assert_abs_diff_eq!(a.svd_values_only().unwrap(), s, epsilon = 1e-10);
+            let (u, s2, vt) = a.svd(false, false).unwrap();
+            assert!(u.is_none() && vt.is_none());
+            approx::assert_abs_diff_eq!(s2, s, epsilon = 1e-10);
+        }
+    }
+}
+
+#[cfg(feature = "approx")]
+#[test]
+fn cholesky() {
+    use ndarray::linalg::LinalgErrorKind;
+
+    for &n in &[0, 1, 2, 5, 40] {
+        let b = Array::from_shape_fn((n, n), |(i, j)| ((i * 7 + j * 3) % 11) as f64 - 5.);
+        let a = b.dot(&b.t()) + Array2::<f64>::eye(n);
+        // the other triangle is never read
+        let lower = Array::from_shape_fn((n, n), |(i, j)| if i >= j { a[[i, j]] } else { 99. });
+        let upper = lower.t().to_owned();
+
+        let l = lower.cholesky(Triangle::Lower).unwrap();
+        let u = upper.cholesky(Triangle::Upper).unwrap();
+        approx::assert_abs_diff_eq!(l.dot(&l.t()), a, epsilon = 1e-9);
+        approx::assert_abs_diff_eq!(u, l.t(), epsilon = 1e-12);
+        for ((i, j), &x) in l.indexed_iter() {
+            if i < j {
+                assert_eq!(x, 0.);
+            }
+        }
+
+        let x = Array::linspace(-1., 2., n);
+        approx::assert_abs_diff_eq!(lower.solvec(&a.dot(&x)).unwrap(), x, epsilon = 1e-9);
+        let xs = range_mat64(n, 3);
+        let mut rhs = Array::zeros((n, 3).f());
+        rhs.assign(&a.dot(&xs));
+        approx::assert_abs_diff_eq!(lower.solvec_into(rhs).unwrap(), xs, epsilon = 1e-9);
+    }
+
+    let indefinite = arr2(&[[1., 2.], [2., 1.]]);
+    for &uplo in &[Triangle::Upper, Triangle::Lower] {
+        let err = indefinite.cholesky(uplo).unwrap_err();
+        assert_eq!(err.kind(), LinalgErrorKind::NotPositiveDefinite);
+    }
+    let err = indefinite.solvec(&arr1(&[1., 1.])).unwrap_err();
+    assert_eq!(err.kind(), LinalgErrorKind::NotPositiveDefinite);
+    let err = Array2::<f64>::eye(2)
+        .solvec(&arr1(&[1., 1., 1.]))
+        .unwrap_err();
+    assert_eq!(err.kind(), LinalgErrorKind::IncompatibleShape);
+}
+
+#[cfg(feature = "approx")]
+#[test]
+fn eigh() {
+    for &n in &[0, 1, 2, 5, 40] {
+        let b = Array::from_shape_fn((n, n), |(i, j)| ((i * 7 + j * 3) % 11) as f64 - 5.);
+        // indefinite, with repeated eigenvalues for the rank deficient cases
+        let a = &b + &b.t();
+        for &uplo in &[Triangle::Upper, Triangle::Lower] {
+            let dirty = Array::from_shape_fn((n, n), |(i, j)| {
+                if (i <= j) == (uplo == Triangle::Upper) || i == j {
+                    a[[i, j]]
+                } else {
+                    99.
+                }
+            });
+            let (w, v) = dirty.eigh(uplo).unwrap();
+            assert!(w.windows(2).into_iter().all(|w| w[0] <= w[1]));
+            approx::assert_abs_diff_eq!(v.t().dot(&v), Array::eye(n), epsilon = 1e-10);
+            approx::assert_abs_diff_eq!(a.dot(&v), &v * &w, epsilon = 1e-9);
+            approx::assert_abs_diff_eq!(dirty.eigvalsh(uplo).unwrap(), w, epsilon = 1e-9);
+        }
+    }
+    let err = arr2(&[[1., 2.]]).eigh(Triangle::Upper).unwrap_err();
+    assert_eq!(err.kind(), ndarray::linalg::LinalgErrorKind::NotSquare);
+}
+
+#[test]
+fn eigh_hermitian() {
+    let max_norm = |a: Array2<Complex64>| a.iter().fold(0., |m: f64, x| m.max(x.norm()));
+    for &n in &[0, 1, 2, 5, 20] {
+        let b = Array::from_shape_fn((n, n), |(i, j)| {
+            Complex64::new(
+                ((i * 7 + j * 3) % 11) as f64 - 5.,
+                ((i * 2 + j * 5) % 7) as f64 - 3.,
+            )
+        });
+        let a = &b + &b.t().mapv(|x| x.conj());
+        for &uplo in &[Triangle::Upper, Triangle::Lower] {
+            let dirty = Array::from_shape_fn((n, n), |(i, j)| {
+                if (i <= j) == (uplo == Triangle::Upper) && i != j {
+                    a[[i, j]]
+                } else if i == j {
+                    a[[i, j]] + Complex64::new(0., 7.)
+                } else {
+                    Complex64::new(99., 99.)
+                }
+            });
+            let h = Hermitian::new(dirty.view(), uplo);
+            let (w, v) = h.eigh().unwrap();
+            assert!(w.windows(2).into_iter().all(|w| w[0] <= w[1]));
+            let vh = v.t().mapv(|x| x.conj());
+            assert!(max_norm(vh.dot(&v) - Array2::<Complex64>::eye(n)) < 1e-10);
+            let vw = &v * &w.mapv(|w| Complex64::new(w, 0.));
+            assert!(max_norm(a.dot(&v) - vw) < 1e-9);
+            let w2 = h.eigvalsh().unwrap();
+            assert!(w2.iter().zip(&w).all(|(x, y)| (x - y).abs() < 1e-9));
+        }
+    }
+}
+
+#[cfg(feature = "approx")]
+#[test]
+fn eig() {
+    let check = |a: &Array2<f64>| {
+        let n = a.nrows();
+        let (w, v) = a.eig().unwrap();
+        let ac = a.mapv(Complex64::from);
+        for (&w, v) in w.iter().zip(v.gencolumns()) {
+            let residual = ac.dot(&v) - &v * w;
+            assert!(residual.iter().all(|x| x.norm() < 1e-9), "{:?}", residual);
+            let norm = v.iter().map(|x| x.norm_sqr()).sum::<f64>();
+            approx::assert_abs_diff_eq!(norm, 1., epsilon = 1e-12);
+        }
+        // complex eigenvalues of a real matrix come in conjugate pairs
+        let trace = w.iter().fold(Complex64::new(0., 0.), |acc, &x| acc + x);
+        approx::assert_abs_diff_eq!(trace.re, a.diag().sum(), epsilon = 1e-9);
+        approx::assert_abs_diff_eq!(trace.im, 0., epsilon = 1e-9);
+        let mut w1 = a.eigvals().unwrap().to_vec();
+        let mut w2 = w.to_vec();
+        let key = |x: &Complex64, y: &Complex64| (x.re, x.im).partial_cmp(&(y.re, y.im)).unwrap();
+        w1.sort_by(key);
+        w2.sort_by(key);
+        assert_eq!(w1.len(), n);
+        for (x, y) in w1.iter().zip(&w2) {
+            assert!((x - y).norm() < 1e-9);
+        }
+    };
+    for &n in &[0, 1, 2, 5, 40] {
+        let a = Array::from_shape_fn((n, n), |(i, j)| ((i * 7 + j * 3) % 11) as f64 - 5.);
+        check(&a);
+        check(&(&a + &a.t()));
+    }
+    // a rotation, a triangular matrix and a permutation
+    check(&arr2(&[[0., -1.], [1., 0.]]));
+    check(&arr2(&[[1., 2., 3.], [0., 4., 5.], [0., 0., 6.]]));
+    check(&arr2(&[[0., 0., 1.], [1., 0., 0.], [0., 1., 0.]]));
+
+    let err = arr2(&[[1., 2.]]).eig().unwrap_err();
+    assert_eq!(err.kind(), ndarray::linalg::LinalgErrorKind::NotSquare);
+}
+
+#[cfg(feature = "approx")]
+#[test]
+fn inv_det() {
+    use ndarray::linalg::LinalgErrorKind;
+
+    for &n in &[0, 1, 2, 5, 40] {
+        let a = Array::from_shape_fn((n, n), |(i, j)| {
+            ((i * 7 + j * 3) % 11) as f64 - 5. + (i == j) as u8 as f64 * 20.
+        });
+        let a_inv = a.inv().unwrap();
+        approx::assert_abs_diff_eq!(a.dot(&a_inv), Array::eye(n), epsilon = 1e-10);
+        approx::assert_abs_diff_eq!(a_inv.dot(&a), Array::eye(n), epsilon = 1e-10);
+
+        // the determinant is the product of the eigenvalues
+        let det = a.det().unwrap();
+        let product = a
+            .eigvals()
+            .unwrap()
+            .iter()
+            .fold(Complex64::new(1., 0.), |acc, &x| acc * x);
+        approx::assert_relative_eq!(det, product.re, max_relative = 1e-9);
+        let (sign, ln) = a.sln_det().unwrap();
+        approx::assert_relative_eq!(sign * ln.exp(), det, max_relative = 1e-9);
+    }
+
+    assert_eq!(arr2(&[[0., 1.], [1., 0.]]).det(), Ok(-1.));
+    assert_eq!(arr2(&[[0., 1.], [1., 0.]]).sln_det(), Ok((-1., 0.)));
+    let singular = arr2(&[[1., 2.], [2., 4.]]);
+    assert_eq!(singular.det(), Ok(0.));
+    assert_eq!(singular.sln_det(), Ok((0., std::f64::NEG_INFINITY)));
+    assert_eq!(
+        singular.inv().unwrap_err().kind(),
+        LinalgErrorKind::Singular
+    );
+    let err = arr2(&[[1., 2.]]).det().unwrap_err();
+    assert_eq!(err.kind(), LinalgErrorKind::NotSquare);
+}
+
+#[cfg(feature = "approx")]
+#[test]
+fn least_squares() {
+    for &(m, n) in &[(1, 1), (5, 5), (7, 3), (3, 7), (40, 15)] {
+        let a = Array::from_shape_fn((m, n), |(i, j)| {
+            ((i * 7 + j * 3) % 11) as f64 - 5. + (i == j) as u8 as f64 * 20.
+        });
+        let b = Array::linspace(-1., 2., m);
+        let result = a.least_squares(&b).unwrap();
+        let x = result.solution();
+        assert_eq!(result.rank(), std::cmp::min(m, n));
+        approx::assert_abs_diff_eq!(result.singular_values(), &a.svd_values_only().unwrap());
+        // the residual is orthogonal to the range of A
+        let r = a.dot(x) - &b;
+        approx::assert_abs_diff_eq!(a.t().dot(&r), Array::zeros(n), epsilon = 1e-9);
+        if m > n {
+            let rss = result.residual_sum_of_squares().unwrap();
+            approx::assert_abs_diff_eq!(rss[()], r.dot(&r), epsilon = 1e-9);
+        } else {
+            assert!(result.residual_sum_of_squares().is_none());
+            // the minimum norm solution lies in the range of Aᵀ
+            let y = a.t().least_squares(x).unwrap();
+            approx::assert_abs_diff_eq!(a.t().dot(y.solution()), x, epsilon = 1e-9);
+        }
+
+        // several right hand sides at once
+        let mut bs = Array::zeros((m, 2));
+        bs.column_mut(0).assign(&b);
+        bs.column_mut(1).assign(&(&b * 2.));
+        let result2 = a.least_squares(&bs).unwrap();
+        approx::assert_abs_diff_eq!(result2.solution().column(0), x, epsilon = 1e-9);
+        approx::assert_abs_diff_eq!(result2.solution().column(1), x * 2., epsilon = 1e-9);
+        if let Some(rss) = result2.residual_sum_of_squares() {
+            approx::assert_abs_diff_eq!(rss[1], rss[0] * 4., epsilon = 1e-9);
+        }
+    }
+
+    // rank deficient
+    let a = arr2(&[[1., 1.], [1., 1.], [1., 1.]]);
+    let result = a.least_squares(&arr1(&[1., 2., 3.])).unwrap();
+    assert_eq!(result.rank(), 1);
+    assert!(result.residual_sum_of_squares().is_none());
+    approx::assert_abs_diff_eq!(result.into_solution(), arr1(&[1., 1.]), epsilon = 1e-12);
+
+    let err = a.least_squares(&arr1(&[1., 2.])).unwrap_err();
+    assert_eq!(
+        err.kind(),
+        ndarray::linalg::LinalgErrorKind::IncompatibleShape
+    );
+}
+
+#[cfg(feature = "approx")]
+#[test]
+fn pinv() {
+    for &(m, n) in &[(0, 0), (0, 3), (1, 1), (5, 5), (7, 3), (3, 7)] {
+        let a = Array::from_shape_fn((m, n), |(i, j)| ((i * 7 + j * 3) % 11) as f64 - 5.);
+        // rank deficient: a repeated column
+        let mut b = a.clone();
+        if n > 1 {
+            let c0 = b.column(0).to_owned();
+            b.column_mut(n - 1).assign(&c0);
+        }
+        for a in &[a, b] {
+            let p = a.pinv(1e-12).unwrap();
+            assert_eq!(p.dim(), (n, m));
+            // the Moore–Penrose conditions
+            approx::assert_abs_diff_eq!(a.dot(&p).dot(a), a, epsilon = 1e-9);
+            approx::assert_abs_diff_eq!(p.dot(a).dot(&p), p, epsilon = 1e-9);
+            let ap = a.dot(&p);
+            let pa = p.dot(a);
+            approx::assert_abs_diff_eq!(ap.t(), ap.view(), epsilon = 1e-9);
+            approx::assert_abs_diff_eq!(pa.t(), pa.view(), epsilon = 1e-9);
+        }
+    }
+    // a larger cutoff drops the small singular value
+    let a = arr2(&[[1., 0.], [0., 1e-6]]);
+    approx::assert_abs_diff_eq!(
+        a.pinv(1e-12).unwrap(),
+        arr2(&[[1., 0.], [0., 1e6]]),
+        epsilon = 1e-6
+    );
+    approx::assert_abs_diff_eq!(a.pinv(1e-3).unwrap(), arr2(&[[1., 0.], [0., 0.]]));
+}
+
+#[cfg(feature = "approx")]
+#[test]
+fn matrix_norms() {
+    let v = arr1(&[1., -7., 2.]);
+    assert_eq!(v.norm_l1(), 10.);
+    assert_eq!(v.norm_max(), 7.);
+    assert_eq!(arr1::<f64>(&[]).norm_max(), 0.);
+
+    let a = Array::from_shape_fn((4, 6), |(i, j)| ((i * 7 + j * 3) % 11) as f64 - 5.);
+    for a in &[a.view(), a.t()] {
+        let col_sums = a.gencolumns().into_iter().map(|c| c.norm_l1());
+        let row_sums = a.genrows().into_iter().map(|r| r.norm_l1());
+        assert_eq!(a.opnorm_one(), col_sums.fold(0., f64::max));
+        assert_eq!(a.opnorm_inf(), row_sums.fold(0., f64::max));
+        approx::assert_abs_diff_eq!(
+            a.opnorm_fro(),
+            a.iter().map(|x| x * x).sum::<f64>().sqrt(),
+            epsilon = 1e-12
+        );
+        let s = a.svd_values_only().unwrap();
+        approx::assert_abs_diff_eq!(a.opnorm_two().unwrap(), s[0]);
+        approx::assert_abs_diff_eq!(a.cond().unwrap(), s[0] / s[3]);
+    }
+    // no overflow in the sum of squares
+    assert_eq!(arr2(&[[3e300, 4e300]]).opnorm_fro(), 5e300);
+    assert_eq!(
+        arr2(&[[std::f64::INFINITY, 1.], [std::f64::INFINITY, 2.]]).opnorm_fro(),
+        std::f64::INFINITY
+    );
+
+    let a = arr2(&[[4., 3.], [6., 3.]]);
+    let a_inv = a.inv().unwrap();
+    approx::assert_abs_diff_eq!(
+        a.cond().unwrap(),
+        a.opnorm_two().unwrap() * a_inv.opnorm_two().unwrap(),
+        epsilon = 1e-12
+    );
+    assert_eq!(arr2(&[[1., 2.], [2., 4.]]).cond().unwrap(), f64::INFINITY);
+    assert_eq!(Array2::<f64>::zeros((0, 0)).opnorm_two().unwrap(), 0.);
+}
+
+#[cfg(feature = "approx")]
+#[test]
+fn matrix_functions() {
+    use ndarray::linalg::LinalgErrorKind;
+
+    let max_diff = |x: &Array2<Complex64>, y: &Array2<Complex64>| {
+        (x - y).iter().fold(0., |acc, d| f64::max(acc, d.norm()))
+    };
+
+    let a = Array::from_shape_fn((5, 5), |(i, j)| ((i * 7 + j * 3) % 11) as f64 / 4. - 1.25);
+    // a large norm, so that scaling and squaring is needed
+    let a_big = &a * 3.;
+    for a in &[a.view(), a_big.view(), a.t()] {
+        // compare with the Taylor series of exp(A / 2⁶), squared six times
+        let small = a.mapv(|x| x / 64.);
+        let mut term = Array2::<f64>::eye(5);
+        let mut e = term.clone();
+        for k in 1..30 {
+            term = term.dot(&small) / k as f64;
+            e += &term;
+        }
+        for _ in 0..6 {
+            e = e.dot(&e);
+        }
+        let expm = a.expm().unwrap();
+        let scale = e.iter().fold(1., |acc, x| f64::max(acc, x.abs()));
+        approx::assert_abs_diff_eq!(expm, e, epsilon = 1e-11 * scale);
+        // exp(A) exp(-A) = I
+        approx::assert_abs_diff_eq!(
+            expm.dot(&a.mapv(|x| -x).expm().unwrap()),
+            Array2::eye(5),
+            epsilon = 1e-9
+        );
+
+        let a_c = a.mapv(Complex64::from);
+        let x = a.sqrtm().unwrap();
+        assert!(max_diff(&x.dot(&x), &a_c) < 1e-10);
+
+        // the logarithm inverts the exponential for small enough A
+        let l = small.expm().unwrap().logm().unwrap();
+        assert!(max_diff(&l, &small.mapv(Complex64::from)) < 1e-12);
+    }
+
+    // a rotation by π has no real square root or logarithm
+    let a = arr2(&[[-1., 0.], [0., -1.]]);
+    let x = a.sqrtm().unwrap();
+    assert!(max_diff(&x.dot(&x), &a.mapv(Complex64::from)) < 1e-12);
+    let l = a.logm().unwrap();
+    approx::assert_abs_diff_eq!(
+        l.mapv(|x| x.im.abs()),
+        Array2::from_diag(&arr1(&[std::f64::consts::PI; 2])),
+        epsilon = 1e-12
+    );
+
+    assert_eq!(
+        arr2(&[[0., 1.], [0., 0.]]).logm().unwrap_err().kind(),
+        LinalgErrorKind::Singular
+    );
+    let zero = Array2::<f64>::zeros((3, 3));
+    approx::assert_abs_diff_eq!(zero.expm().unwrap(), Array2::eye(3));
+    assert!(max_diff(&zero.sqrtm().unwrap(), &Array2::zeros((3, 3))) < 1e-12);
+    assert_eq!(
+        Array2::<f64>::zeros((2, 3)).expm().unwrap_err().kind(),
+        LinalgErrorKind::NotSquare
+    );
+}
+
+#[test]
+fn tensordot() {
+    use ndarray::linalg::tensordot;
+
+    let a = Array::from_shape_fn((3, 4, 5), |(i, j, k)| (i * 20 + j * 5 + k) as f64);
+    let b = Array::from_shape_fn((5, 3, 2), |(i, j, k)| (i * 6 + j * 2 + k) as f64 - 7.);
+    let c = tensordot(&a, &b, &[Axis(2), Axis(0)], &[Axis(0), Axis(1)]);
+    assert_eq!(c.shape(), &[4, 2]);
+    let expected = Array::from_shape_fn((4, 2), |(j, l)| {
+        let mut sum = 0.;
+        for i in 0..3 {
+            for k in 0..5 {
+                sum += a[[i, j, k]] * b[[k, i, l]];
+            }
+        }
+        sum
+    });
+    assert_eq!(c, expected.into_dyn());
+
+    // non-contiguous inputs
+    let c = tensordot(&a.t(), &b.t(), &[Axis(0), Axis(2)], &[Axis(2), Axis(1)]);
+    assert_eq!(c.shape(), &[4, 2]);
+    let c_t = tensordot(&a, &b, &[Axis(2), Axis(0)], &[Axis(0), Axis(1)]);
+    assert_eq!(c, c_t);
+
+    // no contracted axes: the outer product
+    let x = arr1(&[1., 2.]);
+    let y = arr1(&[3., 4., 5.]);
+    let outer = tensordot(&x, &y, &[], &[]);
+    assert_eq!(outer, arr2(&[[3., 4., 5.], [6., 8., 10.]]).into_dyn());
+
+    // all axes contracted: a scalar
+    let m = arr2(&[[1., 2.], [3., 4.]]);
+    let s = tensordot(&m, &m, &[Axis(0), Axis(1)], &[Axis(0), Axis(1)]);
+    assert_eq!(s.shape(), &[] as &[usize]);
+    assert_eq!(s[[]], 30.);
+
+    // the matrix product
+    assert_eq!(
+        tensordot(&m, &m, &[Axis(1)], &[Axis(0)]),
+        m.dot(&m).into_dyn()
+    );
+}
+
+#[test]
+#[should_panic]
+fn tensordot_shape_mismatch() {
+    let a = Array2::<f64>::zeros((2, 3));
+    ndarray::linalg::tensordot(&a, &a, &[Axis(1)], &[Axis(1), Axis(0)]);
+}
+
+#[test]
+fn einsum() {
+    use ndarray::einsum;
+    use ndarray::linalg::{einsum, tensordot};
+    use ndarray::ErrorKind;
+
+    let a = Array::from_shape_fn((3, 4), |(i, j)| (i * 4 + j) as f64 - 5.);
+    let b = Array::from_shape_fn((4, 5), |(i, j)| (i * 5 + j) as f64 / 2.);
+    let c = Array::from_shape_fn((5, 2), |(i, j)| (i + j * 3) as f64);
+    let s = Array::from_shape_fn((4, 4), |(i, j)| (i * 4 + j) as f64);
+
+    assert_eq!(einsum!("ij,jk->ik", a, b), a.dot(&b).into_dyn());
+    assert_eq!(einsum!("ij,jk", a, b), a.dot(&b).into_dyn());
+    assert_eq!(einsum!("ij,jk->ki", a, b), a.dot(&b).t().into_dyn());
+    assert_eq!(
+        einsum!("ij , jk , kl -> il", a, b, c),
+        a.dot(&b).dot(&c).into_dyn()
+    );
+    assert_eq!(
+        einsum!("ij,jk,kl->il", a.t().t(), b, c),
+        a.dot(&b).dot(&c).into_dyn()
+    );
+    assert_eq!(einsum!("ji,jk->ik", a.t(), b), a.dot(&b).into_dyn());
+    assert_eq!(einsum!("ij->ji", a), a.t().into_dyn());
+    assert_eq!(einsum!("ij->", a)[[]], a.sum());
+    assert_eq!(einsum!("ij->j", a), a.sum_axis(Axis(0)).into_dyn());
+    assert_eq!(einsum!("ii", s)[[]], s.diag().sum());
+    assert_eq!(einsum!("ii->i", s), s.diag().into_dyn());
+    assert_eq!(einsum!("ij,ij->", a, a)[[]], a.iter().map(|x| x * x).sum());
+    assert_eq!(einsum!("ij,ij->ij", a, a), (&a * &a).into_dyn());
+    let x = arr1(&[1., 2.]);
+    let y = arr1(&[3., 4., 5.]);
+    assert_eq!(
+        einsum!("i,j->ij", x, y),
+        arr2(&[[3., 4., 5.], [6., 8., 10.]]).into_dyn()
+    );
+    // a label only in one operand is summed over
+    assert_eq!(einsum!("i,j->j", x, y), (&y * 3.).into_dyn());
+
+    // batched matrix multiplication
+    let p = Array::from_shape_fn((2, 3, 4), |(b, i, j)| (b * 12 + i * 4 + j) as f64);
+    let q = Array::from_shape_fn((2, 4, 5), |(b, i, j)| (b * 20 + i * 5 + j) as f64 - 9.);
+    assert_eq!(einsum!("bij,bjk->bik", p, q), p.dot(&q).into_dyn());
+    assert_eq!(
+        einsum!("bij,bjk->ik", p, q),
+        p.dot(&q).sum_axis(Axis(0)).into_dyn()
+    );
+    let r = Array::from_shape_fn((4, 2, 6), |(i, j, k)| (i * 12 + j * 6 + k) as f64);
+    assert_eq!(
+        einsum!("bij,jbl->il", p, r),
+        tensordot(&p, &r, &[Axis(0), Axis(2)], &[Axis(1), Axis(0)])
+    );
+
+    let kind = |subscripts, operands: &[ArrayViewD<'_, f64>]| {
+        einsum(subscripts, operands).unwrap_err().kind()
+    };
+    let (a, b) = (a.view().into_dyn(), b.view().into_dyn());
+    assert_eq!(
+        kind("ij,jk->ik", &[a.clone()]),
+        ErrorKind::IncompatibleShape
+    );
+    assert_eq!(
+        kind("ijk,jk->ik", &[a.clone(), b.clone()]),
+        ErrorKind::IncompatibleShape
+    );
+    assert_eq!(
+        kind("ij,ik->jk", &[a.clone(), b.clone()]),
+        ErrorKind::IncompatibleShape
+    );
+    assert_eq!(
+        kind("i.,jk->ik", &[a.clone(), b.clone()]),
+        ErrorKind::Unsupported
+    );
+    assert_eq!(
+        kind("ij,jk->iz", &[a.clone(), b.clone()]),
+        ErrorKind::Unsupported
+    );
+    assert_eq!(kind("ij,jk->ii", &[a, b]), ErrorKind::Unsupported);
+}
+
+#[cfg(feature = "approx")]
+#[test]
+fn matrix_power() {
+    use ndarray::linalg::LinalgErrorKind;
+
+    let a = Array::from_shape_fn((4, 4), |(i, j)| ((i * 7 + j * 3) % 11) as f64 / 8. - 0.5);
+    let mut power = Array2::<f64>::eye(4);
+    for n in 0..12 {
+        approx::assert_abs_diff_eq!(a.matrix_power(n).unwrap(), power, epsilon = 1e-9);
+        approx::assert_abs_diff_eq!(
+            a.matrix_power(-n).unwrap().dot(&power),
+            Array2::eye(4),
+            epsilon = 1e-9
+        );
+        power = power.dot(&a);
+    }
+    let p5 = a.matrix_power(5).unwrap();
+    approx::assert_abs_diff_eq!(a.t().matrix_power(5).unwrap(), p5.t(), epsilon = 1e-12);
+
+    let singular = arr2(&[[1., 2.], [2., 4.]]);
+    assert_eq!(
+        singular.matrix_power(3).unwrap(),
+        arr2(&[[25., 50.], [50., 100.]])
+    );
+    assert_eq!(
+        singular.matrix_power(-1).unwrap_err().kind(),
+        LinalgErrorKind::Singular
+    );
+    assert_eq!(
+        Array2::<f64>::zeros((2, 3))
+            .matrix_power(2)
+            .unwrap_err()
+            .kind(),
+        LinalgErrorKind::NotSquare
+    );
+    assert_eq!(
+        arr2(&[[1.]]).matrix_power(i32::min_value()).unwrap(),
+        arr2(&[[1.]])
+    );
+}
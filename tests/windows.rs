@@ -117,3 +117,57 @@ fn test_window_zip() {
         }
     }
 }
+
+/// Test that verifies the `Windows` iterator panics on strides equal to zero.
+#[test]
+#[should_panic]
+fn windows_iterator_zero_stride() {
+    let a = Array::from_iter(10..37).into_shape((3, 3, 3)).unwrap();
+    a.windows_with_stride((2, 2, 2), (1, 0, 1));
+}
+
+/// Simple test for iterating 1d-arrays via `Windows` with a stride.
+#[test]
+fn windows_iterator_1d_stride() {
+    let a = Array::from_iter(10..20).into_shape(10).unwrap();
+    itertools::assert_equal(
+        a.windows_with_stride(4, 3),
+        vec![
+            arr1(&[10, 11, 12, 13]),
+            arr1(&[13, 14, 15, 16]),
+            arr1(&[16, 17, 18, 19]),
+        ],
+    );
+    itertools::assert_equal(
+        a.windows_with_stride(2, 4),
+        vec![arr1(&[10, 11]), arr1(&[14, 15]), arr1(&[18, 19])],
+    );
+    assert_eq!(a.windows_with_stride(11, 1).into_iter().next(), None);
+}
+
+#[test]
+fn test_window_stride_zip() {
+    let a = Array::from_iter(0..64).into_shape((4, 4, 4)).unwrap();
+    let a = a.slice(s![.., ..;-1, ..]);
+
+    for x in 1..4 {
+        for y in 1..4 {
+            for sx in 1..5 {
+                for sy in 1..5 {
+                    let windows = a.windows_with_stride((x, y, 2), (sx, sy, 2));
+                    assert_eq!(
+                        windows.clone().into_iter().count(),
+                        ((4 - x) / sx + 1) * ((4 - y) / sy + 1) * 2
+                    );
+                    Zip::indexed(windows).apply(|(i, j, k), window| {
+                        let i = (i * sx) as isize;
+                        let j = (j * sy) as isize;
+                        let k = (k * 2) as isize;
+                        let (x, y) = (x as isize, y as isize);
+                        assert_eq!(window, a.slice(s![i..i + x, j..j + y, k..k + 2]));
+                    })
+                }
+            }
+        }
+    }
+}